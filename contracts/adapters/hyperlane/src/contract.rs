@@ -1,14 +1,44 @@
 use crate::{
     error::{ContractError, ContractResult},
-    state::ENTRY_POINT_CONTRACT_ADDRESS,
+    state::{
+        ALLOWED_INBOUND_SENDERS, ALLOWED_INBOUND_WARP_ROUTES, CONSUMED_WARP_MESSAGES,
+        ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_CW20_COIN, IN_PROGRESS_FUNDS,
+        IN_PROGRESS_RECOVER_ADDRESS, IN_PROGRESS_WARP_ADDRESS, MAILBOX_CONTRACT_ADDRESS,
+    },
+};
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Addr, Api, BankMsg, Binary, Coin, Coins, Deps, DepsMut,
+    Env, HexBinary, MessageInfo, Reply, Response, SubMsg, SubMsgResult, Uint128, Uint256, WasmMsg,
 };
-use cosmwasm_std::{entry_point, to_json_binary, DepsMut, Env, HexBinary, MessageInfo, Response};
 use cw2::set_contract_version;
+use cw20::{Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
 use cw_utils::one_coin;
-use hpl_interface::warp::native::ExecuteMsg::TransferRemote;
+use hpl_interface::{
+    connection::{ConnectionQueryMsg, MailboxResponse},
+    core::{
+        mailbox::{
+            DispatchMsg, MailboxQueryMsg, MessageDeliveredResponse, QueryMsg as MailboxQueryWrapper,
+        },
+        HandleMsg,
+    },
+    hook::QuoteDispatchResponse,
+    ism::{InterchainSecurityModuleResponse, IsmSpecifierQueryMsg},
+    router::{RouteResponse, RouterQuery},
+    types::Message,
+    warp::{
+        cw20::ExecuteMsg::TransferRemote as Cw20TransferRemote,
+        native::{ExecuteMsg::TransferRemote as NativeTransferRemote, QueryMsg as WarpQueryMsg},
+        TokenMessage, TokenType, TokenTypeNative, TokenTypeResponse, TokenWarpDefaultQueryMsg,
+    },
+};
 use skip::{
+    admin::assert_contract_admin,
     asset::Asset,
-    hyperlane::{ExecuteMsg, InstantiateMsg, MigrateMsg},
+    entry_point::{Cw20HookMsg as EntryPointCw20HookMsg, ExecuteMsg as EntryPointExecuteMsg},
+    hyperlane::{
+        Cw20HookMsg, ExecuteMsg, HplInboundSender, HplSwapAndAction, InstantiateMsg, MigrateMsg,
+        QueryMsg,
+    },
 };
 
 ///////////////
@@ -27,12 +57,27 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> ContractResult<Resp
     // Store the entry point contract address
     ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
 
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_attribute("action", "migrate")
         .add_attribute(
             "entry_point_contract_address",
             checked_entry_point_contract_address.to_string(),
-        ))
+        );
+
+    // Validate and store the mailbox and the senders and warp routes allowed for inbound messages
+    if let Some(checked_mailbox_contract_address) = save_inbound_config(
+        deps,
+        msg.mailbox_contract_address,
+        msg.allowed_inbound_senders,
+        msg.allowed_inbound_warp_routes,
+    )? {
+        response = response.add_attribute(
+            "mailbox_contract_address",
+            checked_mailbox_contract_address.to_string(),
+        );
+    }
+
+    Ok(response)
 }
 
 /////////////////
@@ -43,6 +88,9 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> ContractResult<Resp
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Reply id used for the warp route transfer sub message
+const REPLY_ID: u64 = 1;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -60,12 +108,27 @@ pub fn instantiate(
     // Store the entry point contract address
     ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
 
-    Ok(Response::new()
+    let mut response = Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute(
             "entry_point_contract_address",
             checked_entry_point_contract_address.to_string(),
-        ))
+        );
+
+    // Validate and store the mailbox and the senders and warp routes allowed for inbound messages
+    if let Some(checked_mailbox_contract_address) = save_inbound_config(
+        deps,
+        msg.mailbox_contract_address,
+        msg.allowed_inbound_senders,
+        msg.allowed_inbound_warp_routes,
+    )? {
+        response = response.add_attribute(
+            "mailbox_contract_address",
+            checked_mailbox_contract_address.to_string(),
+        );
+    }
+
+    Ok(response)
 }
 
 ///////////////
@@ -75,63 +138,631 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
         ExecuteMsg::HplTransfer {
             dest_domain,
             recipient,
             hook,
             metadata,
             warp_address,
-        } => execute_hpl_transfer(
+            recover_address,
+            fee,
+        } => {
+            // Get the asset from the info
+            let asset: Asset = one_coin(&info)?.into();
+
+            execute_hpl_transfer(
+                deps,
+                info.sender,
+                asset,
+                dest_domain,
+                recipient,
+                hook,
+                metadata,
+                warp_address,
+                recover_address,
+                fee,
+            )
+        }
+        ExecuteMsg::Handle(HandleMsg {
+            origin,
+            sender,
+            body,
+        }) => execute_handle(deps, env, info, origin, sender, body),
+        ExecuteMsg::UpdateInboundConfig {
+            mailbox_contract_address,
+            allowed_inbound_senders,
+            allowed_inbound_warp_routes,
+        } => execute_update_inbound_config(
             deps,
+            env,
             info,
+            mailbox_contract_address,
+            allowed_inbound_senders,
+            allowed_inbound_warp_routes,
+        ),
+    }
+}
+
+// Receives cw20 tokens and transfers them through a cw20 collateral warp route
+fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> ContractResult<Response> {
+    let sent_asset = Asset::Cw20(Cw20Coin {
+        address: info.sender.to_string(),
+        amount: cw20_msg.amount,
+    });
+    sent_asset.validate(&deps, &env, &info)?;
+
+    // Set the sender to the originating address that triggered the cw20 send call
+    // This is later validated / enforced to be the entry point contract address
+    let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+
+    match from_json(&cw20_msg.msg)? {
+        Cw20HookMsg::HplTransfer {
+            dest_domain,
+            recipient,
+            hook,
+            metadata,
+            warp_address,
+            recover_address,
+            fee,
+        } => execute_hpl_transfer(
+            deps,
+            sender,
+            sent_asset,
             dest_domain,
             recipient,
             hook,
             metadata,
             warp_address,
+            recover_address,
+            fee,
         ),
     }
 }
 
-// Converts the given info and coin into a Hyperlane remote transfer
+// Converts the given asset into a Hyperlane remote transfer, attaching the
+// interchain gas payment held by the contract. If a recover address is given,
+// saves the assets and recover address in case the transfer fails and emits
+// the transfer message as a sub message always replying, otherwise emits
+// the transfer message as a plain message
+#[allow(clippy::too_many_arguments)]
 fn execute_hpl_transfer(
     deps: DepsMut,
-    info: MessageInfo,
+    sender: Addr,
+    asset: Asset,
     dest_domain: u32,
     recipient: HexBinary,
     hook: Option<String>,
     metadata: Option<HexBinary>,
     warp_address: String,
+    recover_address: Option<String>,
+    fee: Option<Coin>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
 
     // Enforce the caller is the entry point contract
-    if info.sender != entry_point_contract_address {
+    if sender != entry_point_contract_address {
         return Err(ContractError::Unauthorized);
     }
 
-    // Get the asset from the info
-    let asset: Asset = one_coin(&info)?.into();
+    // Query the interchain gas payment quoted for the transfer
+    let quoted_fees = query_interchain_gas_payment(
+        deps.as_ref(),
+        &warp_address,
+        dest_domain,
+        &recipient,
+        asset.amount(),
+        &hook,
+        &metadata,
+    )?;
+
+    // Error if the interchain gas payment does not cover the quoted fees
+    for quoted_fee in quoted_fees
+        .iter()
+        .filter(|quoted_fee| !quoted_fee.amount.is_zero())
+    {
+        match &fee {
+            Some(fee) if fee.denom == quoted_fee.denom && fee.amount >= quoted_fee.amount => {}
+            _ => {
+                return Err(ContractError::InsufficientInterchainGasPayment {
+                    quoted_fee: quoted_fee.to_string(),
+                })
+            }
+        }
+    }
+
+    // Attach the interchain gas payment, and the transferred coin for native warp
+    // routes, the warp route forwards any funds beyond the transfer amount to the
+    // mailbox hook
+    let mut funds = Coins::default();
+    if let Some(fee) = fee {
+        funds.add(fee)?;
+    }
+
+    let mut response = Response::new();
 
     // Create the Hyperlane remote transfer message
-    let msg = to_json_binary(&TransferRemote {
-        dest_domain,
-        recipient,
-        amount: asset.amount(),
-        hook,
-        metadata,
-    })?;
+    let msg = match &asset {
+        Asset::Native(coin) => {
+            funds.add(coin.clone())?;
+
+            to_json_binary(&NativeTransferRemote {
+                dest_domain,
+                recipient,
+                amount: coin.amount,
+                hook,
+                metadata,
+            })?
+        }
+        Asset::Cw20(cw20_coin) => {
+            // Cw20 collateral warp routes pull the transferred tokens from
+            // the sender, so allow the warp route to spend the tokens
+            let increase_allowance_msg = WasmMsg::Execute {
+                contract_addr: cw20_coin.address.clone(),
+                msg: to_json_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: warp_address.clone(),
+                    amount: cw20_coin.amount,
+                    expires: None,
+                })?,
+                funds: vec![],
+            };
+
+            response = response.add_message(increase_allowance_msg);
+
+            to_json_binary(&Cw20TransferRemote {
+                dest_domain,
+                recipient,
+                amount: cw20_coin.amount,
+                hook,
+                metadata,
+            })?
+        }
+    };
 
     // Convert the hyperlane transfer message into a wasm message
-    let hpl_msg = asset.into_wasm_msg(warp_address, msg)?;
+    let hpl_msg = WasmMsg::Execute {
+        contract_addr: warp_address.clone(),
+        msg,
+        funds: funds.to_vec(),
+    };
 
-    Ok(Response::new()
-        .add_message(hpl_msg)
+    // Without a recover address, a failed transfer reverts the whole transaction
+    let Some(recover_address) = recover_address else {
+        return Ok(response
+            .add_message(hpl_msg)
+            .add_attribute("action", "execute_hyperlane_transfer"));
+    };
+
+    // Save the in progress recover address, asset and funds to storage, to be used in the reply
+    IN_PROGRESS_RECOVER_ADDRESS.save(
+        deps.storage,
+        &recover_address, // This address is verified in entry point
+    )?;
+    IN_PROGRESS_FUNDS.save(deps.storage, &funds.to_vec())?;
+    if let Asset::Cw20(cw20_coin) = &asset {
+        IN_PROGRESS_CW20_COIN.save(deps.storage, cw20_coin)?;
+        IN_PROGRESS_WARP_ADDRESS.save(deps.storage, &warp_address)?;
+    }
+
+    // Create sub message from the hyperlane transfer message to always receive a reply,
+    // so the in progress items are removed from storage whether the transfer fails or not
+    let sub_msg = SubMsg::reply_always(hpl_msg, REPLY_ID);
+
+    Ok(response
+        .add_submessage(sub_msg)
         .add_attribute("action", "execute_hyperlane_transfer"))
 }
+
+// Handles an inbound Hyperlane message delivered by the mailbox, swapping and
+// actioning the asset received from the warp route through the entry point
+fn execute_handle(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    origin: u32,
+    sender: HexBinary,
+    body: HexBinary,
+) -> ContractResult<Response> {
+    // Get mailbox contract address from storage, inbound messages
+    // are only enabled once a mailbox is set
+    let mailbox_contract_address = MAILBOX_CONTRACT_ADDRESS
+        .may_load(deps.storage)?
+        .ok_or(ContractError::InboundMessagesNotEnabled)?;
+
+    // Enforce the caller is the mailbox contract
+    if info.sender != mailbox_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Enforce the remote sender is allowed to send inbound messages from the origin domain
+    if !ALLOWED_INBOUND_SENDERS.has(deps.storage, (origin, sender.to_hex())) {
+        return Err(ContractError::InboundSenderNotAllowed {
+            origin,
+            sender: sender.to_hex(),
+        });
+    }
+
+    // Decode the swap and action from the message body
+    let HplSwapAndAction {
+        sent_asset,
+        user_swap,
+        min_asset,
+        timeout_timestamp,
+        post_swap_action,
+        affiliates,
+        recovery_addr,
+        warp_address,
+        warp_message,
+    } = from_json(body.as_slice())?;
+
+    // Validate the recovery address
+    let recovery_addr = deps.api.addr_validate(&recovery_addr)?;
+
+    // Verify the warp route message delivered the sent asset to this contract,
+    // consuming it so the asset cannot fund any other inbound message
+    let warp_message_id = consume_warp_message(
+        deps.branch(),
+        &env,
+        &mailbox_contract_address,
+        origin,
+        &sent_asset,
+        warp_address,
+        warp_message,
+    )?;
+
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Create the entry point swap and action message, recovering the sent
+    // asset to the recovery address if the swap or action fails
+    let msg = match &sent_asset {
+        Asset::Native(_) => to_json_binary(&EntryPointExecuteMsg::SwapAndActionWithRecover {
+            sent_asset: Some(sent_asset.clone()),
+            user_swap,
+            min_asset,
+            timeout_timestamp,
+            post_swap_action,
+            affiliates,
+            recovery_addr,
+        })?,
+        Asset::Cw20(_) => to_json_binary(&EntryPointCw20HookMsg::SwapAndActionWithRecover {
+            user_swap,
+            min_asset,
+            timeout_timestamp,
+            post_swap_action,
+            affiliates,
+            recovery_addr,
+        })?,
+    };
+
+    // Send the sent asset to the entry point with the swap and action message
+    let entry_point_msg =
+        sent_asset.into_wasm_msg(entry_point_contract_address.to_string(), msg)?;
+
+    Ok(Response::new()
+        .add_message(entry_point_msg)
+        .add_attribute("action", "execute_hyperlane_handle")
+        .add_attribute("origin", origin.to_string())
+        .add_attribute("sender", sender.to_hex())
+        .add_attribute("warp_message_id", warp_message_id.to_hex()))
+}
+
+// Replaces the mailbox and the senders and warp routes allowed for inbound
+// messages, only callable by the contract admin
+fn execute_update_inbound_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mailbox_contract_address: Option<String>,
+    allowed_inbound_senders: Vec<HplInboundSender>,
+    allowed_inbound_warp_routes: Vec<String>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract admin
+    assert_contract_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let mut response = Response::new().add_attribute("action", "update_inbound_config");
+
+    // Validate and store the mailbox and the senders and warp routes allowed for inbound messages
+    if let Some(checked_mailbox_contract_address) = save_inbound_config(
+        deps,
+        mailbox_contract_address,
+        allowed_inbound_senders,
+        allowed_inbound_warp_routes,
+    )? {
+        response = response.add_attribute(
+            "mailbox_contract_address",
+            checked_mailbox_contract_address.to_string(),
+        );
+    }
+
+    Ok(response)
+}
+
+/////////////
+/// REPLY ///
+/////////////
+
+// Handles the reply from the hyperlane transfer sub message
+// The in progress items are removed from storage on success. A failed sub message
+// is reverted, so the transferred asset and interchain gas payment are still held
+// by this contract and are sent to the recover address.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response> {
+    // Error if the reply id is not the same as the one used in the sub message dispatched
+    // This should never happen since we are using a constant reply id, but added in case
+    // the wasm module doesn't behave as expected.
+    if reply.id != REPLY_ID {
+        unreachable!()
+    }
+
+    // Get and delete the in progress recover address, funds, cw20 coin and warp address from storage
+    let in_progress_recover_address = IN_PROGRESS_RECOVER_ADDRESS.load(deps.storage)?;
+    IN_PROGRESS_RECOVER_ADDRESS.remove(deps.storage);
+
+    let in_progress_funds = IN_PROGRESS_FUNDS.load(deps.storage)?;
+    IN_PROGRESS_FUNDS.remove(deps.storage);
+
+    let in_progress_cw20_coin = IN_PROGRESS_CW20_COIN.may_load(deps.storage)?;
+    IN_PROGRESS_CW20_COIN.remove(deps.storage);
+
+    // The warp address is only saved with a cw20 coin, to reset its allowance
+    let in_progress_warp_address = match &in_progress_cw20_coin {
+        Some(_) => Some(IN_PROGRESS_WARP_ADDRESS.load(deps.storage)?),
+        None => None,
+    };
+    IN_PROGRESS_WARP_ADDRESS.remove(deps.storage);
+
+    // Nothing is left to recover if the transfer succeeded
+    let SubMsgResult::Err(error) = reply.result else {
+        return Ok(Response::new()
+            .add_attribute("action", "hyperlane_transfer_succeeded")
+            .add_attribute("recover_address", in_progress_recover_address));
+    };
+
+    let mut response = Response::new();
+
+    // Create bank send message to send the funds back to the recover address
+    if !in_progress_funds.is_empty() {
+        response = response.add_message(BankMsg::Send {
+            to_address: in_progress_recover_address.clone(),
+            amount: in_progress_funds,
+        });
+    }
+
+    // Create cw20 messages to reset the allowance granted to the warp route, which
+    // is not reverted with the failed sub message, and to send the cw20 coin back
+    // to the recover address
+    if let (Some(in_progress_cw20_coin), Some(warp_address)) =
+        (in_progress_cw20_coin, in_progress_warp_address)
+    {
+        let decrease_allowance_msg = WasmMsg::Execute {
+            contract_addr: in_progress_cw20_coin.address.clone(),
+            msg: to_json_binary(&Cw20ExecuteMsg::DecreaseAllowance {
+                spender: warp_address,
+                amount: in_progress_cw20_coin.amount,
+                expires: None,
+            })?,
+            funds: vec![],
+        };
+
+        response = response
+            .add_message(decrease_allowance_msg)
+            .add_message(Asset::Cw20(in_progress_cw20_coin).transfer(&in_progress_recover_address));
+    }
+
+    Ok(response
+        .add_attribute("action", "hyperlane_transfer_failed_and_bank_send")
+        .add_attribute("recover_address", in_progress_recover_address)
+        .add_attribute("error", error))
+}
+
+/////////////
+/// QUERY ///
+/////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(_deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    match msg {
+        // No interchain security module is specified, so the mailbox
+        // verifies inbound messages with its default module
+        QueryMsg::IsmSpecifier(IsmSpecifierQueryMsg::InterchainSecurityModule()) => {
+            to_json_binary(&InterchainSecurityModuleResponse { ism: None })
+        }
+    }
+    .map_err(From::from)
+}
+
+////////////////////////
+/// HELPER FUNCTIONS ///
+////////////////////////
+
+// Validates and stores the mailbox allowed to deliver inbound messages, and
+// replaces the remote senders allowed to send them and the local warp routes
+// allowed to deliver their assets
+fn save_inbound_config(
+    deps: DepsMut,
+    mailbox_contract_address: Option<String>,
+    allowed_inbound_senders: Vec<HplInboundSender>,
+    allowed_inbound_warp_routes: Vec<String>,
+) -> ContractResult<Option<Addr>> {
+    let checked_mailbox_contract_address = match mailbox_contract_address {
+        Some(mailbox_contract_address) => {
+            let checked_mailbox_contract_address =
+                deps.api.addr_validate(&mailbox_contract_address)?;
+            MAILBOX_CONTRACT_ADDRESS.save(deps.storage, &checked_mailbox_contract_address)?;
+            Some(checked_mailbox_contract_address)
+        }
+        None => {
+            MAILBOX_CONTRACT_ADDRESS.remove(deps.storage);
+            None
+        }
+    };
+
+    ALLOWED_INBOUND_SENDERS.clear(deps.storage);
+    for allowed_inbound_sender in allowed_inbound_senders {
+        ALLOWED_INBOUND_SENDERS.save(
+            deps.storage,
+            (
+                allowed_inbound_sender.origin_domain,
+                allowed_inbound_sender.sender.to_hex(),
+            ),
+            &(),
+        )?;
+    }
+
+    ALLOWED_INBOUND_WARP_ROUTES.clear(deps.storage);
+    for allowed_inbound_warp_route in allowed_inbound_warp_routes {
+        let checked_warp_address = deps.api.addr_validate(&allowed_inbound_warp_route)?;
+        ALLOWED_INBOUND_WARP_ROUTES.save(deps.storage, &checked_warp_address, &())?;
+    }
+
+    Ok(checked_mailbox_contract_address)
+}
+
+// Verifies the warp route message was delivered by the mailbox to an allowed
+// warp route, and transferred exactly the sent asset to this contract from the
+// origin domain of the inbound message. The warp route message is then stored
+// as consumed, so each warp route delivery funds a single inbound message.
+fn consume_warp_message(
+    deps: DepsMut,
+    env: &Env,
+    mailbox_contract_address: &Addr,
+    origin: u32,
+    sent_asset: &Asset,
+    warp_address: String,
+    warp_message: HexBinary,
+) -> ContractResult<HexBinary> {
+    // Enforce the warp route is allowed to deliver the assets of inbound messages
+    let checked_warp_address = deps.api.addr_validate(&warp_address)?;
+    if !ALLOWED_INBOUND_WARP_ROUTES.has(deps.storage, &checked_warp_address) {
+        return Err(ContractError::InboundWarpRouteNotAllowed { warp_address });
+    }
+
+    // Error if the warp route message already funded another inbound message
+    let warp_message = Message::from(warp_message);
+    let warp_message_id = warp_message.id();
+    if CONSUMED_WARP_MESSAGES.has(deps.storage, warp_message_id.to_hex()) {
+        return Err(ContractError::InboundWarpMessageConsumed {
+            id: warp_message_id.to_hex(),
+        });
+    }
+
+    // Get the denom of the asset the warp route delivers
+    let TokenTypeResponse { typ } = deps.querier.query_wasm_smart(
+        &checked_warp_address,
+        &WarpQueryMsg::TokenDefault(TokenWarpDefaultQueryMsg::TokenType {}),
+    )?;
+    let warp_denom = match typ {
+        TokenType::Native(TokenTypeNative::Fungible { denom }) => Some(denom),
+        TokenType::CW20 { contract } => Some(contract),
+        _ => None,
+    };
+
+    // Error if the warp route message is not a transfer of the sent asset to this
+    // contract, sent from the origin domain to the warp route
+    let token_message = TokenMessage::from(warp_message.body.clone());
+    if warp_message.origin_domain != origin
+        || warp_message.recipient != to_bytes32(deps.api, &checked_warp_address)?
+        || token_message.recipient != to_bytes32(deps.api, &env.contract.address)?
+        || token_message.amount != Uint256::from(sent_asset.amount())
+        || warp_denom.as_deref() != Some(sent_asset.denom())
+    {
+        return Err(ContractError::InboundWarpMessageMismatch);
+    }
+
+    // Error if the warp route message has not been delivered yet, it may be
+    // processed after this message, so erroring leaves the message undelivered
+    // for the relayer to retry
+    let MessageDeliveredResponse { delivered } = deps.querier.query_wasm_smart(
+        mailbox_contract_address,
+        &MailboxQueryWrapper::Mailbox(MailboxQueryMsg::MessageDelivered {
+            id: warp_message_id.clone(),
+        }),
+    )?;
+    if !delivered {
+        return Err(ContractError::InboundAssetNotReceived {
+            amount: sent_asset.amount(),
+            denom: sent_asset.denom().to_string(),
+        });
+    }
+
+    // Store the warp route message as consumed
+    CONSUMED_WARP_MESSAGES.save(deps.storage, warp_message_id.to_hex(), &())?;
+
+    Ok(warp_message_id)
+}
+
+// Converts the address into the 32 byte address Hyperlane messages use,
+// left padding the canonical address with zeros
+fn to_bytes32(api: &dyn Api, address: &Addr) -> ContractResult<HexBinary> {
+    let canonical_address = api.addr_canonicalize(address.as_str())?;
+
+    let mut bytes32 = vec![0u8; 32usize.saturating_sub(canonical_address.len())];
+    bytes32.extend_from_slice(canonical_address.as_slice());
+
+    Ok(bytes32.into())
+}
+
+// Queries the fees the warp route's mailbox quotes for dispatching the transfer,
+// which includes the interchain gas payment of the warp route's hook
+#[allow(clippy::too_many_arguments)]
+fn query_interchain_gas_payment(
+    deps: Deps,
+    warp_address: &str,
+    dest_domain: u32,
+    recipient: &HexBinary,
+    amount: Uint128,
+    hook: &Option<String>,
+    metadata: &Option<HexBinary>,
+) -> ContractResult<Vec<Coin>> {
+    // Get the mailbox the warp route dispatches through
+    let MailboxResponse { mailbox } = deps.querier.query_wasm_smart(
+        warp_address,
+        &WarpQueryMsg::Connection(ConnectionQueryMsg::GetMailbox {}),
+    )?;
+    let mailbox = mailbox.ok_or(ContractError::WarpRouteMailboxNotSet)?;
+
+    // Get the remote router the warp route dispatches to for the destination domain
+    let RouteResponse { route } = deps.querier.query_wasm_smart(
+        warp_address,
+        &WarpQueryMsg::Router(RouterQuery::GetRoute {
+            domain: dest_domain,
+        }),
+    )?;
+    let dest_router: HexBinary = route
+        .route
+        .ok_or(ContractError::WarpRouteNotFound { dest_domain })?;
+
+    // Quote the dispatch of the warp route's token message, sent by the warp route
+    let QuoteDispatchResponse { fees } = deps.querier.query_wasm_smart(
+        mailbox,
+        &MailboxQueryWrapper::Mailbox(MailboxQueryMsg::QuoteDispatch {
+            sender: warp_address.to_string(),
+            msg: DispatchMsg {
+                dest_domain,
+                recipient_addr: dest_router,
+                msg_body: TokenMessage {
+                    recipient: recipient.clone(),
+                    amount: Uint256::from(amount),
+                    metadata: HexBinary::default(),
+                }
+                .into(),
+                hook: hook.clone(),
+                metadata: metadata.clone(),
+            },
+        }),
+    )?;
+
+    Ok(fees)
+}
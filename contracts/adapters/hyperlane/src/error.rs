@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 use skip::error::SkipError;
 use thiserror::Error;
 
@@ -17,4 +17,31 @@ pub enum ContractError {
 
     #[error(transparent)]
     Payment(#[from] cw_utils::PaymentError),
+
+    #[error("Warp Route Has No Mailbox Set")]
+    WarpRouteMailboxNotSet,
+
+    #[error("Warp Route Has No Route For Destination Domain {dest_domain}")]
+    WarpRouteNotFound { dest_domain: u32 },
+
+    #[error("Interchain Gas Payment Does Not Cover Quoted Fee Of {quoted_fee}")]
+    InsufficientInterchainGasPayment { quoted_fee: String },
+
+    #[error("Inbound Hyperlane Messages Are Not Enabled")]
+    InboundMessagesNotEnabled,
+
+    #[error("Inbound Hyperlane Message Sender {sender} From Domain {origin} Is Not Allowed")]
+    InboundSenderNotAllowed { origin: u32, sender: String },
+
+    #[error("Inbound Hyperlane Message Asset Of {amount} {denom} Has Not Been Received")]
+    InboundAssetNotReceived { amount: Uint128, denom: String },
+
+    #[error("Inbound Warp Route {warp_address} Is Not Allowed")]
+    InboundWarpRouteNotAllowed { warp_address: String },
+
+    #[error("Inbound Warp Route Message {id} Has Already Been Consumed")]
+    InboundWarpMessageConsumed { id: String },
+
+    #[error("Inbound Warp Route Message Does Not Transfer The Sent Asset To This Contract")]
+    InboundWarpMessageMismatch,
 }
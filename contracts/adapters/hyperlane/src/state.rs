@@ -1,4 +1,13 @@
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Coin};
+use cw20::Cw20Coin;
+use cw_storage_plus::{Item, Map};
 
 pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+pub const IN_PROGRESS_RECOVER_ADDRESS: Item<String> = Item::new("in_progress_recover_address");
+pub const IN_PROGRESS_FUNDS: Item<Vec<Coin>> = Item::new("in_progress_funds");
+pub const IN_PROGRESS_CW20_COIN: Item<Cw20Coin> = Item::new("in_progress_cw20_coin");
+pub const IN_PROGRESS_WARP_ADDRESS: Item<String> = Item::new("in_progress_warp_address");
+pub const MAILBOX_CONTRACT_ADDRESS: Item<Addr> = Item::new("mailbox_contract_address");
+pub const ALLOWED_INBOUND_SENDERS: Map<(u32, String), ()> = Map::new("allowed_inbound_senders");
+pub const ALLOWED_INBOUND_WARP_ROUTES: Map<&Addr, ()> = Map::new("allowed_inbound_warp_routes");
+pub const CONSUMED_WARP_MESSAGES: Map<String, ()> = Map::new("consumed_warp_messages");
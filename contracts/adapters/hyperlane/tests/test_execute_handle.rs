@@ -0,0 +1,561 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, mock_info, MockApi},
+    to_json_binary, Addr, Api, Binary, Coin, ContractResult as SystemContractResult, HexBinary,
+    QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Uint128, Uint256, WasmMsg, WasmQuery,
+};
+use cw20::{Cw20Coin, Cw20ExecuteMsg};
+use hpl_interface::{
+    core::{
+        mailbox::{MailboxQueryMsg, MessageDeliveredResponse, QueryMsg as MailboxQueryWrapper},
+        HandleMsg,
+    },
+    types::Message,
+    warp::{
+        native::QueryMsg as WarpQueryMsg, TokenMessage, TokenType, TokenTypeNative,
+        TokenTypeResponse, TokenWarpDefaultQueryMsg,
+    },
+};
+use skip::{
+    asset::Asset,
+    entry_point::{
+        Action, Cw20HookMsg as EntryPointCw20HookMsg, ExecuteMsg as EntryPointExecuteMsg,
+    },
+    hyperlane::{ExecuteMsg, HplInboundSender, HplSwapAndAction, InstantiateMsg},
+    swap::{Swap, SwapExactAssetIn, SwapOperation},
+};
+use skip_go_hyperlane_adapter::{error::ContractResult, state::CONSUMED_WARP_MESSAGES};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response (Output Message Is Correct, Warp Route Message Is Consumed)
+    - Native Asset Inbound Swap And Action
+    - Cw20 Asset Inbound Swap And Action
+
+Expect Error
+    - Mailbox Not Set (Inbound messages are only enabled once a mailbox is set)
+    - Unauthorized Caller (Only the stored mailbox contract can call handle)
+    - Sender Not Allowed
+    - Origin Domain Not Allowed (The sender is only allowed from its origin domain)
+    - Warp Route Not Allowed
+    - Warp Route Message Already Consumed (Another inbound message was funded by it)
+    - Warp Route Message From Another Origin Domain
+    - Warp Route Message Delivered To Another Warp Route
+    - Warp Route Message Transfer To Another Recipient
+    - Warp Route Message Transfer Amount Less Than Sent Asset
+    - Warp Route Message Transfer Of Another Denom
+    - Warp Route Message Not Delivered (The sent asset has not been received yet)
+
+ */
+
+// Define test parameters
+struct Params {
+    mailbox_contract_address: Option<String>,
+    caller: String,
+    origin: u32,
+    sender: HexBinary,
+    sent_asset: Asset,
+    warp_address: String,
+    warp_transfer: WarpTransfer,
+    warp_message_delivered: bool,
+    warp_message_consumed: bool,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Defines the warp route transfer carried by the warp route message named in the inbound message
+struct WarpTransfer {
+    origin: u32,
+    warp_address: String,
+    recipient: String,
+    amount: u128,
+}
+
+// Returns a warp route transfer from domain 1 to the hyperlane transfer adapter
+fn warp_transfer(warp_address: &str, amount: u128) -> WarpTransfer {
+    WarpTransfer {
+        origin: 1,
+        warp_address: warp_address.to_string(),
+        recipient: "hyperlane_transfer".to_string(),
+        amount,
+    }
+}
+
+// Returns the 32 byte address of the given address, left padded with zeros
+fn bytes32(address: &str) -> HexBinary {
+    let canonical_address = MockApi::default().addr_canonicalize(address).unwrap();
+
+    let mut bytes32 = vec![0u8; 32usize.saturating_sub(canonical_address.len())];
+    bytes32.extend_from_slice(canonical_address.as_slice());
+
+    bytes32.into()
+}
+
+// Returns the Hyperlane message the warp route is delivered the warp route transfer with
+fn warp_message(warp_transfer: &WarpTransfer) -> Message {
+    Message {
+        version: 3,
+        nonce: 7,
+        origin_domain: warp_transfer.origin,
+        sender: HexBinary::from_hex(
+            "0000000000000000000000000000000000000000000000000000000000000202",
+        )
+        .unwrap(),
+        dest_domain: 2,
+        recipient: bytes32(&warp_transfer.warp_address),
+        body: TokenMessage {
+            recipient: bytes32(&warp_transfer.recipient),
+            amount: Uint256::from(warp_transfer.amount),
+            metadata: HexBinary::default(),
+        }
+        .into(),
+    }
+}
+
+// Returns the 32 byte address of the remote sender allowed to send inbound messages
+fn allowed_sender() -> HexBinary {
+    HexBinary::from_hex("0000000000000000000000000000000000000000000000000000000000000101").unwrap()
+}
+
+fn user_swap() -> Swap {
+    Swap::SwapExactAssetIn(SwapExactAssetIn {
+        swap_venue_name: "swap_venue_name".to_string(),
+        operations: vec![SwapOperation {
+            pool: "pool".to_string(),
+            denom_in: "ua".to_string(),
+            denom_out: "uo".to_string(),
+            interface: None,
+        }],
+    })
+}
+
+fn min_asset() -> Asset {
+    Asset::Native(Coin::new(100, "uo"))
+}
+
+fn post_swap_action() -> Action {
+    Action::Transfer {
+        to_address: "to_address".to_string(),
+    }
+}
+
+// Answers the warp route token type queries and the mailbox message delivered query,
+// the warp_native route delivers ua and the warp_cw20 route delivers the neutron123 cw20
+fn mock_hyperlane_query(query: &WasmQuery, delivered_id: &Option<HexBinary>) -> QuerierResult {
+    let res: Binary = match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "mailbox" => {
+            match from_json(msg).unwrap() {
+                MailboxQueryWrapper::Mailbox(MailboxQueryMsg::MessageDelivered { id }) => {
+                    to_json_binary(&MessageDeliveredResponse {
+                        delivered: delivered_id.as_ref() == Some(&id),
+                    })
+                    .unwrap()
+                }
+                msg => panic!("Unsupported mailbox query: {:?}", msg),
+            }
+        }
+        WasmQuery::Smart { contract_addr, msg } => {
+            let typ = match contract_addr.as_str() {
+                "warp_native" => TokenType::Native(TokenTypeNative::Fungible {
+                    denom: "ua".to_string(),
+                }),
+                "warp_cw20" => TokenType::CW20 {
+                    contract: "neutron123".to_string(),
+                },
+                _ => panic!("Unsupported query: {:?}", query),
+            };
+            match from_json(msg).unwrap() {
+                WarpQueryMsg::TokenDefault(TokenWarpDefaultQueryMsg::TokenType {}) => {
+                    to_json_binary(&TokenTypeResponse { typ }).unwrap()
+                }
+                msg => panic!("Unsupported warp route query: {:?}", msg),
+            }
+        }
+        _ => panic!("Unsupported query: {:?}", query),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res))
+}
+
+// Test execute_handle
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "entry_point".to_string(),
+                msg: to_json_binary(&EntryPointExecuteMsg::SwapAndActionWithRecover {
+                    sent_asset: Some(Asset::Native(Coin::new(100, "ua"))),
+                    user_swap: user_swap(),
+                    min_asset: min_asset(),
+                    timeout_timestamp: 101,
+                    post_swap_action: post_swap_action(),
+                    affiliates: vec![],
+                    recovery_addr: Addr::unchecked("recovery_addr"),
+                })
+                .unwrap(),
+                funds: vec![Coin::new(100, "ua")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error_string: "".to_string(),
+    };
+    "Native Asset Inbound Swap And Action")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Cw20(Cw20Coin {
+            address: "neutron123".to_string(),
+            amount: Uint128::new(100),
+        }),
+        warp_address: "warp_cw20".to_string(),
+        warp_transfer: warp_transfer("warp_cw20", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "neutron123".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                    contract: "entry_point".to_string(),
+                    amount: Uint128::new(100),
+                    msg: to_json_binary(&EntryPointCw20HookMsg::SwapAndActionWithRecover {
+                        user_swap: user_swap(),
+                        min_asset: min_asset(),
+                        timeout_timestamp: 101,
+                        post_swap_action: post_swap_action(),
+                        affiliates: vec![],
+                        recovery_addr: Addr::unchecked("recovery_addr"),
+                    })
+                    .unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error_string: "".to_string(),
+    };
+    "Cw20 Asset Inbound Swap And Action")]
+#[test_case(
+    Params {
+        mailbox_contract_address: None,
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string: "Inbound Hyperlane Messages Are Not Enabled".to_string(),
+    };
+    "Mailbox Not Set - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "random".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: HexBinary::from_hex("0202").unwrap(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string: "Inbound Hyperlane Message Sender 0202 From Domain 1 Is Not Allowed"
+            .to_string(),
+    };
+    "Sender Not Allowed - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 2,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string: format!(
+            "Inbound Hyperlane Message Sender {} From Domain 2 Is Not Allowed",
+            allowed_sender().to_hex()
+        ),
+    };
+    "Origin Domain Not Allowed - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_random".to_string(),
+        warp_transfer: warp_transfer("warp_random", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string: "Inbound Warp Route warp_random Is Not Allowed".to_string(),
+    };
+    "Warp Route Not Allowed - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: true,
+        expected_messages: vec![],
+        expected_error_string: format!(
+            "Inbound Warp Route Message {} Has Already Been Consumed",
+            warp_message(&warp_transfer("warp_native", 100)).id().to_hex()
+        ),
+    };
+    "Warp Route Message Already Consumed - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: WarpTransfer {
+            origin: 3,
+            ..warp_transfer("warp_native", 100)
+        },
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string:
+            "Inbound Warp Route Message Does Not Transfer The Sent Asset To This Contract"
+                .to_string(),
+    };
+    "Warp Route Message From Another Origin Domain - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_cw20", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string:
+            "Inbound Warp Route Message Does Not Transfer The Sent Asset To This Contract"
+                .to_string(),
+    };
+    "Warp Route Message Delivered To Another Warp Route - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: WarpTransfer {
+            recipient: "random".to_string(),
+            ..warp_transfer("warp_native", 100)
+        },
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string:
+            "Inbound Warp Route Message Does Not Transfer The Sent Asset To This Contract"
+                .to_string(),
+    };
+    "Warp Route Message Transfer To Another Recipient - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 50),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string:
+            "Inbound Warp Route Message Does Not Transfer The Sent Asset To This Contract"
+                .to_string(),
+    };
+    "Warp Route Message Transfer Amount Less Than Sent Asset - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "uo")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: true,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string:
+            "Inbound Warp Route Message Does Not Transfer The Sent Asset To This Contract"
+                .to_string(),
+    };
+    "Warp Route Message Transfer Of Another Denom - Expect Error")]
+#[test_case(
+    Params {
+        mailbox_contract_address: Some("mailbox".to_string()),
+        caller: "mailbox".to_string(),
+        origin: 1,
+        sender: allowed_sender(),
+        sent_asset: Asset::Native(Coin::new(100, "ua")),
+        warp_address: "warp_native".to_string(),
+        warp_transfer: warp_transfer("warp_native", 100),
+        warp_message_delivered: false,
+        warp_message_consumed: false,
+        expected_messages: vec![],
+        expected_error_string: "Inbound Hyperlane Message Asset Of 100 ua Has Not Been Received"
+            .to_string(),
+    };
+    "Warp Route Message Not Delivered - Expect Error")]
+fn test_execute_handle(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create the warp route message named in the inbound message
+    let warp_message = warp_message(&params.warp_transfer);
+    let warp_message_id = warp_message.id();
+
+    // Update querier with the mock hyperlane query handler, the mailbox
+    // reports the warp route message delivered if set in the params
+    let delivered_id = params
+        .warp_message_delivered
+        .then(|| warp_message_id.clone());
+    deps.querier
+        .update_wasm(move |query: &WasmQuery| mock_hyperlane_query(query, &delivered_id));
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("hyperlane_transfer");
+
+    // Instantiate the contract with the mailbox, allowed inbound sender and warp routes
+    skip_go_hyperlane_adapter::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            entry_point_contract_address: "entry_point".to_string(),
+            mailbox_contract_address: params.mailbox_contract_address,
+            allowed_inbound_senders: vec![HplInboundSender {
+                origin_domain: 1,
+                sender: allowed_sender(),
+            }],
+            allowed_inbound_warp_routes: vec!["warp_native".to_string(), "warp_cw20".to_string()],
+        },
+    )?;
+
+    // Store the warp route message as consumed if set in the params
+    if params.warp_message_consumed {
+        CONSUMED_WARP_MESSAGES.save(deps.as_mut().storage, warp_message_id.to_hex(), &())?;
+    }
+
+    // Create mock info with the caller
+    let info = mock_info(&params.caller, &[]);
+
+    // Call handle with the given test parameters
+    let res = skip_go_hyperlane_adapter::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Handle(HandleMsg {
+            origin: params.origin,
+            sender: params.sender,
+            body: to_json_binary(&HplSwapAndAction {
+                sent_asset: params.sent_asset,
+                user_swap: user_swap(),
+                min_asset: min_asset(),
+                timeout_timestamp: 101,
+                post_swap_action: post_swap_action(),
+                affiliates: vec![],
+                recovery_addr: "recovery_addr".to_string(),
+                warp_address: params.warp_address,
+                warp_message: warp_message.into(),
+            })?
+            .into(),
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Assert the warp route message is stored as consumed
+            assert!(CONSUMED_WARP_MESSAGES.has(deps.as_ref().storage, warp_message_id.to_hex()));
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
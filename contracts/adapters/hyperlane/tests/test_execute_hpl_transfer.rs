@@ -0,0 +1,305 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Binary, Coin, ContractResult as SystemContractResult, CosmosMsg,
+    HexBinary, QuerierResult, SubMsg, SystemResult, WasmMsg, WasmQuery,
+};
+use hpl_interface::{
+    connection::{ConnectionQueryMsg, MailboxResponse},
+    hook::QuoteDispatchResponse,
+    router::{DomainRouteSet, RouteResponse, RouterQuery},
+    warp::native::{ExecuteMsg::TransferRemote, QueryMsg as WarpQueryMsg},
+};
+use skip::hyperlane::ExecuteMsg;
+use skip_go_hyperlane_adapter::{
+    error::ContractResult,
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_FUNDS, IN_PROGRESS_RECOVER_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response (Output Message Is Correct, In Progress Recover Address And Funds Are Saved, No Error)
+    - Hyperlane Transfer With Interchain Gas Payment Of Same Denom
+    - Hyperlane Transfer With Interchain Gas Payment Of Different Denom
+    - Hyperlane Transfer With Interchain Gas Payment Greater Than Quoted Fee
+
+Expect Response (Output Message Is Not A Sub Message, Nothing Is Saved, No Error)
+    - Hyperlane Transfer Without Recover Address
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Interchain Gas Payment
+    - Interchain Gas Payment Less Than Quoted Fee
+    - Interchain Gas Payment Of Different Denom Than Quoted Fee
+    - No Warp Route For Destination Domain
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    dest_domain: u32,
+    fee: Option<Coin>,
+    recover_address: Option<String>,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Returns the expected warp route transfer message attaching the given funds,
+// as a sub message always replying if a recover address is given
+fn expected_messages(amount: u128, funds: Vec<Coin>, recover_address: bool) -> Vec<SubMsg> {
+    let msg: CosmosMsg = WasmMsg::Execute {
+        contract_addr: "warp_address".to_string(),
+        msg: to_json_binary(&TransferRemote {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex("0101").unwrap(),
+            amount: amount.into(),
+            hook: None,
+            metadata: None,
+        })
+        .unwrap(),
+        funds,
+    }
+    .into();
+
+    if recover_address {
+        vec![SubMsg::reply_always(msg, 1)]
+    } else {
+        vec![SubMsg::new(msg)]
+    }
+}
+
+// Answers the warp route and mailbox queries, quoting a fee of 50untrn
+fn mock_hyperlane_query(query: &WasmQuery) -> QuerierResult {
+    let res: Binary = match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "warp_address" => {
+            match from_json(msg).unwrap() {
+                WarpQueryMsg::Connection(ConnectionQueryMsg::GetMailbox {}) => {
+                    to_json_binary(&MailboxResponse {
+                        mailbox: Some("mailbox".to_string()),
+                    })
+                    .unwrap()
+                }
+                WarpQueryMsg::Router(RouterQuery::GetRoute { domain }) => {
+                    to_json_binary(&RouteResponse {
+                        route: DomainRouteSet {
+                            domain,
+                            route: (domain == 1).then(|| HexBinary::from_hex("02").unwrap()),
+                        },
+                    })
+                    .unwrap()
+                }
+                msg => panic!("Unsupported warp route query: {:?}", msg),
+            }
+        }
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == "mailbox" => {
+            to_json_binary(&QuoteDispatchResponse {
+                fees: vec![Coin::new(50, "untrn")],
+            })
+            .unwrap()
+        }
+        _ => panic!("Unsupported query: {:?}", query),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res))
+}
+
+// Test execute_hpl_transfer
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "untrn")],
+        dest_domain: 1,
+        fee: Some(Coin::new(50, "untrn")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: expected_messages(100, vec![Coin::new(150, "untrn")], true),
+        expected_error_string: "".to_string(),
+    };
+    "Hyperlane Transfer With Interchain Gas Payment Of Same Denom")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: Some(Coin::new(50, "untrn")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: expected_messages(
+            100,
+            vec![Coin::new(100, "uatom"), Coin::new(50, "untrn")],
+            true,
+        ),
+        expected_error_string: "".to_string(),
+    };
+    "Hyperlane Transfer With Interchain Gas Payment Of Different Denom")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: Some(Coin::new(60, "untrn")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: expected_messages(
+            100,
+            vec![Coin::new(100, "uatom"), Coin::new(60, "untrn")],
+            true,
+        ),
+        expected_error_string: "".to_string(),
+    };
+    "Hyperlane Transfer With Interchain Gas Payment Greater Than Quoted Fee")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: Some(Coin::new(50, "untrn")),
+        recover_address: None,
+        expected_messages: expected_messages(
+            100,
+            vec![Coin::new(100, "uatom"), Coin::new(50, "untrn")],
+            false,
+        ),
+        expected_error_string: "".to_string(),
+    };
+    "Hyperlane Transfer Without Recover Address")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: Some(Coin::new(50, "untrn")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: None,
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: vec![],
+        expected_error_string: "Interchain Gas Payment Does Not Cover Quoted Fee Of 50untrn"
+            .to_string(),
+    };
+    "No Interchain Gas Payment - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: Some(Coin::new(49, "untrn")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: vec![],
+        expected_error_string: "Interchain Gas Payment Does Not Cover Quoted Fee Of 50untrn"
+            .to_string(),
+    };
+    "Interchain Gas Payment Less Than Quoted Fee - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 1,
+        fee: Some(Coin::new(50, "uatom")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: vec![],
+        expected_error_string: "Interchain Gas Payment Does Not Cover Quoted Fee Of 50untrn"
+            .to_string(),
+    };
+    "Interchain Gas Payment Of Different Denom Than Quoted Fee - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        dest_domain: 2,
+        fee: Some(Coin::new(50, "untrn")),
+        recover_address: Some("recover_address".to_string()),
+        expected_messages: vec![],
+        expected_error_string: "Warp Route Has No Route For Destination Domain 2".to_string(),
+    };
+    "No Warp Route For Destination Domain - Expect Error")]
+fn test_execute_hpl_transfer(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_hyperlane_query);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("hyperlane_transfer");
+
+    // Create mock info
+    let info = mock_info(&params.caller, &params.info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call execute_hpl_transfer with the given test parameters
+    let res = skip_go_hyperlane_adapter::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::HplTransfer {
+            dest_domain: params.dest_domain,
+            recipient: HexBinary::from_hex("0101").unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: params.recover_address.clone(),
+            fee: params.fee,
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Without a recover address, nothing is saved for the reply
+            let Some(recover_address) = params.recover_address else {
+                assert!(IN_PROGRESS_RECOVER_ADDRESS
+                    .may_load(&deps.storage)?
+                    .is_none());
+                assert!(IN_PROGRESS_FUNDS.may_load(&deps.storage)?.is_none());
+                return Ok(());
+            };
+
+            // Load the in progress recover address from state and verify it is correct
+            let stored_in_progress_recover_address =
+                IN_PROGRESS_RECOVER_ADDRESS.load(&deps.storage)?;
+            assert_eq!(stored_in_progress_recover_address, recover_address);
+
+            // Load the in progress funds from state and verify they are the attached funds
+            let stored_in_progress_funds = IN_PROGRESS_FUNDS.load(&deps.storage)?;
+            let CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) = &params.expected_messages[0].msg
+            else {
+                unreachable!()
+            };
+            assert_eq!(&stored_in_progress_funds, funds);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,245 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Binary, Coin, ContractResult as SystemContractResult, HexBinary,
+    QuerierResult,
+    ReplyOn::{Always, Never},
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20ReceiveMsg};
+use hpl_interface::{
+    connection::{ConnectionQueryMsg, MailboxResponse},
+    hook::QuoteDispatchResponse,
+    router::{DomainRouteSet, RouteResponse, RouterQuery},
+    warp::{cw20::ExecuteMsg::TransferRemote, native::QueryMsg as WarpQueryMsg},
+};
+use skip::hyperlane::{Cw20HookMsg, ExecuteMsg};
+use skip_go_hyperlane_adapter::{
+    error::ContractResult,
+    state::{
+        ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_CW20_COIN, IN_PROGRESS_FUNDS,
+        IN_PROGRESS_RECOVER_ADDRESS, IN_PROGRESS_WARP_ADDRESS,
+    },
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response (Output Messages Are Correct, In Progress Recover Address, Funds, Cw20 Coin And Warp Address Are Saved, No Error)
+    - Cw20 Hyperlane Transfer With Interchain Gas Payment
+
+Expect Error
+    - Unauthorized Cw20 Sender (Only the stored entry point contract can send cw20 tokens)
+    - Native Funds Sent With Cw20 Receive
+    - Interchain Gas Payment Less Than Quoted Fee
+
+ */
+
+// Define test parameters
+struct Params {
+    cw20_sender: String,
+    info_funds: Vec<Coin>,
+    fee: Option<Coin>,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Answers the cw20 balance, warp route and mailbox queries, quoting a fee of 50untrn
+fn mock_hyperlane_query(query: &WasmQuery) -> QuerierResult {
+    let res: Binary = match query {
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == "neutron123" => {
+            to_json_binary(&BalanceResponse {
+                balance: Uint128::new(100),
+            })
+            .unwrap()
+        }
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == "warp_address" => {
+            match from_json(msg).unwrap() {
+                WarpQueryMsg::Connection(ConnectionQueryMsg::GetMailbox {}) => {
+                    to_json_binary(&MailboxResponse {
+                        mailbox: Some("mailbox".to_string()),
+                    })
+                    .unwrap()
+                }
+                WarpQueryMsg::Router(RouterQuery::GetRoute { domain }) => {
+                    to_json_binary(&RouteResponse {
+                        route: DomainRouteSet {
+                            domain,
+                            route: Some(HexBinary::from_hex("02").unwrap()),
+                        },
+                    })
+                    .unwrap()
+                }
+                msg => panic!("Unsupported warp route query: {:?}", msg),
+            }
+        }
+        WasmQuery::Smart { contract_addr, .. } if contract_addr == "mailbox" => {
+            to_json_binary(&QuoteDispatchResponse {
+                fees: vec![Coin::new(50, "untrn")],
+            })
+            .unwrap()
+        }
+        _ => panic!("Unsupported query: {:?}", query),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res))
+}
+
+// Test receive_cw20
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        info_funds: vec![],
+        fee: Some(Coin::new(50, "untrn")),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "neutron123".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::IncreaseAllowance {
+                        spender: "warp_address".to_string(),
+                        amount: Uint128::new(100),
+                        expires: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 1,
+                msg: WasmMsg::Execute {
+                    contract_addr: "warp_address".to_string(),
+                    msg: to_json_binary(&TransferRemote {
+                        dest_domain: 1,
+                        recipient: HexBinary::from_hex("0101").unwrap(),
+                        amount: Uint128::new(100),
+                        hook: None,
+                        metadata: None,
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(50, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Always,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Cw20 Hyperlane Transfer With Interchain Gas Payment")]
+#[test_case(
+    Params {
+        cw20_sender: "random".to_string(),
+        info_funds: vec![],
+        fee: Some(Coin::new(50, "untrn")),
+        expected_messages: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Cw20 Sender - Expect Error")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        info_funds: vec![Coin::new(50, "untrn")],
+        fee: Some(Coin::new(50, "untrn")),
+        expected_messages: vec![],
+        expected_error_string: "This message does no accept funds".to_string(),
+    };
+    "Native Funds Sent With Cw20 Receive - Expect Error")]
+#[test_case(
+    Params {
+        cw20_sender: "entry_point".to_string(),
+        info_funds: vec![],
+        fee: Some(Coin::new(49, "untrn")),
+        expected_messages: vec![],
+        expected_error_string: "Interchain Gas Payment Does Not Cover Quoted Fee Of 50untrn"
+            .to_string(),
+    };
+    "Interchain Gas Payment Less Than Quoted Fee - Expect Error")]
+fn test_execute_receive(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_hyperlane_query);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("hyperlane_transfer");
+
+    // Create mock info with the cw20 contract as the sender
+    let info = mock_info("neutron123", &params.info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call receive with the given test parameters
+    let res = skip_go_hyperlane_adapter::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: params.cw20_sender,
+            amount: Uint128::new(100),
+            msg: to_json_binary(&Cw20HookMsg::HplTransfer {
+                dest_domain: 1,
+                recipient: HexBinary::from_hex("0101").unwrap(),
+                hook: None,
+                metadata: None,
+                warp_address: "warp_address".to_string(),
+                recover_address: Some("recover_address".to_string()),
+                fee: params.fee,
+            })?,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Verify the in progress recover address, funds, cw20 coin and warp address were saved
+            assert_eq!(
+                IN_PROGRESS_RECOVER_ADDRESS.load(&deps.storage)?,
+                "recover_address"
+            );
+            assert_eq!(
+                IN_PROGRESS_FUNDS.load(&deps.storage)?,
+                vec![Coin::new(50, "untrn")]
+            );
+            assert_eq!(
+                IN_PROGRESS_CW20_COIN.load(&deps.storage)?,
+                Cw20Coin {
+                    address: "neutron123".to_string(),
+                    amount: Uint128::new(100),
+                }
+            );
+            assert_eq!(
+                IN_PROGRESS_WARP_ADDRESS.load(&deps.storage)?,
+                "warp_address"
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, ContractInfoResponse, ContractResult as SystemContractResult, HexBinary,
+    Order, QuerierResult, SystemResult, WasmQuery,
+};
+use skip::hyperlane::{ExecuteMsg, HplInboundSender, InstantiateMsg};
+use skip_go_hyperlane_adapter::{
+    error::ContractResult,
+    state::{ALLOWED_INBOUND_SENDERS, ALLOWED_INBOUND_WARP_ROUTES, MAILBOX_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Replace Inbound Config
+    - Disable Inbound Messages (No mailbox, senders or warp routes are stored)
+
+Expect Error
+    - Unauthorized Caller (Only the contract admin can update the inbound config)
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    mailbox_contract_address: Option<String>,
+    allowed_inbound_senders: Vec<HplInboundSender>,
+    allowed_inbound_warp_routes: Vec<String>,
+    expected_allowed_inbound_senders: Vec<(u32, String)>,
+    expected_error_string: String,
+}
+
+// Returns the remote sender from domain 1 with the given 32 byte address
+fn inbound_sender(sender: &str) -> HplInboundSender {
+    HplInboundSender {
+        origin_domain: 1,
+        sender: HexBinary::from_hex(sender).unwrap(),
+    }
+}
+
+// Test execute_update_inbound_config, with the contract instantiated with
+// the mailbox, an allowed inbound sender and an allowed warp route
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        mailbox_contract_address: Some("new_mailbox".to_string()),
+        allowed_inbound_senders: vec![inbound_sender("0202")],
+        allowed_inbound_warp_routes: vec!["new_warp_route".to_string()],
+        expected_allowed_inbound_senders: vec![(1, "0202".to_string())],
+        expected_error_string: "".to_string(),
+    };
+    "Replace Inbound Config")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        mailbox_contract_address: None,
+        allowed_inbound_senders: vec![],
+        allowed_inbound_warp_routes: vec![],
+        expected_allowed_inbound_senders: vec![],
+        expected_error_string: "".to_string(),
+    };
+    "Disable Inbound Messages")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        mailbox_contract_address: Some("new_mailbox".to_string()),
+        allowed_inbound_senders: vec![inbound_sender("0202")],
+        allowed_inbound_warp_routes: vec!["new_warp_route".to_string()],
+        expected_allowed_inbound_senders: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_update_inbound_config(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the contract info query
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { contract_addr } if contract_addr == "hyperlane_transfer" => {
+                let mut contract_info = ContractInfoResponse::new(1, "creator");
+                contract_info.admin = Some("admin".to_string());
+
+                SystemResult::Ok(SystemContractResult::Ok(
+                    to_json_binary(&contract_info).unwrap(),
+                ))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("hyperlane_transfer");
+
+    // Instantiate the contract with the mailbox, allowed inbound sender and warp route
+    skip_go_hyperlane_adapter::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            entry_point_contract_address: "entry_point".to_string(),
+            mailbox_contract_address: Some("mailbox".to_string()),
+            allowed_inbound_senders: vec![inbound_sender("0101")],
+            allowed_inbound_warp_routes: vec!["warp_route".to_string()],
+        },
+    )?;
+
+    // Call execute_update_inbound_config with the given test parameters
+    let res = skip_go_hyperlane_adapter::contract::execute(
+        deps.as_mut(),
+        env,
+        mock_info(&params.caller, &[]),
+        ExecuteMsg::UpdateInboundConfig {
+            mailbox_contract_address: params.mailbox_contract_address.clone(),
+            allowed_inbound_senders: params.allowed_inbound_senders,
+            allowed_inbound_warp_routes: params.allowed_inbound_warp_routes.clone(),
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(_) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the stored mailbox is correct
+            let stored_mailbox_contract_address =
+                MAILBOX_CONTRACT_ADDRESS.may_load(deps.as_ref().storage)?;
+            assert_eq!(
+                stored_mailbox_contract_address,
+                params.mailbox_contract_address.map(Addr::unchecked)
+            );
+
+            // Assert the stored allowed inbound senders are correct
+            let stored_allowed_inbound_senders = ALLOWED_INBOUND_SENDERS
+                .keys(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(
+                stored_allowed_inbound_senders,
+                params.expected_allowed_inbound_senders
+            );
+
+            // Assert the stored allowed inbound warp routes are correct
+            let stored_allowed_inbound_warp_routes = ALLOWED_INBOUND_WARP_ROUTES
+                .keys(deps.as_ref().storage, None, None, Order::Ascending)
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(
+                stored_allowed_inbound_warp_routes,
+                params
+                    .allowed_inbound_warp_routes
+                    .into_iter()
+                    .map(Addr::unchecked)
+                    .collect::<Vec<_>>()
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,343 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    to_json_binary, BankMsg, Coin, Reply,
+    ReplyOn::Never,
+    SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
+};
+use cw20::{Cw20Coin, Cw20ExecuteMsg};
+use skip_go_hyperlane_adapter::{
+    error::ContractResult,
+    state::{
+        IN_PROGRESS_CW20_COIN, IN_PROGRESS_FUNDS, IN_PROGRESS_RECOVER_ADDRESS,
+        IN_PROGRESS_WARP_ADDRESS,
+    },
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Happy Path (tests the in progress funds are sent to the recover address and removed from storage)
+    - Cw20 Coin And Interchain Gas Payment Refunded (tests the warp route allowance is reset)
+    - Cw20 Coin Refunded Without Interchain Gas Payment
+    - SubMsgResult Ok (tests the in progress items are removed from storage without refunding)
+    - SubMsgResult Ok With Cw20 Coin
+
+Expect Error
+    - No In Progress Recover Address To Load
+    - No In Progress Funds To Load
+    - No In Progress Warp Address To Load With Cw20 Coin
+
+Expect Panic
+    - SubMsg Incorrect Reply ID
+        - Should panic because the reply id is set to a constant, so should never happen unless
+          the wasm module worked unexpectedly
+ */
+
+// Define test parameters
+struct Params {
+    reply: Reply,
+    pre_reply_in_progress_recover_address: Option<String>,
+    pre_reply_in_progress_funds: Option<Vec<Coin>>,
+    pre_reply_in_progress_cw20_coin: Option<Cw20Coin>,
+    pre_reply_in_progress_warp_address: Option<String>,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Test reply
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![Coin::new(100, "uatom"), Coin::new(50, "untrn")]),
+        pre_reply_in_progress_cw20_coin: None,
+        pre_reply_in_progress_warp_address: None,
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "recover_address".to_string(),
+                amount: vec![Coin::new(100, "uatom"), Coin::new(50, "untrn")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error_string: "".to_string(),
+    };
+    "Happy Path")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![Coin::new(50, "untrn")]),
+        pre_reply_in_progress_cw20_coin: Some(Cw20Coin {
+            address: "neutron123".to_string(),
+            amount: Uint128::new(100),
+        }),
+        pre_reply_in_progress_warp_address: Some("warp_address".to_string()),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "recover_address".to_string(),
+                    amount: vec![Coin::new(50, "untrn")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "neutron123".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::DecreaseAllowance {
+                        spender: "warp_address".to_string(),
+                        amount: Uint128::new(100),
+                        expires: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "neutron123".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "recover_address".to_string(),
+                        amount: Uint128::new(100),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Cw20 Coin And Interchain Gas Payment Refunded")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![]),
+        pre_reply_in_progress_cw20_coin: Some(Cw20Coin {
+            address: "neutron123".to_string(),
+            amount: Uint128::new(100),
+        }),
+        pre_reply_in_progress_warp_address: Some("warp_address".to_string()),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "neutron123".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::DecreaseAllowance {
+                        spender: "warp_address".to_string(),
+                        amount: Uint128::new(100),
+                        expires: None,
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "neutron123".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: "recover_address".to_string(),
+                        amount: Uint128::new(100),
+                    })
+                    .unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+    "Cw20 Coin Refunded Without Interchain Gas Payment")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![Coin::new(100, "uatom")]),
+        pre_reply_in_progress_cw20_coin: None,
+        pre_reply_in_progress_warp_address: None,
+        expected_messages: vec![],
+        expected_error_string: "".to_string(),
+    };
+    "SubMsgResult Ok")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![Coin::new(50, "untrn")]),
+        pre_reply_in_progress_cw20_coin: Some(Cw20Coin {
+            address: "neutron123".to_string(),
+            amount: Uint128::new(100),
+        }),
+        pre_reply_in_progress_warp_address: Some("warp_address".to_string()),
+        expected_messages: vec![],
+        expected_error_string: "".to_string(),
+    };
+    "SubMsgResult Ok With Cw20 Coin")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: None,
+        pre_reply_in_progress_funds: Some(vec![Coin::new(100, "uatom")]),
+        pre_reply_in_progress_cw20_coin: None,
+        pre_reply_in_progress_warp_address: None,
+        expected_messages: vec![],
+        expected_error_string: "type: alloc::string::String; key: [69, 6E, 5F, 70, 72, 6F, 67, 72, 65, 73, 73, 5F, 72, 65, 63, 6F, 76, 65, 72, 5F, 61, 64, 64, 72, 65, 73, 73] not found".to_string(),
+    };
+    "No In Progress Recover Address To Load - Expect Error")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: None,
+        pre_reply_in_progress_cw20_coin: None,
+        pre_reply_in_progress_warp_address: None,
+        expected_messages: vec![],
+        expected_error_string: "type: alloc::vec::Vec<cosmwasm_std::coin::Coin>; key: [69, 6E, 5F, 70, 72, 6F, 67, 72, 65, 73, 73, 5F, 66, 75, 6E, 64, 73] not found".to_string(),
+    };
+    "No In Progress Funds To Load - Expect Error")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 1,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![]),
+        pre_reply_in_progress_cw20_coin: Some(Cw20Coin {
+            address: "neutron123".to_string(),
+            amount: Uint128::new(100),
+        }),
+        pre_reply_in_progress_warp_address: None,
+        expected_messages: vec![],
+        expected_error_string: "type: alloc::string::String; key: [69, 6E, 5F, 70, 72, 6F, 67, 72, 65, 73, 73, 5F, 77, 61, 72, 70, 5F, 61, 64, 64, 72, 65, 73, 73] not found".to_string(),
+    };
+    "No In Progress Warp Address To Load With Cw20 Coin - Expect Error")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 2,
+            result: SubMsgResult::Err("warp route transfer failed".to_string()),
+        },
+        pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
+        pre_reply_in_progress_funds: Some(vec![Coin::new(100, "uatom")]),
+        pre_reply_in_progress_cw20_coin: None,
+        pre_reply_in_progress_warp_address: None,
+        expected_messages: vec![],
+        expected_error_string: "".to_string(),
+    } => panics "internal error: entered unreachable code";
+    "SubMsg Incorrect Reply ID - Expect Panic")]
+fn test_reply(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let env = mock_env();
+
+    // Store the in progress recover address to state if it exists
+    if let Some(in_progress_recover_address) = params.pre_reply_in_progress_recover_address.clone()
+    {
+        IN_PROGRESS_RECOVER_ADDRESS.save(deps.as_mut().storage, &in_progress_recover_address)?;
+    }
+
+    // Store the in progress funds to state if they exist
+    if let Some(in_progress_funds) = params.pre_reply_in_progress_funds.clone() {
+        IN_PROGRESS_FUNDS.save(deps.as_mut().storage, &in_progress_funds)?;
+    }
+
+    // Store the in progress cw20 coin to state if it exists
+    if let Some(in_progress_cw20_coin) = params.pre_reply_in_progress_cw20_coin.clone() {
+        IN_PROGRESS_CW20_COIN.save(deps.as_mut().storage, &in_progress_cw20_coin)?;
+    }
+
+    // Store the in progress warp address to state if it exists
+    if let Some(in_progress_warp_address) = params.pre_reply_in_progress_warp_address.clone() {
+        IN_PROGRESS_WARP_ADDRESS.save(deps.as_mut().storage, &in_progress_warp_address)?;
+    }
+
+    // Call reply with the given test parameters
+    let res = skip_go_hyperlane_adapter::contract::reply(deps.as_mut(), env, params.reply);
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Verify the in progress recover address, funds, cw20 coin and warp address were removed from storage
+            assert!(IN_PROGRESS_RECOVER_ADDRESS
+                .may_load(&deps.storage)?
+                .is_none());
+            assert!(IN_PROGRESS_FUNDS.may_load(&deps.storage)?.is_none());
+            assert!(IN_PROGRESS_CW20_COIN.may_load(&deps.storage)?.is_none());
+            assert!(IN_PROGRESS_WARP_ADDRESS.may_load(&deps.storage)?.is_none());
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
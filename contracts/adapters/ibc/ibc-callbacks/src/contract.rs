@@ -3,19 +3,20 @@
 use crate::{
     error::{ContractError, ContractResult},
     state::{
-        ACK_ID_TO_RECOVER_INFO, ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_CHANNEL_ID,
-        IN_PROGRESS_COIN, IN_PROGRESS_RECOVER_ADDRESS,
+        ACK_ID_TO_RECOVER_INFO, ENTRY_POINT_CONTRACT_ADDRESS, FAILED_DESTINATION_DELIVERIES,
+        FAILED_DESTINATION_DELIVERY_COUNT, IN_PROGRESS_CHANNEL_ID, IN_PROGRESS_COIN,
+        IN_PROGRESS_RECOVER_ADDRESS,
     },
 };
-use alloy_primitives::Address;
 use alloy_sol_types::SolType;
 use cosmwasm_std::{
     ensure_eq, entry_point, from_json, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps,
     DepsMut, Env, IbcAckCallbackMsg, IbcBasicResponse, IbcDestinationCallbackMsg, IbcPacket,
-    IbcSourceCallbackMsg, IbcTimeoutCallbackMsg, MessageInfo, Reply, Response, StdError, StdResult,
-    SubMsg, SubMsgResult, Uint128, WasmMsg,
+    IbcSourceCallbackMsg, IbcTimeoutCallbackMsg, MessageInfo, Order, Reply, Response, StdError,
+    StdResult, SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 use ibc_eureka_solidity_types::msgs::IICS20TransferMsgs::FungibleTokenPacketData as AbiFungibleTokenPacketData;
 use ibc_proto::ibc::applications::transfer::v1::{
     FungibleTokenPacketData, MsgTransfer, MsgTransferResponse,
@@ -24,15 +25,21 @@ use prost::Message;
 use serde_cw_value::Value;
 use sha2::{Digest, Sha256};
 use skip2::{
+    address::validate_evm_address,
     callbacks::SourceCallbackType,
-    ibc::{AckID, ExecuteMsg, IbcInfo, InstantiateMsg, Memo, MigrateMsg, QueryMsg, RecoverInfo},
+    ibc::{
+        AckID, ExecuteMsg, FailedDelivery, IbcInfo, InstantiateMsg, Memo, MigrateMsg, QueryMsg,
+        RecoverInfo, IBC_SOLIDITY_ABI_ENCODING,
+    },
     proto_coin::ProtoCoin,
 };
 use std::{collections::BTreeMap, str::FromStr};
 
 const IBC_MSG_TRANSFER_TYPE_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
-const IBC_SOLIDITY_ABI_ENCODING: &str = "application/x-solidity-abi";
 const REPLY_ID: u64 = 1;
+const DESTINATION_CALLBACK_REPLY_ID: u64 = 2;
+const DEFAULT_FAILED_DELIVERIES_LIMIT: u32 = 10;
+const MAX_FAILED_DELIVERIES_LIMIT: u32 = 30;
 
 ///////////////
 /// MIGRATE ///
@@ -154,8 +161,8 @@ fn execute_ibc_transfer(
     let encoding = ibc_info.encoding.unwrap_or_default();
 
     // If the encoding is IBC Solidity ABI encoding, error if the receiver is not a valid EVM address
-    if encoding == IBC_SOLIDITY_ABI_ENCODING && !is_valid_ethereum_address(&ibc_info.receiver) {
-        return Err(ContractError::InvalidEVMAddress);
+    if encoding == IBC_SOLIDITY_ABI_ENCODING {
+        validate_evm_address(&ibc_info.receiver)?;
     };
 
     // Create an ibc transfer message
@@ -189,20 +196,25 @@ fn execute_ibc_transfer(
 /// REPLY ///
 /////////////
 
-// Handles the reply from the ibc transfer sub message
-// Upon success, maps the sub msg AckID (channel_id, sequence_id)
-// to the in progress ibc transfer struct, and saves it to storage.
-// Now that the map entry is stored, it also removes the in progress
-// ibc transfer from storage.
+// Routes the reply to the handler of the sub message that was dispatched
 #[entry_point]
 pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response> {
-    // Error if the reply id is not the same as the one used in the sub message dispatched
-    // This should never happen since we are using a constant reply id, but added in case
+    // Error if the reply id is not one of the ones used in the sub messages dispatched
+    // This should never happen since we are using constant reply ids, but added in case
     // the wasm module doesn't behave as expected.
-    if reply.id != REPLY_ID {
-        unreachable!()
+    match reply.id {
+        REPLY_ID => reply_ibc_transfer(deps, reply),
+        DESTINATION_CALLBACK_REPLY_ID => reply_destination_callback(deps, reply),
+        _ => unreachable!(),
     }
+}
 
+// Handles the reply from the ibc transfer sub message
+// Upon success, maps the sub msg AckID (channel_id, sequence_id)
+// to the in progress ibc transfer struct, and saves it to storage.
+// Now that the map entry is stored, it also removes the in progress
+// ibc transfer from storage.
+fn reply_ibc_transfer(deps: DepsMut, reply: Reply) -> ContractResult<Response> {
     // Get the sub message response from the reply and error if it does not exist
     // This should never happen since sub msg was set to reply on success only,
     // but added in case the wasm module doesn't behave as expected.
@@ -254,9 +266,53 @@ pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response>
     Ok(Response::new().add_attribute("action", "sub_msg_reply_success"))
 }
 
+// Handles the reply from a failed destination callback contract call sub message.
+// The failed sub message is reverted, so the received funds are still held by this
+// contract and are sent to the recover address given in the memo. The failed delivery
+// is recorded in storage so it can be queried afterwards.
+fn reply_destination_callback(deps: DepsMut, reply: Reply) -> ContractResult<Response> {
+    // Get the error from the reply
+    // This should never happen since sub msg was set to reply on error only,
+    // but added in case the wasm module doesn't behave as expected.
+    let SubMsgResult::Err(error) = reply.result else {
+        unreachable!()
+    };
+
+    // Get the contract address, recover address, and coin from the reply payload
+    if reply.payload.is_empty() {
+        return Err(ContractError::MissingReplyPayload);
+    }
+    let mut failed_delivery: FailedDelivery = from_json(&reply.payload)?;
+
+    // Assign the next failed delivery id and record the failed delivery
+    let id = FAILED_DESTINATION_DELIVERY_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    FAILED_DESTINATION_DELIVERY_COUNT.save(deps.storage, &(id + 1))?;
+
+    failed_delivery.id = id;
+    failed_delivery.error = error;
+    FAILED_DESTINATION_DELIVERIES.save(deps.storage, id, &failed_delivery)?;
+
+    // Create bank send message to send the received funds to the recover address
+    let bank_send_msg = BankMsg::Send {
+        to_address: failed_delivery.recover_address.clone(),
+        amount: vec![failed_delivery.coin.clone()],
+    };
+
+    Ok(Response::new()
+        .add_message(bank_send_msg)
+        .add_attribute("action", "destination_callback_failed_and_bank_send")
+        .add_attribute("failed_delivery_id", id.to_string())
+        .add_attribute("contract_address", failed_delivery.contract_address)
+        .add_attribute("recover_address", failed_delivery.recover_address)
+        .add_attribute("coin", failed_delivery.coin.to_string())
+        .add_attribute("error", failed_delivery.error))
+}
+
 #[entry_point]
 pub fn ibc_destination_callback(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
     msg: IbcDestinationCallbackMsg,
 ) -> ContractResult<IbcBasicResponse> {
@@ -285,8 +341,9 @@ pub fn ibc_destination_callback(
     // Get this chain's denom for the packet
     let recv_denom = get_recv_denom(msg.packet, packet_data.denom.clone());
 
-    // Decode the memo to get the contract address and message to execute
-    let (contract_addr, msg) = get_contract_addr_and_msg_from_ibc_hooks_memo(packet_data.memo)?;
+    // Decode the memo to get the contract address, message to execute, and recover address
+    let (contract_addr, msg, recover_address) =
+        get_contract_addr_and_msg_from_ibc_hooks_memo(packet_data.memo)?;
 
     // Create a coin to send to the contract based on the packet data
     let coin = Coin {
@@ -294,16 +351,35 @@ pub fn ibc_destination_callback(
         amount: Uint128::from_str(&packet_data.amount)?,
     };
 
-    // @NotJeremyLiu TODO: Turn this into a sub msg and figure out what the recovery case is here
     // Execute the message
-    let msg = CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr,
+    let wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: contract_addr.clone(),
         msg,
-        funds: vec![coin],
+        funds: vec![coin.clone()],
     });
 
-    // Add the message to the response
-    response = response.add_message(msg);
+    // If a recover address is given in the memo, dispatch the message as a sub message
+    // that replies on error so the received funds can be sent to the recover address
+    // if the contract call fails. Otherwise, dispatch the message as is.
+    response = match recover_address {
+        Some(recover_address) => {
+            deps.api.addr_validate(&recover_address)?;
+
+            let failed_delivery = FailedDelivery {
+                id: 0, // Assigned in the reply handler
+                contract_address: contract_addr,
+                recover_address,
+                coin,
+                error: String::new(), // Set in the reply handler
+            };
+
+            response.add_submessage(
+                SubMsg::reply_on_error(wasm_msg, DESTINATION_CALLBACK_REPLY_ID)
+                    .with_payload(to_json_binary(&failed_delivery)?),
+            )
+        }
+        None => response.add_message(wasm_msg),
+    };
 
     // Return the response
     Ok(response)
@@ -411,16 +487,19 @@ fn verify_and_create_memo(memo: String, contract_address: String) -> ContractRes
     Ok(updated_memo)
 }
 
-// Parses the given memo string to get the contract address and message to execute,
-// decoding the ibc hooks memo
-fn get_contract_addr_and_msg_from_ibc_hooks_memo(memo: String) -> StdResult<(String, Binary)> {
+// Parses the given memo string to get the contract address, message to execute,
+// and optional recover address, decoding the ibc hooks memo
+fn get_contract_addr_and_msg_from_ibc_hooks_memo(
+    memo: String,
+) -> StdResult<(String, Binary, Option<String>)> {
     // Convert the memo string to an IBC Hooks Memo struct
     let hooks_memo: Memo = from_json(&memo)?;
 
-    // Return the contract address and the msg as a binary
+    // Return the contract address, the msg as a binary, and the recover address
     Ok((
         hooks_memo.wasm.contract,
         to_json_binary(&hooks_memo.wasm.msg)?,
+        hooks_memo.recover_address,
     ))
 }
 
@@ -474,10 +553,6 @@ fn get_recv_denom(ibc_packet: IbcPacket, packet_denom: String) -> String {
     format!("ibc/{}", hex::encode_upper(denom_hash))
 }
 
-fn is_valid_ethereum_address(addr: &str) -> bool {
-    Address::from_str(addr).is_ok()
-}
-
 /////////////
 /// QUERY ///
 /////////////
@@ -491,10 +566,35 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
         } => {
             to_json_binary(&ACK_ID_TO_RECOVER_INFO.load(deps.storage, (&channel_id, sequence_id))?)
         }
+        QueryMsg::FailedDestinationDeliveries { start_after, limit } => to_json_binary(
+            &query_failed_destination_deliveries(deps, start_after, limit)?,
+        ),
     }
     .map_err(From::from)
 }
 
+// Returns the failed destination deliveries in ascending id order, paginated
+fn query_failed_destination_deliveries(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<FailedDelivery>> {
+    let limit = limit
+        .unwrap_or(DEFAULT_FAILED_DELIVERIES_LIMIT)
+        .min(MAX_FAILED_DELIVERIES_LIMIT) as usize;
+
+    FAILED_DESTINATION_DELIVERIES
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(_, failed_delivery)| failed_delivery))
+        .collect()
+}
+
 // Scratch Tests
 
 // #[cfg(test)]
@@ -509,16 +609,6 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
 
 //     use cosmwasm_std::IbcEndpoint;
 
-//     #[test]
-//     fn test_evm_address_validation() {
-//         let good = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e";
-//         let bad = "0x123INVALIDADDRESS";
-
-//         assert!(is_valid_ethereum_address(good));
-//         assert!(!is_valid_ethereum_address(bad));
-
-//     }
-
 //     #[test]
 //     fn test_reply() {
 //         let sub_msg_result = SubMsgResult::Ok(SubMsgResponse {
@@ -1,4 +1,5 @@
 use cosmwasm_std::StdError;
+use skip2::error::SkipError;
 use thiserror::Error;
 
 pub type ContractResult<T> = core::result::Result<T, ContractError>;
@@ -8,6 +9,9 @@ pub enum ContractError {
     #[error(transparent)]
     Std(#[from] StdError),
 
+    #[error(transparent)]
+    Skip(#[from] SkipError),
+
     #[error(transparent)]
     Decode(#[from] prost::DecodeError),
 
@@ -35,6 +39,6 @@ pub enum ContractError {
     #[error("IBC fees are not supported, vectors must be empty")]
     IbcFeesNotSupported,
 
-    #[error("EVM Address provided is invalid")]
-    InvalidEVMAddress,
+    #[error("Reply payload does not contain the destination callback recover info")]
+    MissingReplyPayload,
 }
@@ -30,6 +30,7 @@ Expect Error
     - Non Empty String, Invalid Json Memo
     - Non Empty IBC Fees, IBC Fees Not Supported
     - Invalid EVM Address When Solidiy Encoding Is Provided
+    - EVM Address Not Matching Its EIP-55 Checksum When Solidiy Encoding Is Provided
 
  */
 
@@ -296,9 +297,28 @@ struct Params {
         },
         timeout_timestamp: 100,
         expected_messages: vec![],
-        expected_error_string: "EVM Address provided is invalid".to_string(),
+        expected_error_string: "Invalid EVM Address: cosmos1zhqrfu9w3sugwykef3rq8t0vlxkz72vw9pzsvv".to_string(),
     };
         "Invalid EVM Address When Solidiy Encoding Is Provided - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        ibc_adapter_contract_address: Addr::unchecked("ibc_transfer".to_string()),
+        coin: Coin::new(100u128, "osmo"),
+        ibc_info: IbcInfo {
+            source_channel: "source_channel".to_string(),
+            receiver: "0x24a9267ce9e0a8F4467B584FDDa12baf1Df772B5".to_string(),
+            fee: None,
+            memo: "{}".to_string(),
+            recover_address: "recover_address".to_string(),
+            encoding: Some("application/x-solidity-abi".to_string()),
+            eureka_fee: None,
+        },
+        timeout_timestamp: 100,
+        expected_messages: vec![],
+        expected_error_string: "EVM Address Does Not Match Its EIP-55 Checksum: 0x24a9267ce9e0a8F4467B584FDDa12baf1Df772B5".to_string(),
+    };
+    "EVM Address Not Matching Its EIP-55 Checksum When Solidiy Encoding Is Provided - Expect Error")]
 fn test_execute_ibc_transfer(params: Params) -> ContractResult<()> {
     // Create mock dependencies
     let mut deps = mock_dependencies();
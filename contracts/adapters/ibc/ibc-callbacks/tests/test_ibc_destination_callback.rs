@@ -0,0 +1,183 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, MockApi},
+    to_json_binary, Binary, Coin, IbcAcknowledgement, IbcDestinationCallbackMsg, IbcEndpoint,
+    IbcPacket, IbcTimeout, SubMsg, Timestamp, WasmMsg,
+};
+use ibc_proto::ibc::applications::transfer::v1::FungibleTokenPacketData;
+use skip2::ibc::FailedDelivery;
+use skip_go_ibc_adapter_ibc_callbacks::error::{ContractError, ContractResult};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response (Output Message Is Correct)
+    - Recover Address Set (the contract call is dispatched as a reply on error sub message with the failed delivery payload)
+    - Recover Address Not Set (the contract call is dispatched as a plain message)
+
+Expect Error
+    - Invalid Recover Address
+    - Receive Packet Failed
+
+ */
+
+// Define test parameters
+struct Params {
+    recover_address: Option<String>,
+    ack: Binary,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Mirrors the IbcDestinationCallbackMsg fields so the non exhaustive
+// message can be built through its serde representation
+#[cw_serde]
+struct DestinationCallbackMsg {
+    packet: IbcPacket,
+    ack: IbcAcknowledgement,
+}
+
+// Returns the acknowledgement of a successfully received packet
+fn success_ack() -> Binary {
+    Binary::from(br#"{"result":"AQ=="}"#.to_vec())
+}
+
+// Returns a valid recover address for the mock api
+fn recover_address() -> String {
+    MockApi::default().addr_make("recover_address").to_string()
+}
+
+// Returns the contract call given in the ibc hooks memo, sending it the received funds
+fn wasm_msg() -> WasmMsg {
+    WasmMsg::Execute {
+        contract_addr: "entry_point".to_string(),
+        msg: Binary::from(br#"{"action":{}}"#.to_vec()),
+        funds: vec![Coin::new(100u128, "uosmo")],
+    }
+}
+
+// Test ibc_destination_callback
+#[test_case(
+    Params {
+        recover_address: Some(recover_address()),
+        ack: success_ack(),
+        expected_messages: vec![SubMsg::reply_on_error(wasm_msg(), 2).with_payload(
+            to_json_binary(&FailedDelivery {
+                id: 0,
+                contract_address: "entry_point".to_string(),
+                recover_address: recover_address(),
+                coin: Coin::new(100u128, "uosmo"),
+                error: "".to_string(),
+            })
+            .unwrap(),
+        )],
+        expected_error_string: "".to_string(),
+    };
+    "Recover Address Set")]
+#[test_case(
+    Params {
+        recover_address: None,
+        ack: success_ack(),
+        expected_messages: vec![SubMsg::new(wasm_msg())],
+        expected_error_string: "".to_string(),
+    };
+    "Recover Address Not Set")]
+#[test_case(
+    Params {
+        recover_address: Some("invalid".to_string()),
+        ack: success_ack(),
+        expected_messages: vec![],
+        expected_error_string: ContractError::from(
+            MockApi::default().addr_validate("invalid").unwrap_err(),
+        )
+        .to_string(),
+    };
+    "Invalid Recover Address - Expect Error")]
+#[test_case(
+    Params {
+        recover_address: Some(recover_address()),
+        ack: Binary::from(br#"{"error":"failed"}"#.to_vec()),
+        expected_messages: vec![],
+        expected_error_string: "Receive packet is not successful, ibc dest callback will not process"
+            .to_string(),
+    };
+    "Receive Packet Failed - Expect Error")]
+fn test_ibc_destination_callback(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let env = mock_env();
+
+    // Create the ibc hooks memo with the contract call and optional recover address
+    let memo = match params.recover_address {
+        Some(recover_address) => format!(
+            r#"{{"wasm":{{"contract":"entry_point","msg":{{"action":{{}}}}}},"recover_address":"{recover_address}"}}"#
+        ),
+        None => r#"{"wasm":{"contract":"entry_point","msg":{"action":{}}}}"#.to_string(),
+    };
+
+    // Create the packet, returning uosmo to its origin so the received denom is uosmo
+    let packet = IbcPacket::new(
+        to_json_binary(&FungibleTokenPacketData {
+            denom: "transfer/channel-0/uosmo".to_string(),
+            amount: "100".to_string(),
+            sender: "sender".to_string(),
+            receiver: "receiver".to_string(),
+            memo,
+        })?,
+        IbcEndpoint {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-0".to_string(),
+        },
+        IbcEndpoint {
+            port_id: "transfer".to_string(),
+            channel_id: "channel-1".to_string(),
+        },
+        1,
+        IbcTimeout::with_timestamp(Timestamp::from_nanos(1)),
+    );
+
+    // Create the destination callback message
+    let msg: IbcDestinationCallbackMsg = from_json(to_json_binary(&DestinationCallbackMsg {
+        packet,
+        ack: IbcAcknowledgement::new(params.ack),
+    })?)?;
+
+    // Call ibc_destination_callback with the given test parameters
+    let res = skip_go_ibc_adapter_ibc_callbacks::contract::ibc_destination_callback(
+        deps.as_mut(),
+        env,
+        msg,
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
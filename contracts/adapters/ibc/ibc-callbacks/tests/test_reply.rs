@@ -0,0 +1,176 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env},
+    to_json_binary, BankMsg, Binary, Coin, Reply, SubMsg, SubMsgResult,
+};
+use skip2::ibc::{FailedDelivery, QueryMsg};
+use skip_go_ibc_adapter_ibc_callbacks::{
+    error::ContractResult,
+    state::{FAILED_DESTINATION_DELIVERIES, FAILED_DESTINATION_DELIVERY_COUNT},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Destination Callback Failed (tests the funds are sent to the recover address and the failed delivery is stored)
+    - Destination Callback Failed With Previous Failed Deliveries (tests the failed delivery id is incremented)
+
+Expect Error
+    - Missing Reply Payload
+ */
+
+// Define test parameters
+struct Params {
+    reply: Reply,
+    pre_reply_failed_delivery_count: Option<u64>,
+    expected_messages: Vec<SubMsg>,
+    expected_failed_delivery: Option<FailedDelivery>,
+    expected_error_string: String,
+}
+
+// Test reply for the destination callback sub message
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 2,
+            payload: to_json_binary(&FailedDelivery {
+                id: 0,
+                contract_address: "entry_point".to_string(),
+                recover_address: "recover_address".to_string(),
+                coin: Coin::new(100u128, "ibc/uatom"),
+                error: "".to_string(),
+            }).unwrap(),
+            gas_used: 0,
+            result: SubMsgResult::Err("contract call failed".to_string()),
+        },
+        pre_reply_failed_delivery_count: None,
+        expected_messages: vec![SubMsg::new(BankMsg::Send {
+            to_address: "recover_address".to_string(),
+            amount: vec![Coin::new(100u128, "ibc/uatom")],
+        })],
+        expected_failed_delivery: Some(FailedDelivery {
+            id: 0,
+            contract_address: "entry_point".to_string(),
+            recover_address: "recover_address".to_string(),
+            coin: Coin::new(100u128, "ibc/uatom"),
+            error: "contract call failed".to_string(),
+        }),
+        expected_error_string: "".to_string(),
+    };
+    "Destination Callback Failed")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 2,
+            payload: to_json_binary(&FailedDelivery {
+                id: 0,
+                contract_address: "entry_point".to_string(),
+                recover_address: "recover_address".to_string(),
+                coin: Coin::new(100u128, "ibc/uatom"),
+                error: "".to_string(),
+            }).unwrap(),
+            gas_used: 0,
+            result: SubMsgResult::Err("contract call failed".to_string()),
+        },
+        pre_reply_failed_delivery_count: Some(3),
+        expected_messages: vec![SubMsg::new(BankMsg::Send {
+            to_address: "recover_address".to_string(),
+            amount: vec![Coin::new(100u128, "ibc/uatom")],
+        })],
+        expected_failed_delivery: Some(FailedDelivery {
+            id: 3,
+            contract_address: "entry_point".to_string(),
+            recover_address: "recover_address".to_string(),
+            coin: Coin::new(100u128, "ibc/uatom"),
+            error: "contract call failed".to_string(),
+        }),
+        expected_error_string: "".to_string(),
+    };
+    "Destination Callback Failed With Previous Failed Deliveries")]
+#[test_case(
+    Params {
+        reply: Reply {
+            id: 2,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Err("contract call failed".to_string()),
+        },
+        pre_reply_failed_delivery_count: None,
+        expected_messages: vec![],
+        expected_failed_delivery: None,
+        expected_error_string: "Reply payload does not contain the destination callback recover info".to_string(),
+    };
+    "Missing Reply Payload - Expect Error")]
+fn test_reply_destination_callback(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let env = mock_env();
+
+    // Store the failed delivery count if provided
+    if let Some(count) = params.pre_reply_failed_delivery_count {
+        FAILED_DESTINATION_DELIVERY_COUNT.save(deps.as_mut().storage, &count)?;
+    }
+
+    // Call reply with the given test parameters
+    let res = skip_go_ibc_adapter_ibc_callbacks::contract::reply(
+        deps.as_mut(),
+        env.clone(),
+        params.reply,
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages in the response are correct
+            assert_eq!(res.messages, params.expected_messages);
+
+            // Assert the failed delivery was stored correctly
+            let expected_failed_delivery = params.expected_failed_delivery.unwrap();
+            let stored_failed_delivery =
+                FAILED_DESTINATION_DELIVERIES.load(&deps.storage, expected_failed_delivery.id)?;
+            assert_eq!(stored_failed_delivery, expected_failed_delivery);
+
+            // Assert the failed delivery count was incremented
+            assert_eq!(
+                FAILED_DESTINATION_DELIVERY_COUNT.load(&deps.storage)?,
+                expected_failed_delivery.id + 1
+            );
+
+            // Assert the failed delivery is returned by the query
+            let queried_failed_deliveries: Vec<FailedDelivery> =
+                from_json(skip_go_ibc_adapter_ibc_callbacks::contract::query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::FailedDestinationDeliveries {
+                        start_after: None,
+                        limit: None,
+                    },
+                )?)?;
+            assert_eq!(queried_failed_deliveries, vec![expected_failed_delivery]);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::StdError;
+use skip_go_ibc_adapter_core::error::ContractError as IbcAdapterError;
 use thiserror::Error;
 
 pub type ContractResult<T> = core::result::Result<T, ContractError>;
@@ -9,32 +10,14 @@ pub enum ContractError {
     Std(#[from] StdError),
 
     #[error(transparent)]
-    Decode(#[from] prost::DecodeError),
+    IbcAdapter(#[from] IbcAdapterError),
 
     #[error(transparent)]
-    JsonDecode(#[from] serde_json_wasm::de::Error),
-
-    #[error(transparent)]
-    JsonEncode(#[from] serde_json_wasm::ser::Error),
+    Decode(#[from] prost::DecodeError),
 
     #[error(transparent)]
-    Overflow(#[from] OverflowError),
+    JsonDecode(#[from] serde_json_wasm::de::Error),
 
     #[error("IBC fees are not supported, vectors must be empty")]
     IbcFeesNotSupported,
-
-    #[error("SubMsgResponse does not contain data")]
-    MissingResponseData,
-
-    #[error("Failed to receive ibc funds to refund the user")]
-    NoFundsToRefund,
-
-    #[error("Unauthorized")]
-    Unauthorized,
-
-    #[error("ACK ID already exists for channel ID {channel_id} and sequence ID {sequence_id}")]
-    AckIDAlreadyExists {
-        channel_id: String,
-        sequence_id: u64,
-    },
 }
@@ -1,4 +1,5 @@
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::StdError;
+use skip_go_ibc_adapter_core::error::ContractError as IbcAdapterError;
 use thiserror::Error;
 
 pub type ContractResult<T> = core::result::Result<T, ContractError>;
@@ -8,33 +9,18 @@ pub enum ContractError {
     #[error(transparent)]
     Std(#[from] StdError),
 
-    #[error("Error decoding Sub Msg Response data to MsgTransferResponse")]
-    Decode(#[from] prost::DecodeError),
-
     #[error(transparent)]
-    Overflow(#[from] OverflowError),
+    IbcAdapter(#[from] IbcAdapterError),
 
-    #[error("Unauthorized")]
-    Unauthorized,
+    #[error("Error decoding Sub Msg Response data to MsgTransferResponse")]
+    Decode(#[from] prost::DecodeError),
 
     #[error("IBC fees are required")]
     IbcFeesRequired,
 
-    #[error("SubMsgResponse does not contain data")]
-    MissingResponseData,
-
     #[error("Channel ID not found")]
     ChannelIDNotFound,
 
     #[error("Sequence not found")]
     SequenceNotFound,
-
-    #[error("Failed to receive ibc funds to refund the user")]
-    NoFundsToRefund,
-
-    #[error("ACK ID already exists for channel ID {channel_id} and sequence ID {sequence_id}")]
-    AckIDAlreadyExists {
-        channel_id: String,
-        sequence_id: u64,
-    },
 }
@@ -1,7 +1,3 @@
-use cosmwasm_std::Addr;
-use cw_storage_plus::{Item, Map};
-use skip::ibc::AckID;
-
-pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
-pub const IN_PROGRESS_RECOVER_ADDRESS: Item<String> = Item::new("in_progress_recover_address");
-pub const ACK_ID_TO_RECOVER_ADDRESS: Map<AckID, String> = Map::new("ack_id_to_recover_address");
+pub use skip_go_ibc_adapter_core::state::{
+    ACK_ID_TO_RECOVER_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_RECOVER_ADDRESS,
+};
@@ -4,6 +4,7 @@ use cosmwasm_std::{
 };
 use neutron_proto::neutron::transfer::MsgTransferResponse;
 use prost::Message;
+use skip_go_ibc_adapter_core::error::ContractError as IbcAdapterError;
 use skip_go_ibc_adapter_neutron_transfer::{
     error::{ContractError, ContractResult},
     state::{ACK_ID_TO_RECOVER_ADDRESS, IN_PROGRESS_RECOVER_ADDRESS},
@@ -71,7 +72,7 @@ struct Params {
         },
         pre_reply_in_progress_recover_address: None,
         store_ack_id_to_in_progress_recover_address: false,
-        expected_error: Some(ContractError::MissingResponseData),
+        expected_error: Some(ContractError::IbcAdapter(IbcAdapterError::MissingResponseData)),
     };
     "Missing Sub Msg Response Data - Expect Error")]
 #[test_case(
@@ -119,7 +120,7 @@ struct Params {
         },
         pre_reply_in_progress_recover_address: Some("recover_address".to_string()),
         store_ack_id_to_in_progress_recover_address: true,
-        expected_error: Some(ContractError::AckIDAlreadyExists { channel_id: "channel_id".to_string(), sequence_id: 5 }),
+        expected_error: Some(ContractError::IbcAdapter(IbcAdapterError::AckIDAlreadyExists { channel_id: "channel_id".to_string(), sequence_id: 5 })),
     };
     "Ack ID Already Exists - Expect Error")]
 #[test_case(
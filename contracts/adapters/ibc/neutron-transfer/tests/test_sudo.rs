@@ -5,6 +5,7 @@ use cosmwasm_std::{
     StdError, SubMsg,
 };
 use neutron_sdk::sudo::msg::{RequestPacket, TransferSudoMsg};
+use skip_go_ibc_adapter_core::error::ContractError as IbcAdapterError;
 use skip_go_ibc_adapter_neutron_transfer::{
     error::{ContractError, ContractResult},
     state::ACK_ID_TO_RECOVER_ADDRESS,
@@ -295,7 +296,7 @@ struct Params {
         },
         stored_in_progress_recover_address: Some("recover_address".to_string()),
         expected_messages: vec![],
-        expected_error: Some(ContractError::NoFundsToRefund),
+        expected_error: Some(ContractError::IbcAdapter(IbcAdapterError::NoFundsToRefund)),
     };
     "No Contract Balance To Refund - Expect Error")]
 fn test_sudo(params: Params) -> ContractResult<()> {
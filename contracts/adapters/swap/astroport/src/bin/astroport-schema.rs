@@ -1,9 +1,9 @@
 use cosmwasm_schema::write_api;
-use skip::swap::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use skip::swap::{AstroportInstantiateMsg, ExecuteMsg, QueryMsg};
 
 fn main() {
     write_api! {
-        instantiate: InstantiateMsg,
+        instantiate: AstroportInstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg
     }
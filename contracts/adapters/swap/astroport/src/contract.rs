@@ -1,10 +1,18 @@
 use crate::{
     error::{ContractError, ContractResult},
-    state::ENTRY_POINT_CONTRACT_ADDRESS,
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
 };
-use astroport::pair::{
-    Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg,
-    ReverseSimulationResponse, SimulationResponse, MAX_ALLOWED_SLIPPAGE,
+use astroport::{
+    asset::PairInfo,
+    factory::QueryMsg as FactoryQueryMsg,
+    pair::{
+        Cw20HookMsg as PairCw20HookMsg, ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg,
+        ReverseSimulationResponse, SimulationResponse, MAX_ALLOWED_SLIPPAGE,
+    },
+    router::{
+        ConfigResponse as RouterConfigResponse, Cw20HookMsg as RouterCw20HookMsg,
+        ExecuteMsg as RouterExecuteMsg, QueryMsg as RouterQueryMsg,
+    },
 };
 use cosmwasm_std::{
     entry_point, from_json, to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo,
@@ -15,10 +23,13 @@ use cw20::{Cw20Coin, Cw20ReceiveMsg};
 use cw_utils::one_coin;
 use skip::{
     asset::{get_current_asset_available, Asset},
+    error::SkipError,
     swap::{
-        execute_transfer_funds_back, get_ask_denom_for_routes, Cw20HookMsg, ExecuteMsg,
-        InstantiateMsg, MigrateMsg, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
-        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
+        calculate_weighted_spot_price, execute_transfer_funds_back, get_ask_denom_for_routes,
+        AstroportInstantiateMsg, AstroportMigrateMsg, CapabilitiesResponse, Cw20HookMsg,
+        ExecuteMsg, PoolIdentifier, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
+        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SlippageInterface,
+        SwapOperation,
     },
 };
 
@@ -31,7 +42,7 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 ///////////////
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> ContractResult<Response> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: AstroportMigrateMsg) -> ContractResult<Response> {
     // Set contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
@@ -42,12 +53,17 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> ContractResult<Resp
     // Store the entry point contract address
     ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
 
+    // Store the router contract address if given, otherwise
+    // remove it to swap on each pool instead of the router
+    let router_contract_address = save_router_contract_address(deps, msg.router_contract_address)?;
+
     Ok(Response::new()
         .add_attribute("action", "migrate")
         .add_attribute(
             "entry_point_contract_address",
             checked_entry_point_contract_address.to_string(),
-        ))
+        )
+        .add_attribute("router_contract_address", router_contract_address))
 }
 
 /////////////////
@@ -59,7 +75,7 @@ pub fn instantiate(
     deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
-    msg: InstantiateMsg,
+    msg: AstroportInstantiateMsg,
 ) -> ContractResult<Response> {
     // Set contract version
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -71,12 +87,39 @@ pub fn instantiate(
     // Store the entry point contract address
     ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
 
+    // Store the router contract address if given
+    let router_contract_address = save_router_contract_address(deps, msg.router_contract_address)?;
+
     Ok(Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute(
             "entry_point_contract_address",
             checked_entry_point_contract_address.to_string(),
-        ))
+        )
+        .add_attribute("router_contract_address", router_contract_address))
+}
+
+// Validates and stores the router contract address if given, otherwise removes it.
+// Returns the router contract address to be used as an attribute.
+fn save_router_contract_address(
+    deps: DepsMut,
+    router_contract_address: Option<String>,
+) -> ContractResult<String> {
+    match router_contract_address {
+        Some(router_contract_address) => {
+            let checked_router_contract_address =
+                deps.api.addr_validate(&router_contract_address)?;
+
+            ROUTER_CONTRACT_ADDRESS.save(deps.storage, &checked_router_contract_address)?;
+
+            Ok(checked_router_contract_address.to_string())
+        }
+        None => {
+            ROUTER_CONTRACT_ADDRESS.remove(deps.storage);
+
+            Ok("none".to_string())
+        }
+    }
 }
 
 /////////////
@@ -102,7 +145,10 @@ pub fn receive_cw20(
     info.sender = deps.api.addr_validate(&cw20_msg.sender)?;
 
     match from_json(&cw20_msg.msg)? {
-        Cw20HookMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        Cw20HookMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, sent_asset, operations, min_amount_out),
     }
 }
 
@@ -119,9 +165,12 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
-        ExecuteMsg::Swap { operations } => {
-            one_coin(&info)?;
-            execute_swap(deps, env, info, operations)
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => {
+            let coin_in = one_coin(&info)?;
+            execute_swap(deps, env, info, coin_in.into(), operations, min_amount_out)
         }
         ExecuteMsg::TransferFundsBack {
             swapper,
@@ -133,12 +182,11 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        ExecuteMsg::AstroportPoolSwap { operation } => {
-            execute_astroport_pool_swap(deps, env, info, operation)
-        }
-        _ => {
-            unimplemented!()
-        }
+        ExecuteMsg::AstroportPoolSwap {
+            operation,
+            min_amount_out,
+        } => execute_astroport_pool_swap(deps, env, info, operation, min_amount_out),
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -146,7 +194,9 @@ fn execute_swap(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
+    offer_asset: Asset,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -159,23 +209,50 @@ fn execute_swap(
     // Create a response object to return
     let mut response: Response = Response::new().add_attribute("action", "execute_swap");
 
-    // Add an astroport pool swap message to the response for each swap operation
-    for operation in &operations {
-        let swap_msg = WasmMsg::Execute {
-            contract_addr: env.contract.address.to_string(),
-            msg: to_json_binary(&ExecuteMsg::AstroportPoolSwap {
-                operation: operation.clone(),
-            })?,
-            funds: vec![],
-        };
-        response = response.add_message(swap_msg);
-    }
-
     let return_denom = match operations.last() {
         Some(last_op) => last_op.denom_out.clone(),
         None => return Err(ContractError::SwapOperationsEmpty),
     };
 
+    // Error if the router swaps through other pools than the swap operation pools
+    assert_router_pools(deps.as_ref(), &operations)?;
+
+    match ROUTER_CONTRACT_ADDRESS.may_load(deps.storage)? {
+        // If the router contract address is set, add a single router swap operations
+        // message to the response, with the router enforcing the min amount out
+        Some(router_contract_address) => {
+            let swap_msg = create_router_swap_msg(
+                deps.as_ref(),
+                router_contract_address.to_string(),
+                offer_asset,
+                operations,
+                min_amount_out,
+            )?;
+            response = response
+                .add_message(swap_msg)
+                .add_attribute("action", "dispatch_router_swap_operations");
+        }
+        // Otherwise add an astroport pool swap message to the response for each swap
+        // operation, only the last swap operation's pool swap enforces the min amount out
+        None => {
+            for (i, operation) in operations.iter().enumerate() {
+                let swap_msg = WasmMsg::Execute {
+                    contract_addr: env.contract.address.to_string(),
+                    msg: to_json_binary(&ExecuteMsg::AstroportPoolSwap {
+                        operation: operation.clone(),
+                        min_amount_out: if i == operations.len() - 1 {
+                            min_amount_out
+                        } else {
+                            None
+                        },
+                    })?,
+                    funds: vec![],
+                };
+                response = response.add_message(swap_msg);
+            }
+        }
+    }
+
     // Create the transfer funds back message
     let transfer_funds_back_msg = WasmMsg::Execute {
         contract_addr: env.contract.address.to_string(),
@@ -196,6 +273,7 @@ fn execute_astroport_pool_swap(
     env: Env,
     info: MessageInfo,
     operation: SwapOperation,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Ensure the caller is the contract itself
     if info.sender != env.contract.address {
@@ -210,19 +288,26 @@ fn execute_astroport_pool_swap(
         return Err(ContractError::NoOfferAssetAmount);
     }
 
+    // Get the belief price and max spread the pool swap is executed with
+    let (belief_price, max_spread) = get_belief_price_and_max_spread(
+        offer_asset.amount(),
+        min_amount_out,
+        &operation.slippage_interface()?,
+    )?;
+
     // Create the astroport pool swap msg depending on the offer asset type
     let msg = match offer_asset {
         Asset::Native(_) => to_json_binary(&PairExecuteMsg::Swap {
             offer_asset: offer_asset.into_astroport_asset(deps.api)?,
             ask_asset_info: None,
-            belief_price: None,
-            max_spread: Some(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+            belief_price,
+            max_spread: Some(max_spread),
             to: None,
         })?,
         Asset::Cw20(_) => to_json_binary(&PairCw20HookMsg::Swap {
             ask_asset_info: None,
-            belief_price: None,
-            max_spread: Some(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+            belief_price,
+            max_spread: Some(max_spread),
             to: None,
         })?,
     };
@@ -235,6 +320,151 @@ fn execute_astroport_pool_swap(
         .add_attribute("action", "dispatch_astroport_pool_swap"))
 }
 
+// Creates the astroport router swap operations message, sending the offer asset to the router.
+// The router swaps through the factory's pair of each swap operation's denoms, so the
+// swap operation pools are not used.
+fn create_router_swap_msg(
+    deps: Deps,
+    router_contract_address: String,
+    offer_asset: Asset,
+    operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
+) -> ContractResult<WasmMsg> {
+    // Get the max spread the router swaps each operation with
+    let max_spread = Some(get_router_max_spread(&operations)?);
+
+    // Convert the swap operations to astroport swap operations
+    let operations = operations
+        .iter()
+        .map(|operation| operation.into_astroport_swap_operation(deps.api))
+        .collect();
+
+    // Create the router swap operations msg depending on the offer asset type
+    let msg = match offer_asset {
+        Asset::Native(_) => to_json_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+            operations,
+            minimum_receive: min_amount_out,
+            to: None,
+            max_spread,
+        })?,
+        Asset::Cw20(_) => to_json_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+            operations,
+            minimum_receive: min_amount_out,
+            to: None,
+            max_spread,
+        })?,
+    };
+
+    Ok(offer_asset.into_wasm_msg(router_contract_address, msg)?)
+}
+
+// Returns the max spread the router swaps each operation with, the strictest max spread
+// of the swap operations' slippage interfaces, defaulting to the max allowed slippage.
+// Errors if a swap operation has a belief price since the router does not support it.
+fn get_router_max_spread(operations: &[SwapOperation]) -> ContractResult<Decimal> {
+    operations.iter().try_fold(
+        MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?,
+        |max_spread, operation| {
+            let slippage_interface = operation.slippage_interface()?;
+
+            if slippage_interface.belief_price.is_some() {
+                return Err(ContractError::BeliefPriceUnsupported);
+            }
+
+            Ok(slippage_interface
+                .max_spread
+                .map_or(max_spread, |interface_max_spread| {
+                    max_spread.min(interface_max_spread)
+                }))
+        },
+    )
+}
+
+// Errors if the router contract address is set and a swap operation's pool is not the
+// astroport factory pair of its denoms. The router swaps through the factory pairs, so
+// simulating on any other pool would not match the swap the router executes.
+fn assert_router_pools(deps: Deps, operations: &[SwapOperation]) -> ContractResult<()> {
+    let Some(router_contract_address) = ROUTER_CONTRACT_ADDRESS.may_load(deps.storage)? else {
+        return Ok(());
+    };
+
+    let router_config: RouterConfigResponse = deps
+        .querier
+        .query_wasm_smart(&router_contract_address, &RouterQueryMsg::Config {})?;
+
+    for operation in operations {
+        let pair_info: PairInfo = deps.querier.query_wasm_smart(
+            &router_config.astroport_factory,
+            &FactoryQueryMsg::Pair {
+                asset_infos: vec![
+                    Asset::new(deps.api, &operation.denom_in, Uint128::zero())
+                        .into_astroport_asset(deps.api)?
+                        .info,
+                    Asset::new(deps.api, &operation.denom_out, Uint128::zero())
+                        .into_astroport_asset(deps.api)?
+                        .info,
+                ],
+            },
+        )?;
+
+        if pair_info.contract_addr.as_str() != operation.pool {
+            return Err(ContractError::RouterPoolMismatch {
+                pool: operation.pool.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the belief price and max spread of a pool swap. With a min amount out, the
+// belief price is set so the pair's expected return is the min amount out (rounded up
+// so it is never stricter than it) and the max spread is zero, so the pair reverts any
+// swap returning less. If the swap operation's slippage interface also sets a belief
+// price, the stricter of the two is used: the one whose lowest accepted return is higher.
+// Without a min amount out, the swap operation's slippage interface is used.
+fn get_belief_price_and_max_spread(
+    offer_amount: Uint128,
+    min_amount_out: Option<Uint128>,
+    slippage_interface: &SlippageInterface,
+) -> ContractResult<(Option<Decimal>, Decimal)> {
+    let max_spread = get_max_spread(slippage_interface)?;
+
+    if let Some(min_amount_out) = min_amount_out.filter(|amount| !amount.is_zero()) {
+        if let Ok(mut min_out_belief_price) =
+            Decimal::checked_from_ratio(offer_amount, min_amount_out)
+        {
+            if min_amount_out * min_out_belief_price < offer_amount {
+                min_out_belief_price += Decimal::raw(1);
+            }
+
+            // The pair accepts returns down to (1 - max spread) of the return expected at
+            // the interface belief price, so the interface is stricter when its belief price
+            // is below the min amount out belief price scaled by (1 - max spread)
+            return match slippage_interface.belief_price {
+                Some(belief_price)
+                    if belief_price
+                        < min_out_belief_price * Decimal::one().saturating_sub(max_spread) =>
+                {
+                    Ok((Some(belief_price), max_spread))
+                }
+                _ => Ok((Some(min_out_belief_price), Decimal::zero())),
+            };
+        }
+    }
+
+    Ok((slippage_interface.belief_price, max_spread))
+}
+
+// Returns the max spread of the swap operation's slippage interface,
+// defaulting to the max allowed slippage if it is not set
+fn get_max_spread(slippage_interface: &SlippageInterface) -> ContractResult<Decimal> {
+    match slippage_interface.max_spread {
+        Some(max_spread) => Ok(max_spread),
+        None => Ok(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+    }
+}
+
 /////////////
 /// QUERY ///
 /////////////
@@ -300,10 +530,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the astroport swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: true,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::ContractAddress],
+    }
+}
+
 // Queries the astroport pool contracts to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -485,14 +730,40 @@ fn query_simulate_smart_swap_exact_asset_in_with_metadata(
     Ok(response)
 }
 
-fn assert_max_spread(return_amount: Uint128, spread_amount: Uint128) -> ContractResult<()> {
-    let max_spread = MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?;
+fn assert_max_spread(
+    return_amount: Uint128,
+    spread_amount: Uint128,
+    max_spread: Decimal,
+) -> ContractResult<()> {
     if Decimal::from_ratio(spread_amount, return_amount + spread_amount) > max_spread {
         return Err(ContractError::MaxSpreadAssertion {});
     }
     Ok(())
 }
 
+// Asserts the return does not fall short of the return expected at the belief price
+// by more than the max spread, as the pair does when swapping with a belief price
+fn assert_belief_price(
+    offer_amount: Uint128,
+    return_amount: Uint128,
+    belief_price: Decimal,
+    max_spread: Decimal,
+) -> ContractResult<()> {
+    let Some(inverse_belief_price) = belief_price.inv() else {
+        return Err(ContractError::MaxSpreadAssertion {});
+    };
+
+    let expected_return = offer_amount * inverse_belief_price;
+    let spread_amount = expected_return.saturating_sub(return_amount);
+
+    if return_amount < expected_return
+        && Decimal::from_ratio(spread_amount, expected_return) > max_spread
+    {
+        return Err(ContractError::MaxSpreadAssertion {});
+    }
+    Ok(())
+}
+
 // Simulates a swap exact amount in request, returning the asset out and optionally the reverse simulation responses
 fn simulate_swap_exact_asset_in(
     deps: Deps,
@@ -500,6 +771,9 @@ fn simulate_swap_exact_asset_in(
     swap_operations: Vec<SwapOperation>,
     include_responses: bool,
 ) -> ContractResult<(Asset, Vec<SimulationResponse>)> {
+    // Error if the router swaps through other pools than the simulated pools
+    assert_router_pools(deps, &swap_operations)?;
+
     let (asset_out, responses) = swap_operations.iter().try_fold(
         (asset_in, Vec::new()),
         |(asset_out, mut responses), operation| -> Result<_, ContractError> {
@@ -515,8 +789,19 @@ fn simulate_swap_exact_asset_in(
                 },
             )?;
 
-            // Assert the operation does not exceed the max spread limit
-            assert_max_spread(res.return_amount, res.spread_amount)?;
+            // Assert the operation does not exceed the max spread limit, as the
+            // pair would given the swap operation's slippage interface
+            let slippage_interface = operation.slippage_interface()?;
+            let max_spread = get_max_spread(&slippage_interface)?;
+            match slippage_interface.belief_price {
+                Some(belief_price) => assert_belief_price(
+                    asset_out.amount(),
+                    res.return_amount,
+                    belief_price,
+                    max_spread,
+                )?,
+                None => assert_max_spread(res.return_amount, res.spread_amount, max_spread)?,
+            }
 
             if include_responses {
                 responses.push(res.clone());
@@ -539,6 +824,9 @@ fn simulate_swap_exact_asset_out(
     swap_operations: Vec<SwapOperation>,
     include_responses: bool,
 ) -> ContractResult<(Asset, Vec<ReverseSimulationResponse>)> {
+    // Error if the router swaps through other pools than the simulated pools
+    assert_router_pools(deps, &swap_operations)?;
+
     let (asset_in, responses) = swap_operations.iter().rev().try_fold(
         (asset_out, Vec::new()),
         |(asset_in_needed, mut responses), operation| -> Result<_, ContractError> {
@@ -554,8 +842,19 @@ fn simulate_swap_exact_asset_out(
                 },
             )?;
 
-            // Assert the operation does not exceed the max spread limit
-            assert_max_spread(res.offer_amount, res.spread_amount)?;
+            // Assert the operation does not exceed the max spread limit, as the
+            // pair would given the swap operation's slippage interface
+            let slippage_interface = operation.slippage_interface()?;
+            let max_spread = get_max_spread(&slippage_interface)?;
+            match slippage_interface.belief_price {
+                Some(belief_price) => assert_belief_price(
+                    res.offer_amount,
+                    asset_in_needed.amount(),
+                    belief_price,
+                    max_spread,
+                )?,
+                None => assert_max_spread(res.offer_amount, res.spread_amount, max_spread)?,
+            }
 
             if include_responses {
                 responses.push(res.clone());
@@ -608,23 +907,23 @@ fn calculate_weighted_spot_price_from_simulation_responses(
     routes: Vec<Route>,
     simulation_responses: Vec<Vec<SimulationResponse>>,
 ) -> ContractResult<Decimal> {
-    let spot_price = routes.into_iter().zip(simulation_responses).try_fold(
-        Decimal::zero(),
-        |curr_spot_price, (route, res)| -> ContractResult<Decimal> {
-            let route_spot_price = calculate_spot_price_from_simulation_responses(
-                deps,
-                asset_in.clone(),
-                route.operations,
-                res,
-            )?;
-
-            let weight = Decimal::from_ratio(route.offer_asset.amount(), asset_in.amount());
-
-            Ok(curr_spot_price + (route_spot_price * weight))
-        },
-    )?;
+    let route_spot_prices = routes
+        .into_iter()
+        .zip(simulation_responses)
+        .map(|(route, res)| {
+            Ok((
+                route.offer_asset.amount(),
+                calculate_spot_price_from_simulation_responses(
+                    deps,
+                    asset_in.clone(),
+                    route.operations,
+                    res,
+                )?,
+            ))
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
 
-    Ok(spot_price)
+    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
 }
 
 // Calculate the spot price using simulation responses
@@ -35,4 +35,10 @@ pub enum ContractError {
 
     #[error("Contract has no balance of offer asset")]
     NoOfferAssetAmount,
+
+    #[error("Belief price not supported by the astroport router swap operations")]
+    BeliefPriceUnsupported,
+
+    #[error("Pool {pool} is not the astroport factory pair the router swaps through")]
+    RouterPoolMismatch { pool: String },
 }
@@ -2,3 +2,4 @@ use cosmwasm_std::Addr;
 use cw_storage_plus::Item;
 
 pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+pub const ROUTER_CONTRACT_ADDRESS: Item<Addr> = Item::new("router_contract_address");
@@ -11,7 +11,7 @@ use cosmwasm_std::{
     SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
 };
 use cw20::{BalanceResponse, Cw20ExecuteMsg};
-use skip::swap::{ExecuteMsg, SwapOperation};
+use skip::swap::{ExecuteMsg, SlippageInterface, SwapOperation};
 use skip_go_swap_adapter_astroport::error::{ContractError, ContractResult};
 use test_case::test_case;
 
@@ -20,6 +20,10 @@ Test Cases:
 
 Expect Success
     - Native Swap Operation
+    - Native Swap Operation With Min Amount Out
+    - Native Swap Operation With Slippage Interface
+    - Native Swap Operation With Min Amount Out And Stricter Slippage Interface (The interface belief price and max spread are used)
+    - Native Swap Operation With Stricter Min Amount Out And Slippage Interface (The min amount out belief price is used)
     - Cw20 Swap Operation
 
 Expect Error
@@ -34,6 +38,7 @@ struct Params {
     caller: String,
     contract_balance: Vec<Coin>,
     swap_operation: SwapOperation,
+    min_amount_out: Option<Uint128>,
     expected_message: Option<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -49,6 +54,7 @@ struct Params {
                 denom_out: "ua".to_string(),
                 interface: None,
             },
+        min_amount_out: None,
         expected_message: Some(SubMsg {
                 id: 0,
                 msg: WasmMsg::Execute {
@@ -74,6 +80,159 @@ struct Params {
         expected_error: None,
     };
     "Native Swap Operation")]
+#[test_case(
+    Params {
+        caller: "swap_contract_address".to_string(),
+        contract_balance: vec![Coin::new(100, "os")],
+        swap_operation: SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(1)),
+                    belief_price: Some(Decimal::percent(50)),
+                })?),
+            },
+        min_amount_out: None,
+        expected_message: Some(SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "pool_1".to_string(),
+                    msg: to_json_binary(&AstroportPairExecuteMsg::Swap {
+                        offer_asset: AstroportAsset {
+                            info: AssetInfo::NativeToken {
+                                denom: "os".to_string(),
+                            },
+                            amount: Uint128::new(100),
+                        },
+                        ask_asset_info: None,
+                        belief_price: Some(Decimal::percent(50)),
+                        max_spread: Some(Decimal::percent(1)),
+                        to: None,
+                    })?,
+                    funds: vec![Coin::new(100, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            }),
+        expected_error: None,
+    };
+    "Native Swap Operation With Slippage Interface")]
+#[test_case(
+    Params {
+        caller: "swap_contract_address".to_string(),
+        contract_balance: vec![Coin::new(100, "os")],
+        swap_operation: SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: None,
+            },
+        min_amount_out: Some(Uint128::new(90)),
+        expected_message: Some(SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "pool_1".to_string(),
+                    msg: to_json_binary(&AstroportPairExecuteMsg::Swap {
+                        offer_asset: AstroportAsset {
+                            info: AssetInfo::NativeToken {
+                                denom: "os".to_string(),
+                            },
+                            amount: Uint128::new(100),
+                        },
+                        ask_asset_info: None,
+                        belief_price: Some(Decimal::raw(1_111_111_111_111_111_112)),
+                        max_spread: Some(Decimal::zero()),
+                        to: None,
+                    })?,
+                    funds: vec![Coin::new(100, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            }),
+        expected_error: None,
+    };
+    "Native Swap Operation With Min Amount Out")]
+#[test_case(
+    Params {
+        caller: "swap_contract_address".to_string(),
+        contract_balance: vec![Coin::new(100, "os")],
+        swap_operation: SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(1)),
+                    belief_price: Some(Decimal::percent(50)),
+                })?),
+            },
+        min_amount_out: Some(Uint128::new(90)),
+        expected_message: Some(SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "pool_1".to_string(),
+                    msg: to_json_binary(&AstroportPairExecuteMsg::Swap {
+                        offer_asset: AstroportAsset {
+                            info: AssetInfo::NativeToken {
+                                denom: "os".to_string(),
+                            },
+                            amount: Uint128::new(100),
+                        },
+                        ask_asset_info: None,
+                        belief_price: Some(Decimal::percent(50)),
+                        max_spread: Some(Decimal::percent(1)),
+                        to: None,
+                    })?,
+                    funds: vec![Coin::new(100, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            }),
+        expected_error: None,
+    };
+    "Native Swap Operation With Min Amount Out And Stricter Slippage Interface")]
+#[test_case(
+    Params {
+        caller: "swap_contract_address".to_string(),
+        contract_balance: vec![Coin::new(100, "os")],
+        swap_operation: SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(1)),
+                    belief_price: Some(Decimal::percent(125)),
+                })?),
+            },
+        min_amount_out: Some(Uint128::new(90)),
+        expected_message: Some(SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "pool_1".to_string(),
+                    msg: to_json_binary(&AstroportPairExecuteMsg::Swap {
+                        offer_asset: AstroportAsset {
+                            info: AssetInfo::NativeToken {
+                                denom: "os".to_string(),
+                            },
+                            amount: Uint128::new(100),
+                        },
+                        ask_asset_info: None,
+                        belief_price: Some(Decimal::raw(1_111_111_111_111_111_112)),
+                        max_spread: Some(Decimal::zero()),
+                        to: None,
+                    })?,
+                    funds: vec![Coin::new(100, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            }),
+        expected_error: None,
+    };
+    "Native Swap Operation With Stricter Min Amount Out And Slippage Interface")]
 #[test_case(
     Params {
         caller: "swap_contract_address".to_string(),
@@ -84,6 +243,7 @@ struct Params {
                 denom_out: "ua".to_string(),
                 interface: None,
             },
+        min_amount_out: None,
         expected_message: Some(SubMsg {
             id: 0,
             msg: WasmMsg::Execute {
@@ -116,6 +276,7 @@ struct Params {
                 denom_out: "ua".to_string(),
                 interface: None,
             },
+        min_amount_out: None,
         expected_message: None,
         expected_error: Some(ContractError::NoOfferAssetAmount),
     };
@@ -130,6 +291,7 @@ struct Params {
                 denom_out: "ua".to_string(),
                 interface: None,
             },
+        min_amount_out: None,
         expected_message: None,
         expected_error: Some(ContractError::NoOfferAssetAmount),
     };
@@ -146,6 +308,7 @@ struct Params {
             denom_out: "".to_string(),
             interface: None,
         },
+        min_amount_out: None,
         expected_message: None,
         expected_error: Some(ContractError::Unauthorized),
     };
@@ -200,6 +363,7 @@ fn test_execute_astroport_pool_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::AstroportPoolSwap {
             operation: params.swap_operation,
+            min_amount_out: params.min_amount_out,
         },
     );
 
@@ -68,7 +68,8 @@ struct Params {
                             denom_in: "neutron123".to_string(),
                             denom_out: "ua".to_string(),
                             interface: None,
-                        }
+                        },
+                        min_amount_out: None,
                     })?,
                     funds: vec![],
                 }.into(),
@@ -120,7 +121,8 @@ struct Params {
                             denom_in: "neutron123".to_string(),
                             denom_out: "neutron987".to_string(),
                             interface: None,
-                        }
+                        },
+                        min_amount_out: None,
                     })?,
                     funds: vec![],
                 }.into(),
@@ -209,6 +211,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
             amount: params.sent_asset.amount(),
             msg: to_json_binary(&ExecuteMsg::Swap {
                 operations: params.swap_operations,
+                min_amount_out: None,
             })
             .unwrap(),
         }),
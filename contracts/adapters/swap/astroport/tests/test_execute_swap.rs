@@ -2,7 +2,7 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     to_json_binary, Addr, Coin,
     ReplyOn::Never,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 use skip::swap::{ExecuteMsg, SwapOperation};
 use skip_go_swap_adapter_astroport::{
@@ -17,6 +17,7 @@ Test Cases:
 Expect Success
     - One Swap Operation
     - Multiple Swap Operations
+    - Multiple Swap Operations With Min Amount Out (Only enforced by the last pool swap)
     - No Swap Operations (This is prevented in the entry point contract; and will not add any swap messages to the response)
 
 Expect Error
@@ -31,6 +32,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -48,6 +50,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -59,7 +62,8 @@ struct Params {
                             denom_in: "os".to_string(),
                             denom_out: "ua".to_string(),
                             interface: None,
-                        }
+                        },
+                        min_amount_out: None,
                     })?,
                     funds: vec![],
                 }.into(),
@@ -102,6 +106,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -113,7 +118,8 @@ struct Params {
                             denom_in: "os".to_string(),
                             denom_out: "ua".to_string(),
                             interface: None,
-                        }
+                        },
+                        min_amount_out: None,
                     })?,
                     funds: vec![],
                 }.into(),
@@ -130,7 +136,8 @@ struct Params {
                             denom_in: "ua".to_string(),
                             denom_out: "un".to_string(),
                             interface: None,
-                        }
+                        },
+                        min_amount_out: None,
                     })?,
                     funds: vec![],
                 }.into(),
@@ -155,11 +162,86 @@ struct Params {
         expected_error: None,
     };
     "Multiple Swap Operations")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: None,
+            },
+            SwapOperation {
+                pool: "pool_2".to_string(),
+                denom_in: "ua".to_string(),
+                denom_out: "un".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::AstroportPoolSwap {
+                        operation: SwapOperation {
+                            pool: "pool_1".to_string(),
+                            denom_in: "os".to_string(),
+                            denom_out: "ua".to_string(),
+                            interface: None,
+                        },
+                        min_amount_out: None,
+                    })?,
+                    funds: vec![],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::AstroportPoolSwap {
+                        operation: SwapOperation {
+                            pool: "pool_2".to_string(),
+                            denom_in: "ua".to_string(),
+                            denom_out: "un".to_string(),
+                            interface: None,
+                        },
+                        min_amount_out: Some(Uint128::new(90)),
+                    })?,
+                    funds: vec![],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "un".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Multiple Swap Operations With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "os")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::SwapOperationsEmpty),
     };
@@ -169,6 +251,7 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::NoFunds{})),
     };
@@ -181,6 +264,7 @@ struct Params {
             Coin::new(100, "os"),
         ],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::MultipleDenoms{})),
     };
@@ -192,6 +276,7 @@ struct Params {
             Coin::new(100, "un"),
         ],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Unauthorized),
     };
@@ -220,6 +305,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: params.min_amount_out,
         },
     );
 
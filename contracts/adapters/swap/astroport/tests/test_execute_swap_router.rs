@@ -0,0 +1,386 @@
+use astroport::{
+    asset::{AssetInfo, PairInfo},
+    factory::{PairType, QueryMsg as FactoryQueryMsg},
+    router::{
+        ConfigResponse as RouterConfigResponse, Cw20HookMsg as RouterCw20HookMsg,
+        ExecuteMsg as RouterExecuteMsg, SwapOperation as AstroportSwapOperation,
+    },
+};
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Coin, ContractResult as SystemContractResult, Decimal, QuerierResult,
+    ReplyOn::Never,
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use skip::swap::{Cw20HookMsg, ExecuteMsg, SlippageInterface, SwapOperation};
+use skip_go_swap_adapter_astroport::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Multiple Swap Operations
+    - Multiple Swap Operations With Min Amount Out And Max Spread Interfaces (The strictest max spread is used)
+    - Cw20 Asset In
+
+Expect Error
+    - Belief Price Interface
+    - No Swap Operations
+    - Swap Operation Pool Is Not The Factory Pair
+
+ */
+
+// Define test parameters
+struct Params {
+    info_funds: Vec<Coin>,
+    cw20_amount: Option<Uint128>,
+    swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Mocks the router config and the factory pairs the router swaps through
+fn mock_router_and_factory(query: &WasmQuery) -> QuerierResult {
+    let WasmQuery::Smart { contract_addr, msg } = query else {
+        panic!("Unsupported query: {:?}", query);
+    };
+
+    let res = match contract_addr.as_str() {
+        "router" => to_json_binary(&RouterConfigResponse {
+            astroport_factory: "factory".to_string(),
+        }),
+        "factory" => {
+            let FactoryQueryMsg::Pair { asset_infos } = from_json(msg).unwrap() else {
+                panic!("Unsupported query: {:?}", query);
+            };
+            let pair = match (
+                asset_infos[0].to_string().as_str(),
+                asset_infos[1].to_string().as_str(),
+            ) {
+                ("os", "ua") | ("neutron123", "os") => "pool_1",
+                ("ua", "un") => "pool_2",
+                _ => panic!("Unsupported pair: {:?}", asset_infos),
+            };
+            to_json_binary(&PairInfo {
+                asset_infos,
+                contract_addr: Addr::unchecked(pair),
+                liquidity_token: Addr::unchecked("lp_token"),
+                pair_type: PairType::Xyk {},
+            })
+        }
+        _ => panic!("Unsupported query: {:?}", query),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+}
+
+// Returns the astroport swap operations of the os -> ua -> un swap operations
+fn astroport_swap_operations() -> Vec<AstroportSwapOperation> {
+    vec![
+        AstroportSwapOperation::AstroSwap {
+            offer_asset_info: AssetInfo::NativeToken {
+                denom: "os".to_string(),
+            },
+            ask_asset_info: AssetInfo::NativeToken {
+                denom: "ua".to_string(),
+            },
+        },
+        AstroportSwapOperation::AstroSwap {
+            offer_asset_info: AssetInfo::NativeToken {
+                denom: "ua".to_string(),
+            },
+            ask_asset_info: AssetInfo::NativeToken {
+                denom: "un".to_string(),
+            },
+        },
+    ]
+}
+
+// Returns the transfer funds back message of the given return denom
+fn transfer_funds_back_msg(return_denom: &str) -> SubMsg {
+    SubMsg {
+        id: 0,
+        msg: WasmMsg::Execute {
+            contract_addr: "swap_contract_address".to_string(),
+            msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                return_denom: return_denom.to_string(),
+                swapper: Addr::unchecked("entry_point"),
+            })
+            .unwrap(),
+            funds: vec![],
+        }
+        .into(),
+        gas_limit: None,
+        reply_on: Never,
+    }
+}
+
+// Test execute_swap with the router contract address set
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(100, "os")],
+        cw20_amount: None,
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: None,
+            },
+            SwapOperation {
+                pool: "pool_2".to_string(),
+                denom_in: "ua".to_string(),
+                denom_out: "un".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router".to_string(),
+                    msg: to_json_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: astroport_swap_operations(),
+                        minimum_receive: None,
+                        to: None,
+                        max_spread: Some(Decimal::percent(50)),
+                    }).unwrap(),
+                    funds: vec![Coin::new(100, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            transfer_funds_back_msg("un"),
+        ],
+        expected_error: None,
+    };
+    "Multiple Swap Operations")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(100, "os")],
+        cw20_amount: None,
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(5)),
+                    belief_price: None,
+                }).unwrap()),
+            },
+            SwapOperation {
+                pool: "pool_2".to_string(),
+                denom_in: "ua".to_string(),
+                denom_out: "un".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(1)),
+                    belief_price: None,
+                }).unwrap()),
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "router".to_string(),
+                    msg: to_json_binary(&RouterExecuteMsg::ExecuteSwapOperations {
+                        operations: astroport_swap_operations(),
+                        minimum_receive: Some(Uint128::new(90)),
+                        to: None,
+                        max_spread: Some(Decimal::percent(1)),
+                    }).unwrap(),
+                    funds: vec![Coin::new(100, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            transfer_funds_back_msg("un"),
+        ],
+        expected_error: None,
+    };
+    "Multiple Swap Operations With Min Amount Out And Max Spread Interfaces")]
+#[test_case(
+    Params {
+        info_funds: vec![],
+        cw20_amount: Some(Uint128::new(100)),
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "neutron123".to_string(),
+                denom_out: "os".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "neutron123".to_string(),
+                    msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                        contract: "router".to_string(),
+                        amount: Uint128::new(100),
+                        msg: to_json_binary(&RouterCw20HookMsg::ExecuteSwapOperations {
+                            operations: vec![
+                                AstroportSwapOperation::AstroSwap {
+                                    offer_asset_info: AssetInfo::Token {
+                                        contract_addr: Addr::unchecked("neutron123"),
+                                    },
+                                    ask_asset_info: AssetInfo::NativeToken {
+                                        denom: "os".to_string(),
+                                    },
+                                },
+                            ],
+                            minimum_receive: Some(Uint128::new(90)),
+                            to: None,
+                            max_spread: Some(Decimal::percent(50)),
+                        }).unwrap(),
+                    }).unwrap(),
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            transfer_funds_back_msg("os"),
+        ],
+        expected_error: None,
+    };
+    "Cw20 Asset In")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(100, "os")],
+        cw20_amount: None,
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(5)),
+                    belief_price: Some(Decimal::percent(50)),
+                }).unwrap()),
+            }
+        ],
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::BeliefPriceUnsupported),
+    };
+    "Belief Price Interface - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(100, "os")],
+        cw20_amount: None,
+        swap_operations: vec![],
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::SwapOperationsEmpty),
+    };
+    "No Swap Operations - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(100, "os")],
+        cw20_amount: None,
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: None,
+            },
+            SwapOperation {
+                pool: "pool_3".to_string(),
+                denom_in: "ua".to_string(),
+                denom_out: "un".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::RouterPoolMismatch {
+            pool: "pool_3".to_string(),
+        }),
+    };
+    "Swap Operation Pool Is Not The Factory Pair - Expect Error")]
+fn test_execute_swap_router(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Mock the router config and factory pair queries
+    deps.querier.update_wasm(mock_router_and_factory);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Store the entry point and router contract addresses
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+    ROUTER_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("router"))?;
+
+    // Call execute_swap with the given test parameters, sending the cw20 asset
+    // in through the receive message if given
+    let res = match params.cw20_amount {
+        Some(amount) => skip_go_swap_adapter_astroport::contract::execute(
+            deps.as_mut(),
+            env,
+            mock_info("neutron123", &[]),
+            ExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: "entry_point".to_string(),
+                amount,
+                msg: to_json_binary(&Cw20HookMsg::Swap {
+                    operations: params.swap_operations.clone(),
+                    min_amount_out: params.min_amount_out,
+                })?,
+            }),
+        ),
+        None => skip_go_swap_adapter_astroport::contract::execute(
+            deps.as_mut(),
+            env,
+            mock_info("entry_point", &params.info_funds),
+            ExecuteMsg::Swap {
+                operations: params.swap_operations.clone(),
+                min_amount_out: params.min_amount_out,
+            },
+        ),
+    };
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use astroport::pair::{QueryMsg as PairQueryMsg, SimulationResponse};
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env},
+    to_json_binary, Coin, ContractResult as SystemContractResult, Decimal, QuerierResult,
+    SystemResult, Uint128, WasmQuery,
+};
+use skip::{
+    asset::Asset,
+    swap::{QueryMsg, SlippageInterface, SwapOperation},
+};
+use skip_go_swap_adapter_astroport::error::{ContractError, ContractResult};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - No Slippage Interface (The max allowed slippage is used)
+    - Max Spread Interface Not Exceeded
+    - Belief Price Interface Within Max Spread
+
+Expect Error
+    - Max Spread Interface Exceeded
+    - Belief Price Interface Exceeds Max Spread
+
+ */
+
+// Define test parameters
+struct Params {
+    interface: Option<SlippageInterface>,
+    expected_asset_out: Option<Asset>,
+    expected_error: Option<ContractError>,
+}
+
+// Test the simulation honors the swap operation's slippage interface, against a mock
+// pair returning 2 ask asset per offer asset with a spread of about 10%
+#[test_case(
+    Params {
+        interface: None,
+        expected_asset_out: Some(Asset::Native(Coin::new(200, "ua"))),
+        expected_error: None,
+    };
+    "No Slippage Interface")]
+#[test_case(
+    Params {
+        interface: Some(SlippageInterface {
+            max_spread: Some(Decimal::percent(15)),
+            belief_price: None,
+        }),
+        expected_asset_out: Some(Asset::Native(Coin::new(200, "ua"))),
+        expected_error: None,
+    };
+    "Max Spread Interface Not Exceeded")]
+#[test_case(
+    Params {
+        interface: Some(SlippageInterface {
+            max_spread: Some(Decimal::percent(5)),
+            belief_price: None,
+        }),
+        expected_asset_out: None,
+        expected_error: Some(ContractError::MaxSpreadAssertion),
+    };
+    "Max Spread Interface Exceeded")]
+#[test_case(
+    Params {
+        interface: Some(SlippageInterface {
+            max_spread: Some(Decimal::percent(5)),
+            belief_price: Some(Decimal::percent(50)),
+        }),
+        expected_asset_out: Some(Asset::Native(Coin::new(200, "ua"))),
+        expected_error: None,
+    };
+    "Belief Price Interface Within Max Spread")]
+#[test_case(
+    Params {
+        interface: Some(SlippageInterface {
+            max_spread: Some(Decimal::percent(10)),
+            belief_price: Some(Decimal::percent(40)),
+        }),
+        expected_asset_out: None,
+        expected_error: Some(ContractError::MaxSpreadAssertion),
+    };
+    "Belief Price Interface Exceeds Max Spread")]
+fn test_query_simulate_swap_exact_asset_in(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the pair simulation queries
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        let WasmQuery::Smart { msg, .. } = query else {
+            panic!("Unsupported query: {:?}", query);
+        };
+
+        let PairQueryMsg::Simulation { offer_asset, .. } = from_json(msg).unwrap() else {
+            panic!("Unsupported query: {:?}", query);
+        };
+
+        let return_amount = offer_asset.amount * Uint128::new(2);
+
+        SystemResult::Ok(SystemContractResult::Ok(
+            to_json_binary(&SimulationResponse {
+                return_amount,
+                spread_amount: return_amount / Uint128::new(9),
+                commission_amount: Uint128::zero(),
+            })
+            .unwrap(),
+        ))
+    };
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create the swap operation with the given slippage interface
+    let swap_operation = SwapOperation {
+        pool: "pool_1".to_string(),
+        denom_in: "os".to_string(),
+        denom_out: "ua".to_string(),
+        interface: params
+            .interface
+            .map(|interface| to_json_binary(&interface))
+            .transpose()?,
+    };
+
+    // Call simulate swap exact asset in with the given test parameters
+    let res = skip_go_swap_adapter_astroport::contract::query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(Coin::new(100, "os")),
+            swap_operations: vec![swap_operation],
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the asset out is correct
+            let asset_out: Asset = from_json(res)?;
+            assert_eq!(asset_out, params.expected_asset_out.unwrap());
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
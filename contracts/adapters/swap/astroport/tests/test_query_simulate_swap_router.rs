@@ -0,0 +1,185 @@
+use astroport::{
+    asset::PairInfo,
+    factory::{PairType, QueryMsg as FactoryQueryMsg},
+    pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse},
+    router::ConfigResponse as RouterConfigResponse,
+};
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env},
+    to_json_binary, Addr, Coin, ContractResult as SystemContractResult, QuerierResult,
+    SystemResult, Uint128, WasmQuery,
+};
+use skip::{
+    asset::Asset,
+    swap::{QueryMsg, SwapOperation},
+};
+use skip_go_swap_adapter_astroport::{
+    error::{ContractError, ContractResult},
+    state::ROUTER_CONTRACT_ADDRESS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Simulate Swap Exact Asset In Through The Factory Pair
+    - Simulate Swap Exact Asset Out Through The Factory Pair
+
+Expect Error
+    - Simulate Swap Exact Asset In Through Another Pool
+    - Simulate Swap Exact Asset Out Through Another Pool
+
+ */
+
+// Define test parameters
+struct Params {
+    query: QueryMsg,
+    expected_asset: Option<Asset>,
+    expected_error: Option<ContractError>,
+}
+
+// Returns the os -> ua swap operation through the given pool
+fn swap_operation(pool: &str) -> SwapOperation {
+    SwapOperation {
+        pool: pool.to_string(),
+        denom_in: "os".to_string(),
+        denom_out: "ua".to_string(),
+        interface: None,
+    }
+}
+
+// Test the simulations with the router contract address set only simulate through the
+// factory pair the router swaps through, against a mock factory pair returning 2 ask
+// asset per offer asset
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(Coin::new(100, "os")),
+            swap_operations: vec![swap_operation("pool_1")],
+        },
+        expected_asset: Some(Asset::Native(Coin::new(200, "ua"))),
+        expected_error: None,
+    };
+    "Simulate Swap Exact Asset In Through The Factory Pair")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            asset_out: Asset::Native(Coin::new(200, "ua")),
+            swap_operations: vec![swap_operation("pool_1")],
+        },
+        expected_asset: Some(Asset::Native(Coin::new(100, "os"))),
+        expected_error: None,
+    };
+    "Simulate Swap Exact Asset Out Through The Factory Pair")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(Coin::new(100, "os")),
+            swap_operations: vec![swap_operation("pool_2")],
+        },
+        expected_asset: None,
+        expected_error: Some(ContractError::RouterPoolMismatch {
+            pool: "pool_2".to_string(),
+        }),
+    };
+    "Simulate Swap Exact Asset In Through Another Pool - Expect Error")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            asset_out: Asset::Native(Coin::new(200, "ua")),
+            swap_operations: vec![swap_operation("pool_2")],
+        },
+        expected_asset: None,
+        expected_error: Some(ContractError::RouterPoolMismatch {
+            pool: "pool_2".to_string(),
+        }),
+    };
+    "Simulate Swap Exact Asset Out Through Another Pool - Expect Error")]
+fn test_query_simulate_swap_router(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the router config, factory pair
+    // and pair simulation queries
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        let WasmQuery::Smart { contract_addr, msg } = query else {
+            panic!("Unsupported query: {:?}", query);
+        };
+
+        let res = match contract_addr.as_str() {
+            "router" => to_json_binary(&RouterConfigResponse {
+                astroport_factory: "factory".to_string(),
+            }),
+            "factory" => {
+                let FactoryQueryMsg::Pair { asset_infos } = from_json(msg).unwrap() else {
+                    panic!("Unsupported query: {:?}", query);
+                };
+                to_json_binary(&PairInfo {
+                    asset_infos,
+                    contract_addr: Addr::unchecked("pool_1"),
+                    liquidity_token: Addr::unchecked("lp_token"),
+                    pair_type: PairType::Xyk {},
+                })
+            }
+            "pool_1" => match from_json(msg).unwrap() {
+                PairQueryMsg::Simulation { offer_asset, .. } => {
+                    to_json_binary(&SimulationResponse {
+                        return_amount: offer_asset.amount * Uint128::new(2),
+                        spread_amount: Uint128::zero(),
+                        commission_amount: Uint128::zero(),
+                    })
+                }
+                PairQueryMsg::ReverseSimulation { ask_asset, .. } => {
+                    to_json_binary(&ReverseSimulationResponse {
+                        offer_amount: ask_asset.amount / Uint128::new(2),
+                        spread_amount: Uint128::zero(),
+                        commission_amount: Uint128::zero(),
+                    })
+                }
+                _ => panic!("Unsupported query: {:?}", query),
+            },
+            _ => panic!("Unsupported query: {:?}", query),
+        };
+
+        SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+    };
+    deps.querier.update_wasm(wasm_handler);
+
+    // Store the router contract address
+    ROUTER_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("router"))?;
+
+    // Call the simulation query with the given test parameters
+    let res =
+        skip_go_swap_adapter_astroport::contract::query(deps.as_ref(), mock_env(), params.query);
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the simulated asset is correct
+            let asset: Asset = from_json(res)?;
+            assert_eq!(asset, params.expected_asset.unwrap());
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -19,9 +19,9 @@ use skip::{
     asset::Asset,
     error::SkipError,
     swap::{
-        get_ask_denom_for_routes, AstrovaultAdapterInstantiateMsg, Cw20HookMsg, ExecuteMsg,
-        MigrateMsg, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
-        SimulateSwapExactAssetInResponse, SwapOperation,
+        get_ask_denom_for_routes, AstrovaultAdapterInstantiateMsg, CapabilitiesResponse,
+        Cw20HookMsg, ExecuteMsg, MigrateMsg, PoolIdentifier, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse, SwapOperation,
     },
 };
 
@@ -113,9 +113,17 @@ pub fn receive_cw20(
     info.sender = deps.api.addr_validate(&cw20_msg.sender)?;
 
     match from_json(&cw20_msg.msg)? {
-        Cw20HookMsg::Swap { operations } => {
-            execute_swap(deps, env, info, sent_asset.amount(), operations)
-        }
+        Cw20HookMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(
+            deps,
+            env,
+            info,
+            sent_asset.amount(),
+            operations,
+            min_amount_out,
+        ),
     }
 }
 
@@ -132,9 +140,12 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
-        ExecuteMsg::Swap { operations } => {
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => {
             let coin = one_coin(&info)?;
-            execute_swap(deps, env, info, coin.amount, operations)
+            execute_swap(deps, env, info, coin.amount, operations, min_amount_out)
         }
         ExecuteMsg::TransferFundsBack {
             swapper,
@@ -146,9 +157,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            unimplemented!()
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -158,6 +167,7 @@ fn execute_swap(
     info: MessageInfo,
     amount_in: Uint128,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
     let astrovault_router_contract_address = ASTROVAULT_ROUTER_ADDRESS.load(deps.storage)?;
@@ -176,7 +186,7 @@ fn execute_swap(
     // Create base astrovault router wasm message
     let router_execute_msg = RouterReceiveMsg::RouteV2 {
         hops,
-        minimum_receive: None,
+        minimum_receive: min_amount_out,
         to: None,
     };
 
@@ -325,13 +335,26 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
-        _ => {
-            unimplemented!()
-        }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
+        _ => return Err(SkipError::UnsupportedQueryMsg.into()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the astrovault swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: false,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: true,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::ContractAddress],
+    }
+}
+
 // Queries the astrovault pool contracts to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -180,6 +180,7 @@ fn test_execute_receive(params: Params) -> ContractResult<()> {
             amount: Uint128::from(100u128),
             msg: to_json_binary(&Cw20HookMsg::Swap {
                 operations: params.swap_operations,
+                min_amount_out: None,
             })?,
         }),
     );
@@ -7,7 +7,7 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     to_json_binary, Addr, Coin, QuerierResult,
     ReplyOn::Never,
-    SubMsg, SystemResult, WasmMsg, WasmQuery,
+    SubMsg, SystemResult, Uint128, WasmMsg, WasmQuery,
 };
 use skip::swap::{ExecuteMsg, SwapOperation};
 use skip_go_swap_adapter_astrovault::{
@@ -21,6 +21,7 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Min Amount Out
     - Multiple Swap Operations
 
 Expect Error
@@ -34,6 +35,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -51,6 +53,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -93,6 +96,61 @@ struct Params {
         expected_error: None,
     };
     "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "astrovault_router".to_string(),
+                    msg: to_json_binary(&astrovault::router::handle_msg::ExecuteMsg::Receive(
+                        cw20::Cw20ReceiveMsg {
+                            sender: "swap_contract_address".to_string(),
+                            amount: cosmwasm_std::Uint128::from(100u128),
+                            msg: to_json_binary(&RouterReceiveMsg::RouteV2 {
+                                hops: vec![
+                                    HopV2::RatioHopInfo { pool:
+                                        PoolInfoInput::Addr("pool_1".to_string()), from_asset_index: 0 }
+                                ],
+                                minimum_receive: Some(Uint128::new(90)),
+                                to: None,
+                            })?,
+                        }
+                    ))?,
+                    funds: vec![coin(100, "os")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "ua".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "One Swap Operation With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -111,6 +169,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -167,6 +226,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::NoFunds{})),
     };
@@ -186,6 +246,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::MultipleDenoms{})),
     };
@@ -204,6 +265,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Unauthorized),
     };
@@ -284,6 +346,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: params.min_amount_out,
         },
     );
 
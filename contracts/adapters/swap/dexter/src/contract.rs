@@ -18,11 +18,12 @@ use dexter::{
 };
 use skip::{
     asset::Asset,
+    error::SkipError,
     swap::{
-        execute_transfer_funds_back, get_ask_denom_for_routes, Cw20HookMsg,
-        DexterAdapterInstantiateMsg, ExecuteMsg, MigrateMsg, QueryMsg, Route,
-        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
-        SimulateSwapExactAssetOutResponse, SwapOperation,
+        calculate_weighted_spot_price, execute_transfer_funds_back, get_ask_denom_for_routes,
+        CapabilitiesResponse, Cw20HookMsg, DexterAdapterInstantiateMsg, ExecuteMsg, MigrateMsg,
+        PoolIdentifier, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
+        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
     },
 };
 
@@ -35,7 +36,7 @@ use skip::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -102,9 +103,17 @@ pub fn receive_cw20(
     info.sender = deps.api.addr_validate(&cw20_msg.sender)?;
 
     match from_json(&cw20_msg.msg)? {
-        Cw20HookMsg::Swap { operations } => {
-            execute_swap(deps, env, info, sent_asset.amount(), operations)
-        }
+        Cw20HookMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(
+            deps,
+            env,
+            info,
+            sent_asset.amount(),
+            operations,
+            min_amount_out,
+        ),
     }
 }
 
@@ -121,7 +130,10 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
-        ExecuteMsg::Swap { operations } => {
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => {
             // validate that there's at least one swap operation
             if operations.is_empty() {
                 return Err(ContractError::SwapOperationsEmpty);
@@ -134,7 +146,7 @@ pub fn execute(
                 return Err(ContractError::CoinInDenomMismatch);
             }
 
-            execute_swap(deps, env, info, coin.amount, operations)
+            execute_swap(deps, env, info, coin.amount, operations, min_amount_out)
         }
         ExecuteMsg::TransferFundsBack {
             swapper,
@@ -146,9 +158,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            panic!("NOT IMPLEMENTED");
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -158,6 +168,7 @@ fn execute_swap(
     info: MessageInfo,
     amount_in: Uint128,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -191,8 +202,8 @@ fn execute_swap(
         requests: hop_swap_requests,
         recipient: None,
         offer_amount: amount_in,
-        // doing this since we would validate it anyway in the entrypoint contract from where swap adapter is called
-        minimum_receive: None,
+        // the router reverts the swap if it returns less than the min amount out
+        minimum_receive: min_amount_out,
     };
 
     let denom_in = operations.first().unwrap().denom_in.clone();
@@ -288,10 +299,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the dexter swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: true,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::PoolId],
+    }
+}
+
 // Queries the dexter pool contracts to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -429,7 +455,7 @@ fn query_simulate_smart_swap_exact_asset_in_with_metadata(
     };
 
     if include_spot_price {
-        response.spot_price = Some(calculate_weighted_spot_price(deps, asset_in, routes)?)
+        response.spot_price = Some(calculate_smart_swap_spot_price(deps, asset_in, routes)?)
     }
 
     Ok(response)
@@ -581,21 +607,20 @@ fn calculate_spot_price(
     Ok(final_price)
 }
 
-fn calculate_weighted_spot_price(
+fn calculate_smart_swap_spot_price(
     deps: Deps,
     asset_in: Asset,
     routes: Vec<Route>,
 ) -> ContractResult<Decimal> {
-    let spot_price = routes.into_iter().try_fold(
-        Decimal::zero(),
-        |curr_spot_price, route| -> ContractResult<Decimal> {
-            let route_spot_price = calculate_spot_price(deps, route.operations)?;
-
-            let weight = Decimal::from_ratio(route.offer_asset.amount(), asset_in.amount());
-
-            Ok(curr_spot_price + (route_spot_price * weight))
-        },
-    )?;
-
-    Ok(spot_price)
+    let route_spot_prices = routes
+        .into_iter()
+        .map(|route| {
+            Ok((
+                route.offer_asset.amount(),
+                calculate_spot_price(deps, route.operations)?,
+            ))
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
+
+    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
 }
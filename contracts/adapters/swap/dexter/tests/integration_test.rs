@@ -397,6 +397,7 @@ pub fn test_swap() {
             denom_out: "stk/uxprt".to_string(),
             interface: None,
         }],
+        min_amount_out: None,
     };
 
     // create a new user and fund them with some uxprt
@@ -420,6 +421,25 @@ pub fn test_swap() {
     assert!(res.is_err());
     assert_eq!(res.err().unwrap().root_cause().to_string(), "No funds sent");
 
+    // execute the swap with a min amount out above what the swap returns
+    let res = app.execute_contract(
+        entrypoint_dummy.clone(),
+        skip_swap_adapter_contract.clone(),
+        &skip::swap::ExecuteMsg::Swap {
+            operations: vec![SwapOperation {
+                pool: "1".to_string(),
+                denom_in: "uxprt".to_string(),
+                denom_out: "stk/uxprt".to_string(),
+                interface: None,
+            }],
+            min_amount_out: Some(Uint128::from(996807u128)),
+        },
+        &[Coin::new(1_000_000u128, "uxprt")],
+    );
+
+    // swap returning less than the min amount out should fail
+    assert!(res.is_err());
+
     // execute the swap
     app.execute_contract(
         entrypoint_dummy.clone(),
@@ -453,6 +473,7 @@ pub fn test_swap() {
                 interface: None,
             },
         ],
+        min_amount_out: None,
     };
 
     // execute the swap
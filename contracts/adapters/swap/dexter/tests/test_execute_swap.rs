@@ -20,6 +20,7 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Min Amount Out
     - Multiple Swap Operations
     - No Swap Operations (This is prevented in the entry point contract; and will fail on Osmosis module if attempted)
 
@@ -36,6 +37,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
 }
@@ -53,6 +55,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -100,6 +103,66 @@ struct Params {
         expected_error_string: "".to_string(),
     };
 "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uxprt")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "1".to_string(),
+                denom_in: "uxprt".to_string(),
+                denom_out: "stk/uxprt".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "dexter_router".to_string(),
+                    msg: to_json_binary(& DexterRouterExecuteMsg::ExecuteMultihopSwap {
+                        requests: vec![
+                            HopSwapRequest {
+                                pool_id: Uint128::from(1u128),
+                                asset_in: DexterAssetInfo::NativeToken {
+                                        denom: "uxprt".to_string()
+                                },
+                                asset_out: DexterAssetInfo::NativeToken {
+                                        denom: "stk/uxprt".to_string()
+                                },
+                            }
+                        ],
+                        offer_amount: Uint128::from(100u128),
+                        recipient: None,
+                        minimum_receive: Some(Uint128::new(90))
+                    })?,
+                    funds: vec![
+                        Coin::new(100, "uxprt")
+                    ],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "stk/uxprt".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+"One Swap Operation With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -118,6 +181,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -179,6 +243,7 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "os")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -224,6 +289,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "No funds sent".to_string(),
     };
@@ -243,6 +309,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Sent more than one denomination".to_string(),
     };
@@ -259,6 +326,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Pool ID cannot be parsed from the given string".to_string(),
     };
@@ -278,6 +346,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Unauthorized".to_string(),
     };
@@ -308,6 +377,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: params.min_amount_out,
         },
     );
 
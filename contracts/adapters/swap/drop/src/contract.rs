@@ -2,21 +2,27 @@ use crate::{
     error::{ContractError, ContractResult},
     state::{
         DROP_CORE_CONTRACT_ADDRESS, DROP_TOKEN_CONTRACT_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS,
-        FACTORY_BONDED_DENOM, IBC_REMOTE_DENOM,
+        FACTORY_BONDED_DENOM, IBC_REMOTE_DENOM, INSTANT_UNBOND_POOL_CONTRACT_ADDRESS,
     },
 };
+use astroport::pair::{
+    ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg, ReverseSimulationResponse,
+    SimulationResponse, MAX_ALLOWED_SLIPPAGE,
+};
 use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
-    WasmMsg,
+    entry_point, to_json_binary, Addr, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_utils::one_coin;
+use serde::Deserialize;
 use skip::{
     asset::Asset,
+    error::SkipError,
     swap::{
-        execute_transfer_funds_back, DropBondInstantiateMsg as InstantiateMsg, ExecuteMsg,
-        MigrateMsg, QueryMsg, SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse,
-        SwapOperation,
+        execute_transfer_funds_back, CapabilitiesResponse,
+        DropBondInstantiateMsg as InstantiateMsg, ExecuteMsg, MigrateMsg, PoolIdentifier, QueryMsg,
+        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
     },
 };
 
@@ -26,7 +32,7 @@ use skip::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -91,6 +97,16 @@ pub fn instantiate(
 
     IBC_REMOTE_DENOM.save(deps.storage, &drop_core_config.base_denom)?;
 
+    // Validate and store the instant unbond pool contract address if provided
+    let checked_instant_unbond_pool_contract_address = msg
+        .instant_unbond_pool_contract_address
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    if let Some(address) = &checked_instant_unbond_pool_contract_address {
+        INSTANT_UNBOND_POOL_CONTRACT_ADDRESS.save(deps.storage, address)?;
+    }
+
     Ok(Response::new()
         .add_attribute("action", "instantiate")
         .add_attribute(
@@ -106,7 +122,12 @@ pub fn instantiate(
             drop_factory_state.core_contract,
         )
         .add_attribute("factory_bonded_denom", bonded_denom)
-        .add_attribute("ibc_remote_denom", drop_core_config.base_denom))
+        .add_attribute("ibc_remote_denom", drop_core_config.base_denom)
+        .add_attribute(
+            "instant_unbond_pool_contract_address",
+            checked_instant_unbond_pool_contract_address
+                .map_or("none".to_string(), |address| address.to_string()),
+        ))
 }
 
 ///////////////
@@ -121,7 +142,10 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        // The min amount out is not used since drop core has no minimum output on
+        // bond, it is verified in the entry point contract instead for both bonding
+        // and unbonding through the instant unbond pool
+        ExecuteMsg::Swap { operations, .. } => execute_swap(deps, env, info, operations),
         ExecuteMsg::TransferFundsBack {
             swapper,
             return_denom,
@@ -132,9 +156,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            unimplemented!()
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -158,27 +180,43 @@ fn execute_swap(
     let remote_denom = IBC_REMOTE_DENOM.load(deps.storage)?;
     let bonded_denom = FACTORY_BONDED_DENOM.load(deps.storage)?;
 
-    // Decide which message to Core contract should be emitted
-    let (drop_core_msg, return_denom) = if coin_in.denom == remote_denom {
-        (
-            drop_staking_base::msg::core::ExecuteMsg::Bond {
+    // Bond the remote denom with drop core, or unbond the bonded denom by swapping it
+    // through the instant unbond pool. Drop core unbonding only returns a withdrawal
+    // voucher, with the remote denom received once the unbonding period has passed.
+    let (swap_msg, return_denom) = if coin_in.denom == remote_denom {
+        let drop_core_contract_address = DROP_CORE_CONTRACT_ADDRESS.load(deps.storage)?;
+
+        let bond_msg = WasmMsg::Execute {
+            contract_addr: drop_core_contract_address.to_string(),
+            msg: to_json_binary(&drop_staking_base::msg::core::ExecuteMsg::Bond {
                 r#ref: None,
                 receiver: None,
-            },
-            bonded_denom,
-        )
+            })?,
+            funds: vec![coin_in],
+        };
+
+        (bond_msg, bonded_denom)
+    } else if coin_in.denom == bonded_denom {
+        let instant_unbond_pool_contract_address = get_instant_unbond_pool(deps.as_ref())?;
+
+        let offer_asset = Asset::Native(coin_in);
+
+        let unbond_msg = offer_asset.clone().into_wasm_msg(
+            instant_unbond_pool_contract_address.to_string(),
+            to_json_binary(&PairExecuteMsg::Swap {
+                offer_asset: offer_asset.into_astroport_asset(deps.api)?,
+                ask_asset_info: None,
+                belief_price: None,
+                max_spread: Some(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+                to: None,
+            })?,
+        )?;
+
+        (unbond_msg, remote_denom)
     } else {
         return Err(ContractError::UnsupportedDenom);
     };
 
-    let drop_core_contract_address = DROP_CORE_CONTRACT_ADDRESS.load(deps.storage)?;
-
-    let swap_msg = WasmMsg::Execute {
-        contract_addr: drop_core_contract_address.to_string(),
-        msg: to_json_binary(&drop_core_msg)?,
-        funds: vec![coin_in],
-    };
-
     // Create the transfer funds back message
     let transfer_funds_back_msg = WasmMsg::Execute {
         contract_addr: env.contract.address.to_string(),
@@ -201,54 +239,27 @@ fn execute_swap(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
-    let remote_denom = IBC_REMOTE_DENOM.load(deps.storage)?;
-    let bonded_denom = FACTORY_BONDED_DENOM.load(deps.storage)?;
-
     match msg {
         QueryMsg::SimulateSwapExactAssetIn { asset_in, .. } => {
-            let asset_out_denom =
-                get_opposite_denom_in(asset_in.denom(), &remote_denom, &bonded_denom)?;
+            let (asset_out, _) = simulate_swap_exact_asset_in(deps, &asset_in)?;
 
-            let exchange_rate = get_exchange_rate(deps)?;
-
-            to_json_binary(&Asset::Native(Coin::new(
-                (asset_in.amount().div_floor(exchange_rate)).into(), //(asset_in.amount().div_floor(exchange_rate)).into()
-                asset_out_denom,
-            )))
+            to_json_binary(&asset_out)
         }
         QueryMsg::SimulateSwapExactAssetOut { asset_out, .. } => {
-            let asset_in_denom =
-                get_opposite_denom_out(asset_out.denom(), &remote_denom, &bonded_denom)?;
-
-            let exchange_rate = get_exchange_rate(deps)?;
+            let (asset_in, _) = simulate_swap_exact_asset_out(deps, &asset_out)?;
 
-            to_json_binary(&Asset::Native(Coin::new(
-                (exchange_rate * asset_out.amount()).into(), //(exchange_rate * asset_out.amount()).into()
-                asset_in_denom,
-            )))
+            to_json_binary(&asset_in)
         }
         QueryMsg::SimulateSwapExactAssetInWithMetadata {
             asset_in,
             include_spot_price,
             ..
         } => {
-            let asset_out_denom =
-                get_opposite_denom_in(asset_in.denom(), &remote_denom, &bonded_denom)?;
-
-            let exchange_rate = get_exchange_rate(deps)?;
-
-            let spot_price = if include_spot_price {
-                Some(exchange_rate)
-            } else {
-                None
-            };
+            let (asset_out, spot_price) = simulate_swap_exact_asset_in(deps, &asset_in)?;
 
             to_json_binary(&SimulateSwapExactAssetInResponse {
-                asset_out: Asset::Native(Coin::new(
-                    (asset_in.amount().div_floor(exchange_rate)).into(),
-                    asset_out_denom,
-                )),
-                spot_price,
+                asset_out,
+                spot_price: include_spot_price.then_some(spot_price),
             })
         }
         QueryMsg::SimulateSwapExactAssetOutWithMetadata {
@@ -256,88 +267,207 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
             include_spot_price,
             ..
         } => {
-            let asset_in_denom =
-                get_opposite_denom_out(asset_out.denom(), &remote_denom, &bonded_denom)?;
-
-            let exchange_rate = get_exchange_rate(deps)?;
-
-            let spot_price = if include_spot_price {
-                Some(exchange_rate)
-            } else {
-                None
-            };
+            let (asset_in, spot_price) = simulate_swap_exact_asset_out(deps, &asset_out)?;
 
             to_json_binary(&SimulateSwapExactAssetOutResponse {
-                asset_in: Asset::Native(Coin::new(
-                    (exchange_rate * asset_out.amount()).into(),
-                    asset_in_denom,
-                )),
-                spot_price,
+                asset_in,
+                spot_price: include_spot_price.then_some(spot_price),
             })
         }
         QueryMsg::SimulateSmartSwapExactAssetIn { asset_in, .. } => {
-            let asset_out_denom =
-                get_opposite_denom_in(asset_in.denom(), &remote_denom, &bonded_denom)?;
-
-            let exchange_rate = get_exchange_rate(deps)?;
+            let (asset_out, _) = simulate_swap_exact_asset_in(deps, &asset_in)?;
 
-            to_json_binary(&Asset::Native(Coin::new(
-                (asset_in.amount().div_floor(exchange_rate)).into(),
-                asset_out_denom,
-            )))
+            to_json_binary(&asset_out)
         }
         QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
             asset_in,
             include_spot_price,
             ..
         } => {
-            let asset_out_denom =
-                get_opposite_denom_in(asset_in.denom(), &remote_denom, &bonded_denom)?;
-
-            let exchange_rate = get_exchange_rate(deps)?;
-
-            let spot_price = if include_spot_price {
-                Some(exchange_rate)
-            } else {
-                None
-            };
+            let (asset_out, spot_price) = simulate_swap_exact_asset_in(deps, &asset_in)?;
 
             to_json_binary(&SimulateSwapExactAssetInResponse {
-                asset_out: Asset::Native(Coin::new(
-                    (asset_in.amount().div_floor(exchange_rate)).into(),
-                    asset_out_denom,
-                )),
-                spot_price,
+                asset_out,
+                spot_price: include_spot_price.then_some(spot_price),
             })
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
-fn get_opposite_denom_in(
-    denom_in: &str,
-    remote_denom: &str,
-    bonded_denom: &str,
-) -> ContractResult<String> {
-    match denom_in {
-        // if doing an 'in' query, only allow for simulating putting the remote denom in
-        denom_in if denom_in == remote_denom => Ok(bonded_denom.to_string()),
-        denom_in if denom_in == bonded_denom => Err(ContractError::UnsupportedDenom),
-        _ => unimplemented!(),
+// Returns the features supported by the drop swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: false,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::Unused],
+    }
+}
+
+// Simulates swapping the asset in, bonding the remote denom or unbonding the bonded denom,
+// returning the asset out and the spot price (the asset in per asset out)
+fn simulate_swap_exact_asset_in(deps: Deps, asset_in: &Asset) -> ContractResult<(Asset, Decimal)> {
+    let remote_denom = IBC_REMOTE_DENOM.load(deps.storage)?;
+    let bonded_denom = FACTORY_BONDED_DENOM.load(deps.storage)?;
+
+    match asset_in.denom() {
+        denom_in if denom_in == remote_denom => {
+            simulate_bond_exact_asset_in(deps, asset_in, bonded_denom)
+        }
+        denom_in if denom_in == bonded_denom => {
+            simulate_unbond_exact_asset_in(deps, asset_in, remote_denom)
+        }
+        _ => Err(ContractError::UnsupportedDenom),
+    }
+}
+
+// Simulates swapping for the asset out, bonding the remote denom or unbonding the bonded
+// denom, returning the asset in needed and the spot price (the asset in per asset out)
+fn simulate_swap_exact_asset_out(
+    deps: Deps,
+    asset_out: &Asset,
+) -> ContractResult<(Asset, Decimal)> {
+    let remote_denom = IBC_REMOTE_DENOM.load(deps.storage)?;
+    let bonded_denom = FACTORY_BONDED_DENOM.load(deps.storage)?;
+
+    match asset_out.denom() {
+        denom_out if denom_out == bonded_denom => {
+            simulate_bond_exact_asset_out(deps, asset_out, remote_denom)
+        }
+        denom_out if denom_out == remote_denom => {
+            simulate_unbond_exact_asset_out(deps, asset_out, bonded_denom)
+        }
+        _ => Err(ContractError::UnsupportedDenom),
     }
 }
 
-fn get_opposite_denom_out(
-    denom_out: &str,
-    remote_denom: &str,
-    bonded_denom: &str,
-) -> ContractResult<String> {
-    match denom_out {
-        // if doing an 'out' query, only allow for simulating getting the bonded denom out
-        denom_out if denom_out == remote_denom => Err(ContractError::UnsupportedDenom),
-        denom_out if denom_out == bonded_denom => Ok(remote_denom.to_string()),
-        _ => unimplemented!(),
+// Simulates bonding the asset in, returning the bonded asset out and the exchange rate.
+// Drop core takes its bond fee of the asset in and mints the bonded denom rounded down,
+// the fee is rounded up so the simulation never mints more than drop core.
+fn simulate_bond_exact_asset_in(
+    deps: Deps,
+    asset_in: &Asset,
+    bonded_denom: String,
+) -> ContractResult<(Asset, Decimal)> {
+    let exchange_rate = get_exchange_rate(deps)?;
+    let config = get_bond_config(deps)?;
+
+    // Error if bonding the asset in would exceed the drop core bond limit
+    assert_bond_limit(deps, &config, asset_in.amount())?;
+
+    let bond_fee = config.bond_fee.unwrap_or_default();
+    let amount_bonded = asset_in
+        .amount()
+        .checked_sub(asset_in.amount().mul_ceil(bond_fee))?;
+
+    Ok((
+        Asset::Native(Coin::new(
+            amount_bonded.div_floor(exchange_rate).into(),
+            bonded_denom,
+        )),
+        exchange_rate,
+    ))
+}
+
+// Simulates bonding for the asset out, returning the asset in needed and the exchange rate.
+// The asset in is rounded up, before and after adding the bond fee, so that bonding it
+// never mints less than the asset out.
+fn simulate_bond_exact_asset_out(
+    deps: Deps,
+    asset_out: &Asset,
+    remote_denom: String,
+) -> ContractResult<(Asset, Decimal)> {
+    let exchange_rate = get_exchange_rate(deps)?;
+    let config = get_bond_config(deps)?;
+
+    let bond_fee = config.bond_fee.unwrap_or_default();
+    if bond_fee >= Decimal::one() {
+        return Err(ContractError::InvalidBondFee);
     }
+
+    let amount_in = asset_out
+        .amount()
+        .mul_ceil(exchange_rate)
+        .div_ceil(Decimal::one() - bond_fee);
+
+    // Error if bonding the asset in would exceed the drop core bond limit
+    assert_bond_limit(deps, &config, amount_in)?;
+
+    Ok((
+        Asset::Native(Coin::new(amount_in.into(), remote_denom)),
+        exchange_rate,
+    ))
+}
+
+// Simulates unbonding the asset in through the instant unbond pool, returning the
+// remote asset out and the pool's spot price
+fn simulate_unbond_exact_asset_in(
+    deps: Deps,
+    asset_in: &Asset,
+    remote_denom: String,
+) -> ContractResult<(Asset, Decimal)> {
+    let instant_unbond_pool_contract_address = get_instant_unbond_pool(deps)?;
+
+    let res: SimulationResponse = deps.querier.query_wasm_smart(
+        instant_unbond_pool_contract_address,
+        &PairQueryMsg::Simulation {
+            offer_asset: asset_in.into_astroport_asset(deps.api)?,
+            ask_asset_info: None,
+        },
+    )?;
+
+    // Calculate the amount out without slippage
+    let amount_out_without_slippage = res
+        .return_amount
+        .checked_add(res.spread_amount)?
+        .checked_add(res.commission_amount)?;
+
+    Ok((
+        Asset::Native(Coin::new(res.return_amount.into(), remote_denom)),
+        Decimal::checked_from_ratio(asset_in.amount(), amount_out_without_slippage)?,
+    ))
+}
+
+// Simulates unbonding for the asset out through the instant unbond pool, returning the
+// bonded asset in needed and the pool's spot price
+fn simulate_unbond_exact_asset_out(
+    deps: Deps,
+    asset_out: &Asset,
+    bonded_denom: String,
+) -> ContractResult<(Asset, Decimal)> {
+    let instant_unbond_pool_contract_address = get_instant_unbond_pool(deps)?;
+
+    let res: ReverseSimulationResponse = deps.querier.query_wasm_smart(
+        instant_unbond_pool_contract_address,
+        &PairQueryMsg::ReverseSimulation {
+            offer_asset_info: None,
+            ask_asset: asset_out.into_astroport_asset(deps.api)?,
+        },
+    )?;
+
+    // Calculate the amount out without slippage
+    let amount_out_without_slippage = asset_out
+        .amount()
+        .checked_add(res.spread_amount)?
+        .checked_add(res.commission_amount)?;
+
+    Ok((
+        Asset::Native(Coin::new(res.offer_amount.into(), bonded_denom)),
+        Decimal::checked_from_ratio(res.offer_amount, amount_out_without_slippage)?,
+    ))
+}
+
+// Returns the instant unbond pool contract address, erroring if it was not set
+fn get_instant_unbond_pool(deps: Deps) -> ContractResult<Addr> {
+    INSTANT_UNBOND_POOL_CONTRACT_ADDRESS
+        .may_load(deps.storage)?
+        .ok_or(ContractError::InstantUnbondPoolNotSet)
 }
 
 fn get_exchange_rate(deps: Deps) -> ContractResult<Decimal> {
@@ -348,5 +478,53 @@ fn get_exchange_rate(deps: Deps) -> ContractResult<Decimal> {
         &drop_staking_base::msg::core::QueryMsg::ExchangeRate {},
     )?;
 
+    // Error instead of dividing by zero in the simulations
+    if exchange_rate.is_zero() {
+        return Err(ContractError::ZeroExchangeRate);
+    }
+
     Ok(exchange_rate)
 }
+
+// The drop core config fields needed to simulate bonding, the other
+// fields of the drop core config query response are ignored
+#[derive(Deserialize)]
+struct DropCoreBondConfig {
+    bond_limit: Option<Uint128>,
+    #[serde(default)]
+    bond_fee: Option<Decimal>,
+}
+
+fn get_bond_config(deps: Deps) -> ContractResult<DropCoreBondConfig> {
+    let drop_core_contract_address = DROP_CORE_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    Ok(deps.querier.query_wasm_smart(
+        &drop_core_contract_address,
+        &drop_staking_base::msg::core::QueryMsg::Config {},
+    )?)
+}
+
+// Errors if bonding the amount would exceed the drop core bond limit, which
+// drop core enforces on the total bonded amount
+fn assert_bond_limit(
+    deps: Deps,
+    config: &DropCoreBondConfig,
+    amount: Uint128,
+) -> ContractResult<()> {
+    let Some(bond_limit) = config.bond_limit else {
+        return Ok(());
+    };
+
+    let drop_core_contract_address = DROP_CORE_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    let total_bonded: Uint128 = deps.querier.query_wasm_smart(
+        &drop_core_contract_address,
+        &drop_staking_base::msg::core::QueryMsg::TotalBonded {},
+    )?;
+
+    if total_bonded.checked_add(amount)? > bond_limit {
+        return Err(ContractError::BondLimitExceeded);
+    }
+
+    Ok(())
+}
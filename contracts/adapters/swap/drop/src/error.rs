@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{CheckedFromRatioError, OverflowError, StdError};
 use skip::error::SkipError;
 use thiserror::Error;
 
@@ -15,12 +15,30 @@ pub enum ContractError {
     #[error(transparent)]
     Payment(#[from] cw_utils::PaymentError),
 
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error(transparent)]
+    CheckedFromRatio(#[from] CheckedFromRatioError),
+
     #[error("Unauthorized")]
     Unauthorized,
 
     #[error("this denom is not supported by Drop")]
     UnsupportedDenom,
 
+    #[error("instant unbond pool was not set, drop core unbonding is not instant")]
+    InstantUnbondPoolNotSet,
+
+    #[error("drop bond fee must be less than one")]
+    InvalidBondFee,
+
+    #[error("drop exchange rate is zero")]
+    ZeroExchangeRate,
+
+    #[error("bonding would exceed the drop core bond limit")]
+    BondLimitExceeded,
+
     #[error("bonded denom was not set")]
     BondedDenomNotSet,
 }
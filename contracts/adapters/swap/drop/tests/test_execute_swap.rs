@@ -1,8 +1,12 @@
+use astroport::{
+    asset::{Asset as AstroportAsset, AssetInfo},
+    pair::ExecuteMsg as PairExecuteMsg,
+};
 use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
-    to_json_binary, Addr, Coin,
+    to_json_binary, Addr, Coin, Decimal,
     ReplyOn::Never,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 // use lido_satellite::msg::ExecuteMsg as LidoSatelliteExecuteMsg;
 use skip::swap::ExecuteMsg;
@@ -10,7 +14,7 @@ use skip_go_swap_adapter_drop::{
     error::{ContractError, ContractResult},
     state::{
         DROP_CORE_CONTRACT_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS, FACTORY_BONDED_DENOM,
-        IBC_REMOTE_DENOM,
+        IBC_REMOTE_DENOM, INSTANT_UNBOND_POOL_CONTRACT_ADDRESS,
     },
 };
 use test_case::test_case;
@@ -20,9 +24,11 @@ Test Cases:
 
 Expect Success
     - 'Bond' Swap Operation
+    - 'Unbond' Swap Operation (Swapped through the instant unbond pool)
 
 Expect Error
     - Incorrect denom
+    - Bonded denom sent without an instant unbond pool (Drop core unbonding is not instant)
     - Unauthorized Caller (Only the stored entry point contract can call this function)
     - No Coin Sent
     - More Than One Coin Sent
@@ -32,6 +38,7 @@ Expect Error
 // Define test parameters
 struct Params {
     caller: String,
+    instant_unbond_pool_contract_address: Option<Addr>,
     info_funds: Vec<Coin>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
@@ -41,6 +48,7 @@ struct Params {
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
+        instant_unbond_pool_contract_address: Some(Addr::unchecked("instant_unbond_pool")),
         info_funds: vec![Coin::new(100, "ibc/uatom")],
         expected_messages: vec![
             SubMsg {
@@ -75,6 +83,7 @@ struct Params {
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
+        instant_unbond_pool_contract_address: Some(Addr::unchecked("instant_unbond_pool")),
         info_funds: vec![Coin::new(100, "uosmo")],
         expected_messages: vec![],
         expected_error: Some(ContractError::UnsupportedDenom),
@@ -83,6 +92,62 @@ struct Params {
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
+        instant_unbond_pool_contract_address: Some(Addr::unchecked("instant_unbond_pool")),
+        info_funds: vec![Coin::new(100, "factory/uatom")],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "instant_unbond_pool".to_string(),
+                    msg: to_json_binary(&PairExecuteMsg::Swap {
+                        offer_asset: AstroportAsset {
+                            info: AssetInfo::NativeToken {
+                                denom: "factory/uatom".to_string(),
+                            },
+                            amount: Uint128::new(100),
+                        },
+                        ask_asset_info: None,
+                        belief_price: None,
+                        max_spread: Some(Decimal::percent(50)),
+                        to: None,
+                    })?,
+                    funds: vec![Coin::new(100, "factory/uatom")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        swapper: Addr::unchecked("entry_point"),
+                        return_denom: String::from("ibc/uatom"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "'Unbond' Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        instant_unbond_pool_contract_address: None,
+        info_funds: vec![Coin::new(100, "factory/uatom")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::InstantUnbondPoolNotSet),
+    };
+    "Bonded denom sent without an instant unbond pool - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        instant_unbond_pool_contract_address: Some(Addr::unchecked("instant_unbond_pool")),
         info_funds: vec![],
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::NoFunds{})),
@@ -91,6 +156,7 @@ struct Params {
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
+        instant_unbond_pool_contract_address: Some(Addr::unchecked("instant_unbond_pool")),
         info_funds: vec![
             Coin::new(100, "untrn"),
             Coin::new(100, "uosmo"),
@@ -102,6 +168,7 @@ struct Params {
 #[test_case(
     Params {
         caller: "random".to_string(),
+        instant_unbond_pool_contract_address: Some(Addr::unchecked("instant_unbond_pool")),
         info_funds: vec![
             Coin::new(100, "untrn"),
             Coin::new(100, "uosmo"),
@@ -133,6 +200,11 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         &Addr::unchecked("drop_core_contract"),
     )?;
 
+    // Store the instant unbond pool contract address if set
+    if let Some(address) = params.instant_unbond_pool_contract_address {
+        INSTANT_UNBOND_POOL_CONTRACT_ADDRESS.save(deps.as_mut().storage, &address)?;
+    }
+
     // Store Lido Satellite denoms
     IBC_REMOTE_DENOM.save(deps.as_mut().storage, &String::from("ibc/uatom"))?;
     FACTORY_BONDED_DENOM.save(deps.as_mut().storage, &String::from("factory/uatom"))?;
@@ -142,7 +214,10 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         deps.as_mut(),
         env,
         info,
-        ExecuteMsg::Swap { operations: vec![] },
+        ExecuteMsg::Swap {
+            operations: vec![],
+            min_amount_out: None,
+        },
     );
 
     // Assert the behavior is correct
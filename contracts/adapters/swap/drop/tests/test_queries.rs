@@ -1,22 +1,55 @@
+use astroport::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
+use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
+    from_json,
     testing::{mock_dependencies, mock_env},
     to_json_binary, Addr, Binary, Coin, ContractResult as SystemContractResult, Decimal,
-    QuerierResult, SystemResult, WasmQuery,
+    QuerierResult, SystemResult, Uint128, WasmQuery,
 };
+use drop_staking_base::msg::core::QueryMsg as DropCoreQueryMsg;
 // use lido_satellite::msg::ExecuteMsg as LidoSatelliteExecuteMsg;
 use skip::{asset::Asset, swap::QueryMsg};
 use skip_go_swap_adapter_drop::{
     error::ContractError,
     error::ContractResult,
-    state::{DROP_CORE_CONTRACT_ADDRESS, FACTORY_BONDED_DENOM, IBC_REMOTE_DENOM},
+    state::{
+        DROP_CORE_CONTRACT_ADDRESS, FACTORY_BONDED_DENOM, IBC_REMOTE_DENOM,
+        INSTANT_UNBOND_POOL_CONTRACT_ADDRESS,
+    },
 };
 use test_case::test_case;
 
+/*
+Test Cases:
+
+Expect Success
+    - Simulate queries bonding the remote denom with the exchange rate
+    - Simulate queries unbonding the bonded denom through the instant unbond pool (The mock pool takes a 10% commission)
+    - Simulate exact out query rounding the asset in up
+    - Simulate queries taking the bond fee
+    - Capabilities Query
+
+Expect Error
+    - Simulate queries with an unknown denom
+    - Simulate exact out query with a bond fee of one
+    - Simulate queries exceeding the bond limit (The mock drop core has a bond limit of 1000 and 800 bonded)
+    - Simulate query with a zero exchange rate
+
+ */
+
+// The part of the drop core config returned by the mock drop core contract
+#[cw_serde]
+struct MockDropCoreConfig {
+    bond_limit: Option<Uint128>,
+    bond_fee: Option<Decimal>,
+}
+
 // Define test parameters
 struct Params {
     query: QueryMsg,
     response: Binary,
     exchange_rate: Decimal,
+    bond_fee: Option<Decimal>,
     expected_error: Option<ContractError>,
 }
 
@@ -32,6 +65,7 @@ struct Params {
             "factory/uatom",
         ))).unwrap(),
         exchange_rate: Decimal::one(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetIn Query")]
@@ -46,6 +80,7 @@ struct Params {
             "factory/uatom",
         ))).unwrap(),
         exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetIn Query half exchange rate")]
@@ -55,9 +90,13 @@ struct Params {
             swap_operations: vec![],
             asset_out: Asset::Native(Coin::new(100, "ibc/uatom")),
         },
-        response: Binary([].to_vec()),
+        response: to_json_binary(&Asset::Native(Coin::new(
+            111,
+            "factory/uatom",
+        ))).unwrap(),
         exchange_rate: Decimal::one(),
-        expected_error: Some(ContractError::UnsupportedDenom),
+        bond_fee: None,
+        expected_error: None,
     };
     "SimulateSwapExactAssetOut Query - want out remote denom")]
 #[test_case(
@@ -71,6 +110,7 @@ struct Params {
             "ibc/uatom",
         ))).unwrap(),
         exchange_rate: Decimal::one(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetOut Query - want out factory denom")]
@@ -85,6 +125,7 @@ struct Params {
             "ibc/uatom",
         ))).unwrap(),
         exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetOut Query half exchange rate - want out factory denom")]
@@ -94,9 +135,13 @@ struct Params {
             swap_operations: vec![],
             asset_out: Asset::Native(Coin::new(100, "ibc/uatom")),
         },
-        response: Binary([].to_vec()),
+        response: to_json_binary(&Asset::Native(Coin::new(
+            111,
+            "factory/uatom",
+        ))).unwrap(),
         exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
-        expected_error: Some(ContractError::UnsupportedDenom),
+        bond_fee: None,
+        expected_error: None,
     };
     "SimulateSwapExactAssetOut Query half exchange rate - want out remote denom")]
 #[test_case(
@@ -114,6 +159,7 @@ struct Params {
             spot_price: None
         }).unwrap(),
         exchange_rate: Decimal::one(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetInWithMetadata Query")]
@@ -132,6 +178,7 @@ struct Params {
             spot_price: Some(Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap())
         }).unwrap(),
         exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetInWithMetadata Query include spot price")]
@@ -150,6 +197,7 @@ struct Params {
             spot_price: None
         }).unwrap(),
         exchange_rate: Decimal::one(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetOutWithMetadata Query - want out factory denom")]
@@ -160,9 +208,16 @@ struct Params {
             asset_out: Asset::Native(Coin::new(100, "ibc/uatom")),
             include_spot_price: false,
         },
-        response: Binary([].to_vec()),
+        response: to_json_binary(&skip::swap::SimulateSwapExactAssetOutResponse{
+            asset_in: Asset::Native(Coin::new(
+                111,
+                "factory/uatom",
+            )),
+            spot_price: None
+        }).unwrap(),
         exchange_rate: Decimal::one(),
-        expected_error: Some(ContractError::UnsupportedDenom),
+        bond_fee: None,
+        expected_error: None,
     };
     "SimulateSwapExactAssetOutWithMetadata Query - want out remote denom")]
 #[test_case(
@@ -172,9 +227,16 @@ struct Params {
             asset_out: Asset::Native(Coin::new(100, "ibc/uatom")),
             include_spot_price: true,
         },
-        response: Binary([].to_vec()),
+        response: to_json_binary(&skip::swap::SimulateSwapExactAssetOutResponse{
+            asset_in: Asset::Native(Coin::new(
+                111,
+                "factory/uatom",
+            )),
+            spot_price: Some(Decimal::one())
+        }).unwrap(),
         exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
-        expected_error: Some(ContractError::UnsupportedDenom),
+        bond_fee: None,
+        expected_error: None,
     };
     "SimulateSwapExactAssetOutWithMetadata Query include spot price - want out remote denom")]
 #[test_case(
@@ -192,24 +254,243 @@ struct Params {
             spot_price: Some(Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap())
         }).unwrap(),
         exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: None,
         expected_error: None,
     };
     "SimulateSwapExactAssetOutWithMetadata Query include spot price - want out factory denom")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(100, "uosmo")),
+        },
+        response: Binary([].to_vec()),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: Some(ContractError::UnsupportedDenom),
+    };
+    "SimulateSwapExactAssetIn Query - unknown denom in")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            swap_operations: vec![],
+            asset_out: Asset::Native(Coin::new(100, "uosmo")),
+        },
+        response: Binary([].to_vec()),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: Some(ContractError::UnsupportedDenom),
+    };
+    "SimulateSwapExactAssetOut Query - unknown denom out")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(100, "factory/uatom")),
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(
+            90,
+            "ibc/uatom",
+        ))).unwrap(),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetIn Query - bonded denom in")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(100, "factory/uatom")),
+            include_spot_price: true,
+        },
+        response: to_json_binary(&skip::swap::SimulateSwapExactAssetInResponse{
+            asset_out: Asset::Native(Coin::new(
+                90,
+                "ibc/uatom",
+            )),
+            spot_price: Some(Decimal::one())
+        }).unwrap(),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetInWithMetadata Query include spot price - bonded denom in")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(100, "ibc/uatom")),
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(
+            180,
+            "factory/uatom",
+        ))).unwrap(),
+        exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: Some(Decimal::percent(10)),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetIn Query half exchange rate - bond fee taken")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            swap_operations: vec![],
+            asset_out: Asset::Native(Coin::new(180, "factory/uatom")),
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(
+            100,
+            "ibc/uatom",
+        ))).unwrap(),
+        exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: Some(Decimal::percent(10)),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetOut Query half exchange rate - bond fee added")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            swap_operations: vec![],
+            asset_out: Asset::Native(Coin::new(100, "factory/uatom")),
+        },
+        response: Binary([].to_vec()),
+        exchange_rate: Decimal::one(),
+        bond_fee: Some(Decimal::one()),
+        expected_error: Some(ContractError::InvalidBondFee),
+    };
+    "SimulateSwapExactAssetOut Query - bond fee of one")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            swap_operations: vec![],
+            asset_out: Asset::Native(Coin::new(101, "factory/uatom")),
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(
+            51,
+            "ibc/uatom",
+        ))).unwrap(),
+        exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(5), 1).unwrap(),
+        bond_fee: None,
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetOut Query half exchange rate - asset in rounded up")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(201, "ibc/uatom")),
+        },
+        response: Binary([].to_vec()),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: Some(ContractError::BondLimitExceeded),
+    };
+    "SimulateSwapExactAssetIn Query - bond limit exceeded")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(200, "ibc/uatom")),
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(
+            200,
+            "factory/uatom",
+        ))).unwrap(),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetIn Query - bond limit reached")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            swap_operations: vec![],
+            asset_out: Asset::Native(Coin::new(100, "factory/uatom")),
+            include_spot_price: false,
+        },
+        response: Binary([].to_vec()),
+        exchange_rate: Decimal::from_atomics(cosmwasm_std::Uint128::new(3), 0).unwrap(),
+        bond_fee: None,
+        expected_error: Some(ContractError::BondLimitExceeded),
+    };
+    "SimulateSwapExactAssetOutWithMetadata Query - bond limit exceeded")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            swap_operations: vec![],
+            asset_in: Asset::Native(Coin::new(100, "ibc/uatom")),
+        },
+        response: Binary([].to_vec()),
+        exchange_rate: Decimal::zero(),
+        bond_fee: None,
+        expected_error: Some(ContractError::ZeroExchangeRate),
+    };
+    "SimulateSwapExactAssetIn Query - zero exchange rate")]
+#[test_case(
+    Params {
+        query: QueryMsg::Capabilities {},
+        response: to_json_binary(&skip::swap::CapabilitiesResponse {
+            swap_exact_asset_in: true,
+            swap_exact_asset_out: true,
+            swap_exact_asset_out_execution: false,
+            smart_swap: true,
+            smart_swap_execution: false,
+            cw20_input: false,
+            spot_price: true,
+            pool_identifiers: vec![skip::swap::PoolIdentifier::Unused],
+        }).unwrap(),
+        exchange_rate: Decimal::one(),
+        bond_fee: None,
+        expected_error: None,
+    };
+    "Capabilities Query")]
 
 fn test_queries(params: Params) -> ContractResult<()> {
     // Create mock dependencies
     let mut deps = mock_dependencies();
 
     let exchange_rate = params.exchange_rate;
+    let bond_fee = params.bond_fee;
 
-    // Create mock wasm handler to handle the swap adapter contract query
+    // Create mock wasm handler to handle the drop core contract queries, with
+    // a bond limit of 1000 and a total bonded amount of 800, and the instant
+    // unbond pool queries, with a commission of 10% of the offer amount
     let wasm_handler = move |query: &WasmQuery| -> QuerierResult {
         match query {
-            WasmQuery::Smart { contract_addr, .. } => {
+            WasmQuery::Smart { contract_addr, msg } => {
                 if contract_addr == "drop_core_contract" {
-                    SystemResult::Ok(SystemContractResult::Ok(
-                        to_json_binary(&exchange_rate).unwrap(),
-                    ))
+                    let response = match from_json(msg).unwrap() {
+                        DropCoreQueryMsg::ExchangeRate {} => to_json_binary(&exchange_rate),
+                        DropCoreQueryMsg::Config {} => to_json_binary(&MockDropCoreConfig {
+                            bond_limit: Some(Uint128::new(1000)),
+                            bond_fee,
+                        }),
+                        DropCoreQueryMsg::TotalBonded {} => to_json_binary(&Uint128::new(800)),
+                        _ => panic!("Unsupported drop core query: {:?}", query),
+                    };
+
+                    SystemResult::Ok(SystemContractResult::Ok(response.unwrap()))
+                } else if contract_addr == "instant_unbond_pool" {
+                    let response = match from_json(msg).unwrap() {
+                        PairQueryMsg::Simulation { offer_asset, .. } => {
+                            let commission_amount = offer_asset.amount / Uint128::new(10);
+                            to_json_binary(&SimulationResponse {
+                                return_amount: offer_asset.amount - commission_amount,
+                                spread_amount: Uint128::zero(),
+                                commission_amount,
+                            })
+                        }
+                        PairQueryMsg::ReverseSimulation { ask_asset, .. } => {
+                            let commission_amount = ask_asset.amount / Uint128::new(9);
+                            to_json_binary(&ReverseSimulationResponse {
+                                offer_amount: ask_asset.amount + commission_amount,
+                                spread_amount: Uint128::zero(),
+                                commission_amount,
+                            })
+                        }
+                        _ => panic!("Unsupported pool query: {:?}", query),
+                    };
+
+                    SystemResult::Ok(SystemContractResult::Ok(response.unwrap()))
                 } else {
                     panic!("Unsupported contract: {:?}", query);
                 }
@@ -231,6 +512,12 @@ fn test_queries(params: Params) -> ContractResult<()> {
         &Addr::unchecked("drop_core_contract"),
     )?;
 
+    // Store the instant unbond pool contract address
+    INSTANT_UNBOND_POOL_CONTRACT_ADDRESS.save(
+        deps.as_mut().storage,
+        &Addr::unchecked("instant_unbond_pool"),
+    )?;
+
     // Store drop remote and bonded denoms
     IBC_REMOTE_DENOM.save(deps.as_mut().storage, &String::from("ibc/uatom"))?;
     FACTORY_BONDED_DENOM.save(deps.as_mut().storage, &String::from("factory/uatom"))?;
@@ -4,34 +4,50 @@ use crate::{
 };
 use cosmwasm_std::{
     entry_point, to_json_binary, Binary, Coin, CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Env,
-    Int128, MessageInfo, Response, StdError, Uint128, WasmMsg,
+    Int128, MessageInfo, Reply, Response, StdError, SubMsg, SubMsgResult, Uint128, Uint256,
+    WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_utils::one_coin;
 use neutron_sdk::stargate::dex::types::{
-    AllTickLiquidityRequest, AllTickLiquidityResponse, LimitOrderType, SimulateMultiHopSwapRequest,
+    AllTickLiquidityRequest, AllTickLiquidityResponse, GetLimitOrderTrancheRequest,
+    GetLimitOrderTrancheResponse, LimitOrderTrancheUserRequest, LimitOrderTrancheUserResponse,
+    LimitOrderType as DualityLimitOrderType, PlaceLimitOrderRequest, SimulateMultiHopSwapRequest,
     SimulateMultiHopSwapResponse, SimulatePlaceLimitOrderRequest, SimulatePlaceLimitOrderResponse,
     TickLiquidity::{LimitOrderTranche, PoolReserves},
 };
 use neutron_sdk::{
     bindings::query::PageRequest,
-    proto_types::neutron::dex::{MsgMultiHopSwap, MultiHopRoute},
+    proto_types::neutron::dex::{MsgMultiHopSwap, MsgPlaceLimitOrderResponse, MultiHopRoute},
     stargate::{
         aux::create_stargate_msg,
+        dex::msg::msg_place_limit_order,
         dex::query::{
-            get_simulate_multi_hop_swap, get_simulate_place_limit_order, get_tick_liquidity_all,
+            get_limit_order_tranche, get_limit_order_tranche_user, get_simulate_multi_hop_swap,
+            get_simulate_place_limit_order, get_tick_liquidity_all,
         },
     },
 };
 
+use prost::Message;
 use std::str::FromStr;
 
+// Duality prices are integers in units of 1/10^27
+const EXIT_LIMIT_PRICE_SCALE: u128 = 1_000_000_000_000_000_000_000_000_000;
+
+// Reply id of the place limit order sub message
+pub const PLACE_LIMIT_ORDER_REPLY_ID: u64 = 1;
+
 use skip::{
     asset::Asset,
+    error::SkipError,
     swap::{
-        execute_transfer_funds_back, get_ask_denom_for_routes,
-        DualityInstantiateMsg as InstantiateMsg, ExecuteMsg, MigrateMsg, QueryMsg, Route,
-        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
+        execute_refund_asset_in, execute_transfer_funds_back, get_ask_denom_for_routes,
+        CapabilitiesResponse, DualityExecuteMsg, DualityInstantiateMsg as InstantiateMsg,
+        DualityLimitOrderExecuteMsg, DualityLimitOrderQueryMsg, DualityQueryMsg, ExecuteMsg,
+        LimitOrder, LimitOrderTrancheStatusResponse, LimitOrderType, MigrateMsg, PoolIdentifier,
+        QueryMsg, Route, SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation,
     },
 };
 
@@ -41,7 +57,7 @@ use skip::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -90,13 +106,44 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: DualityExecuteMsg,
+) -> ContractResult<Response> {
+    match msg {
+        DualityExecuteMsg::SwapAdapter(msg) => execute_swap_adapter(deps, env, info, msg),
+        DualityExecuteMsg::LimitOrder(DualityLimitOrderExecuteMsg::PlaceLimitOrder {
+            limit_order,
+        }) => execute_place_limit_order(deps, env, info, limit_order),
+    }
+}
+
+fn execute_swap_adapter(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, operations, min_amount_out),
+        ExecuteMsg::SmartSwap {
+            routes,
+            min_amount_out,
+        } => execute_smart_swap(deps, env, info, routes, min_amount_out),
+        ExecuteMsg::RefundAssetIn {
+            refund_address,
+            denom_in,
+        } => Ok(execute_refund_asset_in(
+            deps,
+            env,
+            info,
+            refund_address,
+            denom_in,
+        )?),
         ExecuteMsg::TransferFundsBack {
             swapper,
             return_denom,
@@ -107,9 +154,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            unimplemented!()
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -118,6 +163,7 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -135,8 +181,11 @@ fn execute_swap(
         None => return Err(ContractError::SwapOperationsEmpty),
     };
 
+    // Convert the swap operations into a Duality multi hop swap route.
+    let route = get_route_from_swap_operations(operations)?;
+
     //build duality Swap message
-    let swap_msg: CosmosMsg = create_duality_swap_msg(&env, coin_in, operations)?;
+    let swap_msg: CosmosMsg = create_duality_swap_msg(&env, coin_in, vec![route], min_amount_out)?;
 
     // Create the transfer funds back message
     let transfer_funds_back_msg = WasmMsg::Execute {
@@ -154,23 +203,145 @@ fn execute_swap(
         .add_attribute("action", "dispatch_swap_and_transfer_back"))
 }
 
+// Executes a smart swap as a single duality multi hop swap over all the routes. Duality
+// swaps the whole coin in through the route with the best return, so the route offer
+// amounts only need to add up to the coin in.
+fn execute_smart_swap(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    routes: Vec<Route>,
+    min_amount_out: Option<Uint128>,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Get the return denom from the last route, error if there are no routes
+    let return_denom = get_ask_denom_for_routes(&routes)?;
+
+    // Verify the route offer assets are of the coin in denom and add up to exactly the coin in
+    let offer_amount = get_routes_offer_amount(&routes, &coin_in.denom, &return_denom)?;
+
+    if offer_amount != coin_in.amount {
+        return Err(ContractError::RouteOfferAssetAmountMismatch);
+    }
+
+    // Convert the routes into Duality multi hop swap routes
+    let multi_hop_routes = routes
+        .into_iter()
+        .map(|route| get_route_from_swap_operations(route.operations))
+        .collect::<ContractResult<Vec<MultiHopRoute>>>()?;
+
+    // Create the duality multi hop swap message over all the routes
+    let swap_msg = create_duality_swap_msg(&env, coin_in, multi_hop_routes, min_amount_out)?;
+
+    // Create the transfer funds back message
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+            return_denom,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_smart_swap_and_transfer_back"))
+}
+
+// Places a limit order selling the coin sent on the duality dex, with the limit order's
+// receiver owning the order. The part of the coin sent the order does not use, e.g. the
+// unfilled part of an immediate or cancel order, is refunded to the receiver. The order
+// is placed in a sub message so its tranche key can be emitted on reply.
+fn execute_place_limit_order(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    limit_order: LimitOrder,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Error if the limit order sells the coin in for itself
+    if coin_in.denom == limit_order.denom_out {
+        return Err(ContractError::SameSwapDenoms);
+    }
+
+    // Validate the receiver of the limit order
+    let checked_receiver = deps.api.addr_validate(&limit_order.receiver)?;
+
+    // Get the duality limit order type and expiration time of the limit order
+    let (order_type, expiration_time) = get_duality_limit_order_type(&env, limit_order.order_type)?;
+
+    // Create the duality place limit order message
+    let place_limit_order_msg = msg_place_limit_order(PlaceLimitOrderRequest {
+        sender: env.contract.address.to_string(),
+        receiver: checked_receiver.to_string(),
+        token_in: coin_in.denom.clone(),
+        token_out: limit_order.denom_out,
+        tick_index_in_to_out: limit_order.tick_index_in_to_out,
+        amount_in: coin_in.amount.to_string(),
+        order_type,
+        expiration_time,
+        max_amount_out: limit_order.max_amount_out.map(|amount| amount.to_string()),
+        limit_sell_price: None,
+        min_avg_sell_price: None,
+    });
+
+    // Create the refund asset in message to refund the coin in the order did not use
+    let refund_asset_in_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::RefundAssetIn {
+            refund_address: checked_receiver,
+            denom_in: coin_in.denom,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(
+            place_limit_order_msg,
+            PLACE_LIMIT_ORDER_REPLY_ID,
+        ))
+        .add_message(refund_asset_in_msg)
+        .add_attribute("action", "dispatch_place_limit_order"))
+}
+
 // Creates the duality swap message
 fn create_duality_swap_msg(
     env: &Env,
     coin_in: Coin,
-    swap_operations: Vec<SwapOperation>,
+    routes: Vec<MultiHopRoute>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<CosmosMsg> {
-    // Convert the swap operations into a Duality multi hop swap route.
-    let route = get_route_from_swap_operations(swap_operations)?;
+    // Get the exit limit price before the amount in is moved into the message
+    let exit_limit_price = get_exit_limit_price(coin_in.amount, min_amount_out);
 
     // Create the duality multi hop swap message
     let swap_msg = MsgMultiHopSwap {
         creator: env.contract.address.to_string(),
         receiver: env.contract.address.to_string(),
-        routes: vec![route],
+        routes,
         amount_in: coin_in.amount.to_string(),
-        // From duality side this price represents 1/10^27 -- the smallest possible price
-        exit_limit_price: "1".to_string(),
+        exit_limit_price,
         pick_best_route: true,
     };
 
@@ -180,12 +351,73 @@ fn create_duality_swap_msg(
     ))
 }
 
+// Converts the min amount out into the multi hop swap exit limit price, the minimum
+// amount out per amount in the swap must exit at. From duality side the price is an
+// integer in units of 1/10^27, so it is rounded down to never be stricter than the
+// min amount out. Without a min amount out, or when rounding down to zero, the price
+// is set to 1 -- the smallest possible price.
+fn get_exit_limit_price(amount_in: Uint128, min_amount_out: Option<Uint128>) -> String {
+    match min_amount_out {
+        Some(min_amount_out) => Uint256::from(min_amount_out)
+            .multiply_ratio(EXIT_LIMIT_PRICE_SCALE, amount_in)
+            .max(Uint256::one())
+            .to_string(),
+        None => "1".to_string(),
+    }
+}
+
+/////////////
+/// REPLY ///
+/////////////
+
+// Emits the tranche key of the limit order placed, used to query the status of the order
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, reply: Reply) -> ContractResult<Response> {
+    // Error if the reply id is not the one of the place limit order sub message
+    // This should never happen since it is the only sub message dispatched
+    if reply.id != PLACE_LIMIT_ORDER_REPLY_ID {
+        unreachable!()
+    }
+
+    // Get the sub message response from the reply, the sub message is set
+    // to reply on success only so the result is never an error
+    let SubMsgResult::Ok(sub_msg_response) = reply.result else {
+        unreachable!()
+    };
+
+    // Parse the place limit order response from the sub message response data
+    let resp = MsgPlaceLimitOrderResponse::decode(
+        sub_msg_response
+            .data
+            .ok_or(ContractError::MissingResponseData)?
+            .as_slice(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "place_limit_order")
+        .add_attribute("tranche_key", resp.tranche_key))
+}
+
 /////////////
 /// QUERY ///
 /////////////
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: DualityQueryMsg) -> ContractResult<Binary> {
+    match msg {
+        DualityQueryMsg::SwapAdapter(msg) => query_swap_adapter(deps, env, msg),
+        DualityQueryMsg::LimitOrder(DualityLimitOrderQueryMsg::LimitOrderTrancheStatus {
+            address,
+            tranche_key,
+        }) => Ok(to_json_binary(&query_limit_order_tranche_status(
+            deps,
+            address,
+            tranche_key,
+        )?)?),
+    }
+}
+
+fn query_swap_adapter(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
     match msg {
         QueryMsg::SimulateSwapExactAssetIn {
             asset_in,
@@ -250,10 +482,79 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the duality swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: true,
+        cw20_input: false,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::Unused],
+    }
+}
+
+// Returns the status of the address' share of the limit order tranche, with the
+// tranche reserves while the tranche is still active
+fn query_limit_order_tranche_status(
+    deps: Deps,
+    address: String,
+    tranche_key: String,
+) -> ContractResult<LimitOrderTrancheStatusResponse> {
+    let tranche_user_response: LimitOrderTrancheUserResponse = get_limit_order_tranche_user(
+        deps,
+        LimitOrderTrancheUserRequest {
+            address,
+            tranche_key: tranche_key.clone(),
+            calc_withdrawable_shares: true,
+        },
+    )?;
+
+    let Some(tranche_user) = tranche_user_response.limit_order_tranche_user else {
+        return Err(ContractError::LimitOrderTrancheUserNotFound);
+    };
+
+    // The tranche is only found while it is active, it is moved to the
+    // inactive tranches once it has been fully filled or has expired
+    let tranche_response: Option<GetLimitOrderTrancheResponse> = get_limit_order_tranche(
+        deps,
+        GetLimitOrderTrancheRequest {
+            pair_id: new_pair_id_str(
+                &tranche_user.trade_pair_id.maker_denom,
+                &tranche_user.trade_pair_id.taker_denom,
+            ),
+            tick_index: tranche_user.tick_index_taker_to_maker.i64(),
+            token_in: tranche_user.trade_pair_id.maker_denom.clone(),
+            tranche_key,
+        },
+    )
+    .ok();
+    let tranche = tranche_response.and_then(|response| response.limit_order_tranche);
+
+    Ok(LimitOrderTrancheStatusResponse {
+        shares_owned: tranche_user.shares_owned.unsigned_abs(),
+        shares_withdrawn: tranche_user.shares_withdrawn.unsigned_abs(),
+        shares_cancelled: tranche_user.shares_cancelled.unsigned_abs(),
+        withdrawable_shares: tranche_user_response
+            .withdrawable_shares
+            .unwrap_or_default()
+            .unsigned_abs(),
+        reserves_maker_denom: tranche
+            .as_ref()
+            .map(|tranche| tranche.reserves_maker_denom.unsigned_abs()),
+        reserves_taker_denom: tranche
+            .as_ref()
+            .map(|tranche| tranche.reserves_taker_denom.unsigned_abs()),
+    })
+}
+
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
     _env: Env,
@@ -285,10 +586,21 @@ fn query_simulate_swap_exact_asset_in(
     let duality_multi_hop_swap_route =
         get_route_from_swap_operations_for_query(swap_operations).unwrap();
 
+    simulate_multi_hop_swap(deps, coin_in, duality_multi_hop_swap_route, denom_out)
+}
+
+// Simulates a duality multi hop swap of the coin in over the given routes,
+// returning the asset out of the best route
+fn simulate_multi_hop_swap(
+    deps: Deps,
+    coin_in: Coin,
+    routes: Vec<Vec<String>>,
+    denom_out: String,
+) -> ContractResult<Asset> {
     let query_msg: SimulateMultiHopSwapRequest = SimulateMultiHopSwapRequest {
         sender: String::from(""),
         receiver: String::from(""),
-        routes: duality_multi_hop_swap_route,
+        routes,
         amount_in: coin_in.amount.to_string(),
         // From duality side this price represents 1/10^27 -- the smallest possible price
         exit_limit_price: "1".to_string(),
@@ -391,58 +703,71 @@ fn query_simulate_swap_exact_asset_out_with_metadata(
     Ok(response)
 }
 
-/////////////////
-// UNSUPPORTED //
-/////////////////
-
-// Smart swap is not supported since it cannot happen atomically right now.
-// These functions can be used as long as routes is of length 1.
-// This effectively makes them the same as their non-smart counterparts:
-// "query_simulate_swap_exact_asset_in" and "query_simulate_swap_exact_asset_out"
+// Simulates a smart swap as a single duality multi hop swap of the total route
+// offer amount over all the routes, since duality swaps the whole amount in
+// through the route with the best return
 fn query_simulate_smart_swap_exact_asset_in(
     deps: Deps,
-    env: Env,
+    _env: Env,
     ask_denom: String,
     routes: Vec<Route>,
 ) -> ContractResult<Asset> {
-    if routes.len() != 1 {
-        return Err(ContractError::SmartSwapUnsupported);
-    }
-    let sim_asset_out = query_simulate_swap_exact_asset_in(
+    // Get the offer denom from the first route, every route must offer the same denom
+    let offer_denom = match routes.first() {
+        Some(route) => route.offer_asset.denom().to_string(),
+        None => return Err(SkipError::RoutesEmpty.into()),
+    };
+
+    let offer_amount = get_routes_offer_amount(&routes, &offer_denom, &ask_denom)?;
+
+    simulate_multi_hop_swap(
         deps,
-        env,
-        routes[0].offer_asset.clone(),
-        routes[0].operations.clone(),
-    )?;
-    if *sim_asset_out.denom() == ask_denom {
-        Ok(sim_asset_out)
-    } else {
-        Err(ContractError::SmartSwapUnexpectedOut)
-    }
+        Coin::new(offer_amount.u128(), offer_denom),
+        get_routes_for_query(routes)?,
+        ask_denom,
+    )
 }
+
+// same as query_simulate_smart_swap_exact_asset_in but simulates the given asset in and
+// also returns the spot price of the route with the best spot price if required
 fn query_simulate_smart_swap_exact_asset_in_with_metadata(
     deps: Deps,
-    env: Env,
+    _env: Env,
     asset_in: Asset,
     ask_denom: String,
     routes: Vec<Route>,
     include_spot_price: bool,
-) -> ContractResult<SimulateSwapExactAssetInResponse> {
-    if routes.len() != 1 {
-        return Err(ContractError::SmartSwapUnsupported);
-    }
-    let responce = query_simulate_swap_exact_asset_in_with_metadata(
-        deps,
-        env,
-        asset_in,
-        routes[0].operations.clone(),
-        include_spot_price,
-    )?;
-    if *responce.asset_out.denom() == ask_denom {
-        Ok(responce)
-    } else {
-        Err(ContractError::SmartSwapUnexpectedOut)
+) -> ContractResult<SimulateSmartSwapExactAssetInResponse> {
+    // Get coin in from asset in, error if asset in is not a
+    // native coin because Duality does not support CW20 tokens.
+    let coin_in = match asset_in {
+        Asset::Native(coin) => coin,
+        _ => return Err(ContractError::AssetNotNative),
+    };
+
+    // Verify the routes offer the coin in denom and return the ask denom
+    get_routes_offer_amount(&routes, &coin_in.denom, &ask_denom)?;
+
+    let mut response = SimulateSmartSwapExactAssetInResponse {
+        asset_out: simulate_multi_hop_swap(
+            deps,
+            coin_in,
+            get_routes_for_query(routes.clone())?,
+            ask_denom,
+        )?,
+        spot_price: None,
+    };
+
+    if include_spot_price {
+        let mut best_spot_price = Decimal::zero();
+        for route in routes {
+            best_spot_price =
+                best_spot_price.max(calculate_spot_price_multi(deps, route.operations)?);
+        }
+        response.spot_price = Some(best_spot_price);
     }
+
+    Ok(response)
 }
 
 /////////////
@@ -500,6 +825,63 @@ pub fn get_route_from_swap_operations_for_query(
     Ok(vec![route])
 }
 
+// Converts the routes into duality multi hop routes for the simulation query
+fn get_routes_for_query(routes: Vec<Route>) -> ContractResult<Vec<Vec<String>>> {
+    let mut query_routes = vec![];
+    for route in routes {
+        query_routes.extend(get_route_from_swap_operations_for_query(route.operations)?);
+    }
+
+    Ok(query_routes)
+}
+
+// Verifies every route offers the offer denom and returns the ask denom,
+// returning the total offer amount of the routes
+fn get_routes_offer_amount(
+    routes: &[Route],
+    offer_denom: &str,
+    ask_denom: &str,
+) -> ContractResult<Uint128> {
+    let mut offer_amount = Uint128::zero();
+    for route in routes {
+        if route.offer_asset.denom() != offer_denom {
+            return Err(ContractError::RouteOfferAssetDenomMismatch);
+        }
+
+        if route.ask_denom()? != ask_denom {
+            return Err(ContractError::RouteAskDenomMismatch);
+        }
+
+        offer_amount = offer_amount.checked_add(route.offer_asset.amount())?;
+    }
+
+    Ok(offer_amount)
+}
+
+// Converts the limit order type into the duality limit order type, returning the
+// expiration time of good til time orders, which must be in the future
+fn get_duality_limit_order_type(
+    env: &Env,
+    order_type: LimitOrderType,
+) -> ContractResult<(DualityLimitOrderType, Option<i64>)> {
+    match order_type {
+        LimitOrderType::GoodTilCancelled => Ok((DualityLimitOrderType::GoodTilCancelled, None)),
+        LimitOrderType::FillOrKill => Ok((DualityLimitOrderType::FillOrKill, None)),
+        LimitOrderType::ImmediateOrCancel => Ok((DualityLimitOrderType::ImmediateOrCancel, None)),
+        LimitOrderType::JustInTime => Ok((DualityLimitOrderType::JustInTime, None)),
+        LimitOrderType::GoodTilTime { expiration_time } => {
+            if expiration_time <= env.block.time.seconds() {
+                return Err(ContractError::InvalidExpirationTime);
+            }
+
+            let expiration_time =
+                i64::try_from(expiration_time).map_err(|_| ContractError::InvalidExpirationTime)?;
+
+            Ok((DualityLimitOrderType::GoodTilTime, Some(expiration_time)))
+        }
+    }
+}
+
 fn uint128_to_int128(u: Uint128) -> Result<Int128, ContractError> {
     let value = u.u128();
     if value > i128::MAX as u128 {
@@ -548,7 +930,7 @@ fn perform_duality_limit_order_query(
         token_out: swap_operation.denom_out.clone(),
         tick_index_in_to_out,
         amount_in,
-        order_type: LimitOrderType::FillOrKill,
+        order_type: DualityLimitOrderType::FillOrKill,
         expiration_time: None,
         max_amount_out: Some(max_out.to_string()),
         limit_sell_price: None,
@@ -18,6 +18,9 @@ pub enum ContractError {
     #[error(transparent)]
     Overflow(#[from] cosmwasm_std::OverflowError),
 
+    #[error(transparent)]
+    Decode(#[from] prost::DecodeError),
+
     #[error("Unauthorized")]
     Unauthorized,
 
@@ -50,11 +53,23 @@ pub enum ContractError {
     #[error("swap operation denom-in and denom-out are the same.")]
     SameSwapDenoms,
 
-    #[error("Route must me length 1. Smart Swap is not supported")]
-    SmartSwapUnsupported,
+    #[error("route offer asset denoms must match the coin_in denom")]
+    RouteOfferAssetDenomMismatch,
+
+    #[error("route offer asset amounts must add up to the coin_in amount")]
+    RouteOfferAssetAmountMismatch,
+
+    #[error("route ask denoms must match the last route's ask denom")]
+    RouteAskDenomMismatch,
+
+    #[error("limit order expiration time must be a unix timestamp in seconds in the future")]
+    InvalidExpirationTime,
+
+    #[error("limit order tranche user not found")]
+    LimitOrderTrancheUserNotFound,
 
-    #[error("Simulation Error. Unexpected output denom")]
-    SmartSwapUnexpectedOut,
+    #[error("SubMsgResponse does not contain data")]
+    MissingResponseData,
 
     #[error("Simulation Error. Not Enough Liquidity")]
     NoLiquidityToParse,
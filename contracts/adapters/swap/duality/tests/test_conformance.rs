@@ -0,0 +1,118 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, Coin, ContractResult as SystemContractResult, QuerierResult,
+    SystemResult, Uint128,
+};
+use neutron_sdk::{
+    proto_types::neutron::dex::MsgMultiHopSwap,
+    stargate::dex::types::{
+        AllTickLiquidityResponse, MHRoute, MultiHopSwapResponse, PlaceLimitOrderResponse,
+        PoolReserves, PoolReservesKey, SimulateMultiHopSwapResponse,
+        SimulatePlaceLimitOrderResponse, TickLiquidity, TradePairID,
+    },
+};
+use prost::Message;
+use skip::swap::{DualityExecuteMsg, DualityInstantiateMsg, DualityQueryMsg, SwapOperation};
+use skip_go_swap_adapter_duality::contract::{execute, instantiate, query};
+use skip_go_swap_adapter_testing::suite::{MockVenue, SwapAdapterSuite};
+
+// Mirrors the protobuf encoded simulate multi hop swap request the dex module is queried with
+#[derive(Clone, PartialEq, Message)]
+struct SimulateMultiHopSwapRequest {
+    #[prost(message, optional, tag = "1")]
+    msg: Option<MsgMultiHopSwap>,
+}
+
+// Mocks the duality dex module with pools that swap at a rate of 2 ask asset per offer asset
+fn mock_dex(path: &str, data: &Binary) -> QuerierResult {
+    let res = match path {
+        "/neutron.dex.Query/SimulateMultiHopSwap" => {
+            let msg = SimulateMultiHopSwapRequest::decode(data.as_slice())
+                .unwrap()
+                .msg
+                .unwrap();
+            let hops = msg.routes[0].hops.clone();
+            let amount_in: Uint128 = msg.amount_in.parse().unwrap();
+            let amount_out = amount_in * Uint128::new(2u128.pow(hops.len() as u32 - 1));
+
+            to_json_binary(&SimulateMultiHopSwapResponse {
+                resp: MultiHopSwapResponse {
+                    coin_out: Coin::new(amount_out.u128(), hops.last().unwrap()),
+                    route: MHRoute { hops },
+                    dust: vec![],
+                },
+            })
+        }
+        "/neutron.dex.Query/SimulatePlaceLimitOrder" => {
+            to_json_binary(&SimulatePlaceLimitOrderResponse {
+                resp: PlaceLimitOrderResponse {
+                    trancheKey: "".to_string(),
+                    coin_in: Some(Coin::new(50, "coin_in")),
+                    taker_coin_out: Some(Coin::new(1_000_000, "taker_coin_out")),
+                    taker_coin_in: Some(Coin::new(50, "taker_coin_in")),
+                },
+            })
+        }
+        "/neutron.dex.Query/TickLiquidityAll" => to_json_binary(&AllTickLiquidityResponse {
+            tick_liquidity: vec![TickLiquidity::PoolReserves(PoolReserves {
+                key: PoolReservesKey {
+                    trade_pair_id: TradePairID {
+                        maker_denom: "maker".to_string(),
+                        taker_denom: "taker".to_string(),
+                    },
+                    tick_index_taker_to_maker: 1.into(),
+                    fee: None,
+                },
+                reserves_maker_denom: 1_000_000.into(),
+                price_taker_to_maker: "0.5".to_string(),
+                price_opposite_taker_to_maker: "2".to_string(),
+            })],
+            pagination: None,
+        }),
+        _ => panic!("Unsupported stargate query: {path}"),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+}
+
+// Runs the swap adapter conformance suite against the mocked duality dex module
+#[test]
+fn test_conformance() {
+    SwapAdapterSuite {
+        instantiate: |deps, env, info, entry_point_contract_address| {
+            instantiate(
+                deps,
+                env,
+                info,
+                DualityInstantiateMsg {
+                    entry_point_contract_address,
+                    dex_module_address: "dex_module".to_string(),
+                },
+            )
+        },
+        execute: |deps, env, info, msg| {
+            execute(deps, env, info, DualityExecuteMsg::SwapAdapter(msg))
+        },
+        query: |deps, env, msg| query(deps, env, DualityQueryMsg::SwapAdapter(msg)),
+        venue: MockVenue {
+            mock_querier: |_| {},
+            stargate_querier: Some(mock_dex),
+            swap_operations: vec![
+                SwapOperation {
+                    pool: "pool_1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "ua".to_string(),
+                    interface: None,
+                },
+                SwapOperation {
+                    pool: "pool_2".to_string(),
+                    denom_in: "ua".to_string(),
+                    denom_out: "un".to_string(),
+                    interface: None,
+                },
+            ],
+            cw20_swap_operations: None,
+            supports_exact_asset_out: true,
+        },
+    }
+    .run();
+}
@@ -0,0 +1,235 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Coin,
+    ReplyOn::{Never, Success},
+    SubMsg, Timestamp, Uint128, WasmMsg,
+};
+use neutron_sdk::stargate::dex::{
+    msg::msg_place_limit_order,
+    types::{LimitOrderType as DualityLimitOrderType, PlaceLimitOrderRequest},
+};
+use skip::swap::{
+    DualityExecuteMsg, DualityLimitOrderExecuteMsg, ExecuteMsg, LimitOrder, LimitOrderType,
+};
+use skip_go_swap_adapter_duality::{
+    contract::PLACE_LIMIT_ORDER_REPLY_ID, error::ContractResult,
+    state::ENTRY_POINT_CONTRACT_ADDRESS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Good Til Cancelled Limit Order
+    - Immediate Or Cancel Limit Order With Max Amount Out
+    - Good Til Time Limit Order (The expiration time is passed through in seconds)
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Coin Sent
+    - Denom Out Is The Same As The Coin Sent Denom
+    - Good Til Time Limit Order Expiration Time Not In The Future
+    - Invalid Receiver Address
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    limit_order: LimitOrder,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Returns a limit order selling os for uatom with the given order type and max amount out
+fn limit_order(order_type: LimitOrderType, max_amount_out: Option<Uint128>) -> LimitOrder {
+    LimitOrder {
+        denom_out: "uatom".to_string(),
+        tick_index_in_to_out: 100,
+        order_type,
+        max_amount_out,
+        receiver: "receiver".to_string(),
+    }
+}
+
+// Returns the expected messages of placing a limit order of 100 os for uatom
+fn expected_messages(
+    order_type: DualityLimitOrderType,
+    expiration_time: Option<i64>,
+    max_amount_out: Option<String>,
+) -> Vec<SubMsg> {
+    vec![
+        SubMsg {
+            id: PLACE_LIMIT_ORDER_REPLY_ID,
+            msg: msg_place_limit_order(PlaceLimitOrderRequest {
+                sender: "swap_contract_address".to_string(),
+                receiver: "receiver".to_string(),
+                token_in: "os".to_string(),
+                token_out: "uatom".to_string(),
+                tick_index_in_to_out: 100,
+                amount_in: "100".to_string(),
+                order_type,
+                expiration_time,
+                max_amount_out,
+                limit_sell_price: None,
+                min_avg_sell_price: None,
+            }),
+            gas_limit: None,
+            reply_on: Success,
+        },
+        SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_contract_address".to_string(),
+                msg: to_json_binary(&ExecuteMsg::RefundAssetIn {
+                    refund_address: Addr::unchecked("receiver"),
+                    denom_in: "os".to_string(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+    ]
+}
+
+// Test execute_place_limit_order
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        limit_order: limit_order(LimitOrderType::GoodTilCancelled, None),
+        expected_messages: expected_messages(DualityLimitOrderType::GoodTilCancelled, None, None),
+        expected_error_string: "".to_string(),
+    };
+    "Good Til Cancelled Limit Order")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        limit_order: limit_order(LimitOrderType::ImmediateOrCancel, Some(Uint128::new(90))),
+        expected_messages: expected_messages(
+            DualityLimitOrderType::ImmediateOrCancel,
+            None,
+            Some("90".to_string()),
+        ),
+        expected_error_string: "".to_string(),
+    };
+    "Immediate Or Cancel Limit Order With Max Amount Out")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        limit_order: limit_order(LimitOrderType::GoodTilTime { expiration_time: 200 }, None),
+        expected_messages: expected_messages(DualityLimitOrderType::GoodTilTime, Some(200), None),
+        expected_error_string: "".to_string(),
+    };
+    "Good Til Time Limit Order")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        limit_order: limit_order(LimitOrderType::GoodTilCancelled, None),
+        expected_messages: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        limit_order: limit_order(LimitOrderType::GoodTilCancelled, None),
+        expected_messages: vec![],
+        expected_error_string: "No funds sent".to_string(),
+    };
+    "No Coin Sent - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "uatom")],
+        limit_order: limit_order(LimitOrderType::GoodTilCancelled, None),
+        expected_messages: vec![],
+        expected_error_string: "swap operation denom-in and denom-out are the same.".to_string(),
+    };
+    "Same Denom In And Out - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        limit_order: limit_order(LimitOrderType::GoodTilTime { expiration_time: 100 }, None),
+        expected_messages: vec![],
+        expected_error_string: "limit order expiration time must be a unix timestamp in seconds in the future".to_string(),
+    };
+    "Expiration Time Not In The Future - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        limit_order: LimitOrder {
+            receiver: "r".to_string(),
+            ..limit_order(LimitOrderType::GoodTilCancelled, None)
+        },
+        expected_messages: vec![],
+        expected_error_string: "Generic error: Invalid input: human address too short for this mock implementation (must be >= 3).".to_string(),
+    };
+    "Invalid Receiver Address - Expect Error")]
+fn test_execute_place_limit_order(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env with a block time of 100 seconds
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+    env.block.time = Timestamp::from_seconds(100);
+
+    // Convert info funds vector into a slice of Coin objects
+    let info_funds: &[Coin] = &params.info_funds;
+
+    // Create mock info with entry point contract address
+    let info = mock_info(&params.caller, info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call execute_place_limit_order with the given test parameters
+    let res = skip_go_swap_adapter_duality::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        DualityExecuteMsg::LimitOrder(DualityLimitOrderExecuteMsg::PlaceLimitOrder {
+            limit_order: params.limit_order,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
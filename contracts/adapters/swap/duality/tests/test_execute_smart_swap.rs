@@ -0,0 +1,258 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Coin, CosmosMsg,
+    ReplyOn::Never,
+    SubMsg, Uint128, WasmMsg,
+};
+use neutron_sdk::{
+    proto_types::neutron::dex::{MsgMultiHopSwap, MultiHopRoute},
+    stargate::aux::create_stargate_msg,
+};
+use skip::{
+    asset::Asset,
+    swap::{DualityExecuteMsg, ExecuteMsg, Route, SwapOperation},
+};
+use skip_go_swap_adapter_duality::{error::ContractResult, state::ENTRY_POINT_CONTRACT_ADDRESS};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Multiple Routes (All routes are sent in a single multi hop swap, the best one is picked by the dex)
+    - Multiple Routes With Min Amount Out
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Coin Sent
+    - No Routes
+    - Route Offer Asset Denom Is Not The Same As The Coin Sent Denom
+    - Route Offer Asset Amounts Do Not Add Up To The Coin Sent Amount
+    - Route Ask Denom Is Not The Same As The Last Route Ask Denom
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    routes: Vec<Route>,
+    min_amount_out: Option<Uint128>,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Returns two routes from os to the given denom out, offering the given amounts of os
+fn routes(denom_out: &str, amount_1: u128, amount_2: u128) -> Vec<Route> {
+    vec![
+        Route {
+            offer_asset: Asset::Native(Coin::new(amount_1, "os")),
+            operations: vec![SwapOperation {
+                pool: "1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: denom_out.to_string(),
+                interface: None,
+            }],
+        },
+        Route {
+            offer_asset: Asset::Native(Coin::new(amount_2, "os")),
+            operations: vec![
+                SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "untrn".to_string(),
+                    interface: None,
+                },
+                SwapOperation {
+                    pool: "3".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "uatom".to_string(),
+                    interface: None,
+                },
+            ],
+        },
+    ]
+}
+
+// Returns the expected messages of a smart swap over the routes
+fn expected_messages(exit_limit_price: &str) -> Vec<SubMsg> {
+    vec![
+        SubMsg {
+            id: 0,
+            msg: get_multi_hop_msg(MsgMultiHopSwap {
+                creator: "swap_contract_address".to_string(),
+                receiver: "swap_contract_address".to_string(),
+                routes: vec![
+                    MultiHopRoute {
+                        hops: vec!["os".to_string(), "uatom".to_string()],
+                    },
+                    MultiHopRoute {
+                        hops: vec!["os".to_string(), "untrn".to_string(), "uatom".to_string()],
+                    },
+                ],
+                amount_in: "100".to_string(),
+                exit_limit_price: exit_limit_price.to_string(),
+                pick_best_route: true,
+            }),
+            gas_limit: None,
+            reply_on: Never,
+        },
+        SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_contract_address".to_string(),
+                msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                    return_denom: "uatom".to_string(),
+                    swapper: Addr::unchecked("entry_point"),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+    ]
+}
+
+// Test execute_smart_swap
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("uatom", 40, 60),
+        min_amount_out: None,
+        expected_messages: expected_messages("1"),
+        expected_error_string: "".to_string(),
+    };
+    "Multiple Routes")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("uatom", 40, 60),
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: expected_messages("900000000000000000000000000"),
+        expected_error_string: "".to_string(),
+    };
+    "Multiple Routes With Min Amount Out")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("uatom", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        routes: routes("uatom", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "No funds sent".to_string(),
+    };
+    "No Coin Sent - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: vec![],
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "Routes Empty".to_string(),
+    };
+    "No Routes - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "untrn")],
+        routes: routes("uatom", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "route offer asset denoms must match the coin_in denom".to_string(),
+    };
+    "Route Offer Asset Denom Mismatch - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("uatom", 40, 50),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "route offer asset amounts must add up to the coin_in amount".to_string(),
+    };
+    "Route Offer Asset Amount Mismatch - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("untrn", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "route ask denoms must match the last route's ask denom".to_string(),
+    };
+    "Route Ask Denom Mismatch - Expect Error")]
+fn test_execute_smart_swap(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Convert info funds vector into a slice of Coin objects
+    let info_funds: &[Coin] = &params.info_funds;
+
+    // Create mock info with entry point contract address
+    let info = mock_info(&params.caller, info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call execute_smart_swap with the given test parameters
+    let res = skip_go_swap_adapter_duality::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        DualityExecuteMsg::SwapAdapter(ExecuteMsg::SmartSwap {
+            routes: params.routes,
+            min_amount_out: params.min_amount_out,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
+
+fn get_multi_hop_msg(msg: MsgMultiHopSwap) -> CosmosMsg {
+    create_stargate_msg("/neutron.dex.MsgMultiHopSwap", msg)
+}
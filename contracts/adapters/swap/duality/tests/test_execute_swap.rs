@@ -2,12 +2,12 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     SubMsg,
 };
-use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, ReplyOn::Never, WasmMsg};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, ReplyOn::Never, Uint128, WasmMsg};
 use neutron_sdk::{
     proto_types::neutron::dex::{MsgMultiHopSwap, MultiHopRoute},
     stargate::aux::create_stargate_msg,
 };
-use skip::swap::{ExecuteMsg, SwapOperation};
+use skip::swap::{DualityExecuteMsg, ExecuteMsg, SwapOperation};
 
 use skip_go_swap_adapter_duality::{error::ContractResult, state::ENTRY_POINT_CONTRACT_ADDRESS};
 
@@ -18,6 +18,7 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Min Amount Out
     - Multiple Swap Operations
     - No Swap Operations (This is prevented in the entry point contract; and will fail on Duality module if attempted)
 
@@ -34,6 +35,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
 }
@@ -51,6 +53,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -83,6 +86,51 @@ struct Params {
         expected_error_string: "".to_string(),
     };
 "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "uatom".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: get_multi_hop_msg(MsgMultiHopSwap {
+                    creator: "swap_contract_address".to_string(),
+                    receiver: "swap_contract_address".to_string(),
+                    routes: vec![MultiHopRoute {hops: vec![String::from("os"),String::from("uatom")]}],
+                    amount_in: String::from("100"),
+                    exit_limit_price: String::from("900000000000000000000000000"),
+                    pick_best_route: true,
+                })?,
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "uatom".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+"One Swap Operation With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -101,6 +149,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -145,6 +194,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -182,6 +232,7 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "os")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -226,6 +277,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "No funds sent".to_string(),
     };
@@ -245,6 +297,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Sent more than one denomination".to_string(),
     };
@@ -257,6 +310,7 @@ struct Params {
             Coin::new(100, "os"),
         ],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Unauthorized".to_string(),
     };
@@ -283,9 +337,10 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         deps.as_mut(),
         env,
         info,
-        ExecuteMsg::Swap {
+        DualityExecuteMsg::SwapAdapter(ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
-        },
+            min_amount_out: params.min_amount_out,
+        }),
     );
 
     // Assert the behavior is correct
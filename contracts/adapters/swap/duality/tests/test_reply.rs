@@ -0,0 +1,99 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    Attribute, Binary, Reply, SubMsgResponse, SubMsgResult,
+};
+use neutron_sdk::proto_types::neutron::dex::MsgPlaceLimitOrderResponse;
+use prost::Message;
+use skip_go_swap_adapter_duality::{
+    contract::{reply, PLACE_LIMIT_ORDER_REPLY_ID},
+    error::{ContractError, ContractResult},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Place Limit Order Response (The tranche key is emitted as an attribute)
+
+Expect Error
+    - Missing Response Data
+
+ */
+
+// Define test parameters
+struct Params {
+    data: Option<Binary>,
+    expected_attributes: Vec<Attribute>,
+    expected_error: Option<ContractError>,
+}
+
+// Test reply
+#[test_case(
+    Params {
+        data: Some(Binary::from(
+            MsgPlaceLimitOrderResponse {
+                tranche_key: "tranche_key".to_string(),
+                ..Default::default()
+            }
+            .encode_to_vec(),
+        )),
+        expected_attributes: vec![
+            Attribute::new("action", "place_limit_order"),
+            Attribute::new("tranche_key", "tranche_key"),
+        ],
+        expected_error: None,
+    };
+    "Place Limit Order Response")]
+#[test_case(
+    Params {
+        data: None,
+        expected_attributes: vec![],
+        expected_error: Some(ContractError::MissingResponseData),
+    };
+    "Missing Response Data - Expect Error")]
+fn test_reply(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Call reply with the place limit order sub message response
+    let res = reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: PLACE_LIMIT_ORDER_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: params.data,
+            }),
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the attributes are correct
+            assert_eq!(res.attributes, params.expected_attributes);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
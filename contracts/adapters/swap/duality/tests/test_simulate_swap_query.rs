@@ -11,7 +11,8 @@ use neutron_sdk::stargate::dex::types::{
 };
 use skip::asset::Asset;
 use skip::swap::{
-    QueryMsg, SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
+    DualityQueryMsg, QueryMsg, Route, SimulateSwapExactAssetInResponse,
+    SimulateSwapExactAssetOutResponse, SwapOperation,
 };
 use skip_go_swap_adapter_duality::{error::ContractResult, state::ENTRY_POINT_CONTRACT_ADDRESS};
 use std::marker::PhantomData;
@@ -91,11 +92,11 @@ fn test_simulate_swap_exact_asset_in(params: ExactAssetInParams) -> ContractResu
     let res = skip_go_swap_adapter_duality::contract::query(
         deps.as_ref(),
         env,
-        QueryMsg::SimulateSwapExactAssetInWithMetadata {
+        DualityQueryMsg::SwapAdapter(QueryMsg::SimulateSwapExactAssetInWithMetadata {
             asset_in: params.coin.into(),
             swap_operations: params.swap_operations,
             include_spot_price: false,
-        },
+        }),
     )?;
 
     // Deserialize response
@@ -197,11 +198,11 @@ fn test_simulate_swap_exact_asset_out(params: ExactAssetOutParams) -> ContractRe
     let res = skip_go_swap_adapter_duality::contract::query(
         deps.as_ref(),
         env,
-        QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+        DualityQueryMsg::SwapAdapter(QueryMsg::SimulateSwapExactAssetOutWithMetadata {
             asset_out: asset_out.clone(),
             swap_operations: params.swap_operations,
             include_spot_price: false,
-        },
+        }),
     )?;
 
     // Deserialize response
@@ -212,3 +213,163 @@ fn test_simulate_swap_exact_asset_out(params: ExactAssetOutParams) -> ContractRe
 
     Ok(())
 }
+
+struct SmartSwapExactAssetInParams {
+    routes: Vec<Route>,
+    expected_result: Option<Asset>,
+    expected_error_string: String,
+}
+
+#[test_case(
+    SmartSwapExactAssetInParams {
+        routes: vec![
+            Route {
+                offer_asset: Coin::new(40, "os").into(),
+                operations: vec![SwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "uatom".to_string(),
+                    interface: None,
+                }],
+            },
+            Route {
+                offer_asset: Coin::new(60, "os").into(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "2".to_string(),
+                        denom_in: "os".to_string(),
+                        denom_out: "untrn".to_string(),
+                        interface: None,
+                    },
+                    SwapOperation {
+                        pool: "3".to_string(),
+                        denom_in: "untrn".to_string(),
+                        denom_out: "uatom".to_string(),
+                        interface: None,
+                    },
+                ],
+            },
+        ],
+        expected_result: Some(Coin::new(100, "uatom").into()),
+        expected_error_string: "".to_string(),
+    }; "Succesful SmartSwapExactAssetIn with multiple routes")]
+#[test_case(
+    SmartSwapExactAssetInParams {
+        routes: vec![
+            Route {
+                offer_asset: Coin::new(40, "os").into(),
+                operations: vec![SwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "uatom".to_string(),
+                    interface: None,
+                }],
+            },
+            Route {
+                offer_asset: Coin::new(60, "untrn").into(),
+                operations: vec![SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "uatom".to_string(),
+                    interface: None,
+                }],
+            },
+        ],
+        expected_result: None,
+        expected_error_string: "route offer asset denoms must match the coin_in denom".to_string(),
+    }; "Route Offer Asset Denom Mismatch - Expect Error")]
+#[test_case(
+    SmartSwapExactAssetInParams {
+        routes: vec![
+            Route {
+                offer_asset: Coin::new(40, "os").into(),
+                operations: vec![SwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "untrn".to_string(),
+                    interface: None,
+                }],
+            },
+            Route {
+                offer_asset: Coin::new(60, "os").into(),
+                operations: vec![SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "uatom".to_string(),
+                    interface: None,
+                }],
+            },
+        ],
+        expected_result: None,
+        expected_error_string: "route ask denoms must match the last route's ask denom".to_string(),
+    }; "Route Ask Denom Mismatch - Expect Error")]
+fn test_simulate_smart_swap_exact_asset_in(
+    params: SmartSwapExactAssetInParams,
+) -> ContractResult<()> {
+    // Create mock dependencies with custom querier
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::default(),
+        custom_query_type: PhantomData,
+    };
+
+    // Create mock env
+    let env = mock_env();
+
+    // Store required contract addresses
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // The multi hop swap simulation swaps the total offer amount through the best route
+    let response = SimulateMultiHopSwapResponse {
+        resp: MultiHopSwapResponse {
+            coin_out: Coin::new(100, "coin"),
+            route: MHRoute {
+                hops: vec!["os".to_string(), "uatom".to_string()],
+            },
+            dust: vec![],
+        },
+    };
+
+    deps.querier
+        .mock_stargate_response(("MultiHopSwap".into(), to_json_binary(&response).unwrap()));
+
+    // Call simulate_smart_swap_exact_asset_in
+    let res = skip_go_swap_adapter_duality::contract::query(
+        deps.as_ref(),
+        env,
+        DualityQueryMsg::SwapAdapter(QueryMsg::SimulateSmartSwapExactAssetIn {
+            asset_in: Coin::new(100, "os").into(),
+            routes: params.routes,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the asset out is correct
+            let asset_out: Asset = from_json(res)?;
+            assert_eq!(Some(asset_out), params.expected_result);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use cosmwasm_std::{
     ReplyOn::Never,
     SubMsg,
 };
-use skip::{error::SkipError, swap::ExecuteMsg};
+use skip::{
+    error::SkipError,
+    swap::{DualityExecuteMsg, ExecuteMsg},
+};
 use skip_go_swap_adapter_duality::error::{ContractError, ContractResult};
 use test_case::test_case;
 
@@ -131,10 +134,10 @@ fn test_execute_transfer_funds_back(params: Params) -> ContractResult<()> {
         deps.as_mut(),
         env,
         info,
-        ExecuteMsg::TransferFundsBack {
+        DualityExecuteMsg::SwapAdapter(ExecuteMsg::TransferFundsBack {
             return_denom: params.return_denom,
             swapper: Addr::unchecked("swapper"),
-        },
+        }),
     );
 
     // Assert the behavior is correct
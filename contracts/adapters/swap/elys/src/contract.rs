@@ -14,11 +14,13 @@ use elys_std::types::elys::amm::{
 };
 use skip::{
     asset::Asset,
+    error::SkipError,
     proto_coin::ProtoCoin,
     swap::{
-        convert_swap_operations, execute_transfer_funds_back, get_ask_denom_for_routes, ExecuteMsg,
-        InstantiateMsg, MigrateMsg, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
-        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
+        convert_swap_operations, execute_transfer_funds_back, get_ask_denom_for_routes,
+        CapabilitiesResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolIdentifier, QueryMsg,
+        Route, SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation,
     },
 };
 use std::str::FromStr;
@@ -29,7 +31,7 @@ use std::str::FromStr;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -77,7 +79,10 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, operations, min_amount_out),
         ExecuteMsg::TransferFundsBack {
             swapper,
             return_denom,
@@ -88,9 +93,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            unimplemented!()
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -100,6 +103,7 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -118,7 +122,7 @@ fn execute_swap(
     };
 
     // Create the Elys Amm swap exact amount in message
-    let swap_msg = create_elys_swap_msg(&env, coin_in, operations)?;
+    let swap_msg = create_elys_swap_msg(&env, coin_in, operations, min_amount_out)?;
 
     // Create the transfer funds back message
     let transfer_funds_back_msg = WasmMsg::Execute {
@@ -145,6 +149,7 @@ fn create_elys_swap_msg(
     env: &Env,
     coin_in: Coin,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<CosmosMsg> {
     // Convert the swap operations to elys swap amount in routes
     // Return an error if there was an error converting the swap
@@ -153,14 +158,18 @@ fn create_elys_swap_msg(
         convert_swap_operations(swap_operations).map_err(ContractError::ParseIntPoolID)?;
 
     // Create the elys amm module swap exact amount in message
-    // The token out min amount is set to 1 because we are not concerned
-    // with the minimum amount in this contract, that gets verified in the
-    // entry point contract.
+    // The token out min amount is set to the min amount out if given, so the
+    // amm module reverts a swap that returns less. It is never set below 1 since
+    // the amm module requires it to be positive, in which case the minimum amount
+    // is only verified in the entry point contract.
     let swap_msg = MsgUpFrontSwapExactAmountIn {
         sender: env.contract.address.to_string(),
         routes: elys_swap_amount_in_routes,
         token_in: Some(ProtoCoin(coin_in).into()),
-        token_out_min_amount: "1".to_string(),
+        token_out_min_amount: min_amount_out
+            .unwrap_or_default()
+            .max(Uint128::one())
+            .to_string(),
     }
     .into();
 
@@ -232,10 +241,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the elys swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: false,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::PoolId],
+    }
+}
+
 // Queries the amm module to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -0,0 +1,96 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, ContractResult as SystemContractResult, QuerierResult, SystemResult,
+    Uint128,
+};
+use elys_std::types::{
+    cosmos::base::v1beta1::Coin as ElysStdCoin,
+    elys::amm::{
+        QuerySwapEstimationExactAmountOutRequest, QuerySwapEstimationExactAmountOutResponse,
+        QuerySwapEstimationRequest, QuerySwapEstimationResponse,
+    },
+};
+use skip::swap::{InstantiateMsg, SwapOperation};
+use skip_go_swap_adapter_elys::contract::{execute, instantiate, query};
+use skip_go_swap_adapter_testing::suite::{MockVenue, SwapAdapterSuite};
+
+// Mocks the elys amm module with pools that swap at a rate of 2 ask asset per offer asset
+fn mock_amm(path: &str, data: &Binary) -> QuerierResult {
+    let res = match path {
+        "/elys.amm.Query/SwapEstimation" => {
+            let req = QuerySwapEstimationRequest::try_from(data.clone()).unwrap();
+            let token_in = req.token_in.unwrap();
+            let amount_in: Uint128 = token_in.amount.parse().unwrap();
+
+            to_json_binary(&QuerySwapEstimationResponse {
+                spot_price: rate(req.routes.len()).to_string(),
+                token_out: Some(ElysStdCoin {
+                    denom: req.routes.last().unwrap().token_out_denom.clone(),
+                    amount: (amount_in * rate(req.routes.len())).to_string(),
+                }),
+                ..Default::default()
+            })
+        }
+        "/elys.amm.Query/SwapEstimationExactAmountOut" => {
+            let req = QuerySwapEstimationExactAmountOutRequest::try_from(data.clone()).unwrap();
+            let token_out = req.token_out.unwrap();
+            let amount_out: Uint128 = token_out.amount.parse().unwrap();
+
+            to_json_binary(&QuerySwapEstimationExactAmountOutResponse {
+                spot_price: rate(req.routes.len()).to_string(),
+                token_in: Some(ElysStdCoin {
+                    denom: req.routes.first().unwrap().token_in_denom.clone(),
+                    amount: (amount_out / rate(req.routes.len())).to_string(),
+                }),
+                ..Default::default()
+            })
+        }
+        _ => panic!("Unsupported stargate query: {path}"),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+}
+
+// Returns the rate of swapping over the given number of pools
+fn rate(pools: usize) -> Uint128 {
+    Uint128::new(2u128.pow(pools as u32))
+}
+
+// Runs the swap adapter conformance suite against the mocked elys amm module
+#[test]
+fn test_conformance() {
+    SwapAdapterSuite {
+        instantiate: |deps, env, info, entry_point_contract_address| {
+            instantiate(
+                deps,
+                env,
+                info,
+                InstantiateMsg {
+                    entry_point_contract_address,
+                },
+            )
+        },
+        execute,
+        query,
+        venue: MockVenue {
+            mock_querier: |_| {},
+            stargate_querier: Some(mock_amm),
+            swap_operations: vec![
+                SwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "ua".to_string(),
+                    interface: None,
+                },
+                SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "ua".to_string(),
+                    denom_out: "un".to_string(),
+                    interface: None,
+                },
+            ],
+            cw20_swap_operations: None,
+            supports_exact_asset_out: true,
+        },
+    }
+    .run();
+}
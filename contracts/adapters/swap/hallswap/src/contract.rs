@@ -17,9 +17,9 @@ use skip::{
     asset::Asset,
     error::SkipError,
     swap::{
-        get_ask_denom_for_routes, Cw20HookMsg, ExecuteMsg, HallswapInstantiateMsg, MigrateMsg,
-        QueryMsg, Route, SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
-        SwapOperation,
+        get_ask_denom_for_routes, CapabilitiesResponse, Cw20HookMsg, ExecuteMsg,
+        HallswapInstantiateMsg, MigrateMsg, PoolIdentifier, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse, SwapOperation,
     },
 };
 
@@ -110,7 +110,10 @@ pub fn receive_cw20(
     info.sender = deps.api.addr_validate(&cw20_msg.sender)?;
 
     match from_json(&cw20_msg.msg)? {
-        Cw20HookMsg::Swap { operations } => execute_swap(deps, env, info, operations, sent_asset),
+        Cw20HookMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, operations, min_amount_out, sent_asset),
     }
 }
 
@@ -127,13 +130,21 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
-        ExecuteMsg::Swap { operations } => {
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => {
             let coin = one_coin(&info)?;
-            execute_swap(deps, env, info, operations, Asset::Native(coin))
-        }
-        _ => {
-            unimplemented!()
+            execute_swap(
+                deps,
+                env,
+                info,
+                operations,
+                min_amount_out,
+                Asset::Native(coin),
+            )
         }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -142,6 +153,7 @@ fn execute_swap(
     _env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     asset: Asset,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
@@ -164,7 +176,7 @@ fn execute_swap(
 
     let hallswap_execute_msg = HallswapExecuteMsg::ExecuteRoutesV2 {
         routes: hallswap_routes,
-        minimum_receive: Uint128::zero(),
+        minimum_receive: min_amount_out.unwrap_or_default(),
         to: Some(entry_point_contract_address),
     };
     let msg = match asset.clone() {
@@ -227,13 +239,26 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 deps, ask_denom, routes,
             )?)
         }
-        _ => {
-            unimplemented!()
-        }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
+        _ => return Err(SkipError::UnsupportedQueryMsg.into()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the hallswap swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: false,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: true,
+        spot_price: false,
+        pool_identifiers: vec![PoolIdentifier::ContractAddress],
+    }
+}
+
 // Queries the hallswap contract to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
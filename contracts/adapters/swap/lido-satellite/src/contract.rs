@@ -6,18 +6,18 @@ use crate::{
     },
 };
 use cosmwasm_std::{
-    entry_point, to_json_binary, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
-    WasmMsg,
+    entry_point, to_json_binary, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
-use cw_utils::one_coin;
 use skip::{
     asset::Asset,
+    error::SkipError,
     swap::{
-        execute_transfer_funds_back, ExecuteMsg, LidoSatelliteInstantiateMsg as InstantiateMsg,
-        MigrateMsg, QueryMsg, SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse,
-        SwapOperation,
+        CapabilitiesResponse, ExecuteMsg, LidoSatelliteInstantiateMsg as InstantiateMsg,
+        MigrateMsg, PoolIdentifier, QueryMsg, SwapOperation,
     },
+    swap_adapter::{self, SwapAdapter},
 };
 
 ///////////////
@@ -26,7 +26,7 @@ use skip::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -94,185 +94,144 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
-    match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
-        ExecuteMsg::TransferFundsBack {
-            swapper,
-            return_denom,
-        } => Ok(execute_transfer_funds_back(
-            deps,
-            env,
-            info,
-            swapper,
-            return_denom,
-        )?),
-        _ => {
-            unimplemented!()
-        }
-    }
+    swap_adapter::execute::<LidoSatellite>(deps, env, info, msg)
 }
 
-fn execute_swap(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    _operations: Vec<SwapOperation>,
-) -> ContractResult<Response> {
-    // Get entry point contract address from storage
-    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+/////////////
+/// QUERY ///
+/////////////
 
-    // Enforce the caller is the entry point contract
-    if info.sender != entry_point_contract_address {
-        return Err(ContractError::Unauthorized);
-    }
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    swap_adapter::query::<LidoSatellite>(deps, env, msg)
+}
 
-    // Get coin in from the message info, error if there is not exactly one coin sent
-    let coin_in = one_coin(&info)?;
+//////////////////
+// SWAP ADAPTER //
+//////////////////
 
-    let bridged_denom = BRIDGED_DENOM.load(deps.storage)?;
-    let canonical_denom = CANONICAL_DENOM.load(deps.storage)?;
+// Lido Satellite mints the canonical denom for the bridged denom and burns it back 1:1,
+// so every swap operation converts the asset to the opposite denom at a spot price of 1.
+pub struct LidoSatellite;
 
-    // Decide which message to Lido Satellite should be emitted
-    let (lido_satellite_msg, return_denom) = if coin_in.denom == bridged_denom {
-        (
-            lido_satellite::msg::ExecuteMsg::Mint { receiver: None },
-            canonical_denom,
-        )
-    } else if coin_in.denom == canonical_denom {
-        (
-            lido_satellite::msg::ExecuteMsg::Burn { receiver: None },
-            bridged_denom,
-        )
-    } else {
-        return Err(ContractError::UnsupportedDenom);
-    };
-
-    let lido_satellite_contract_address = LIDO_SATELLITE_CONTRACT_ADDRESS.load(deps.storage)?;
-
-    let swap_msg = WasmMsg::Execute {
-        contract_addr: lido_satellite_contract_address.to_string(),
-        msg: to_json_binary(&lido_satellite_msg)?,
-        funds: vec![coin_in],
-    };
-
-    // Create the transfer funds back message
-    let transfer_funds_back_msg = WasmMsg::Execute {
-        contract_addr: env.contract.address.to_string(),
-        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
-            swapper: info.sender,
-            return_denom,
-        })?,
-        funds: vec![],
-    };
+impl SwapAdapter for LidoSatellite {
+    type Error = ContractError;
 
-    Ok(Response::new()
-        .add_message(swap_msg)
-        .add_message(transfer_funds_back_msg)
-        .add_attribute("action", "dispatch_swap_and_transfer_back"))
-}
+    const POOL_IDENTIFIERS: &'static [PoolIdentifier] = &[PoolIdentifier::Unused];
 
-/////////////
-/// QUERY ///
-/////////////
+    fn simulate_hop_exact_asset_in(
+        deps: Deps,
+        asset_in: Asset,
+        _operation: &SwapOperation,
+    ) -> ContractResult<Asset> {
+        convert_asset(deps, asset_in)
+    }
 
-#[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
-    let bridged_denom = BRIDGED_DENOM.load(deps.storage)?;
-    let canonical_denom = CANONICAL_DENOM.load(deps.storage)?;
+    fn simulate_hop_exact_asset_out(
+        deps: Deps,
+        asset_out: Asset,
+        _operation: &SwapOperation,
+    ) -> ContractResult<Asset> {
+        convert_asset(deps, asset_out)
+    }
 
-    match msg {
-        QueryMsg::SimulateSwapExactAssetIn { asset_in, .. } => {
-            let asset_out_denom =
-                get_opposite_denom(asset_in.denom(), &bridged_denom, &canonical_denom);
+    // The swap operations are not used since the denom out is the opposite denom of the asset in
+    fn simulate_swap_exact_asset_in(
+        deps: Deps,
+        asset_in: Asset,
+        _operations: &[SwapOperation],
+    ) -> ContractResult<Asset> {
+        convert_asset(deps, asset_in)
+    }
 
-            to_json_binary(&Asset::Native(Coin::new(
-                asset_in.amount().u128(),
-                asset_out_denom,
-            )))
-        }
-        QueryMsg::SimulateSwapExactAssetOut { asset_out, .. } => {
-            let asset_in_denom =
-                get_opposite_denom(asset_out.denom(), &bridged_denom, &canonical_denom);
-
-            to_json_binary(&Asset::Native(Coin::new(
-                asset_out.amount().u128(),
-                asset_in_denom,
-            )))
-        }
-        QueryMsg::SimulateSwapExactAssetInWithMetadata {
-            asset_in,
-            include_spot_price,
-            ..
-        } => {
-            let asset_out_denom =
-                get_opposite_denom(asset_in.denom(), &bridged_denom, &canonical_denom);
-
-            let spot_price = if include_spot_price {
-                Some(Decimal::one())
-            } else {
-                None
-            };
-
-            to_json_binary(&SimulateSwapExactAssetInResponse {
-                asset_out: Asset::Native(Coin::new(asset_in.amount().u128(), asset_out_denom)),
-                spot_price,
-            })
-        }
-        QueryMsg::SimulateSwapExactAssetOutWithMetadata {
-            asset_out,
-            include_spot_price,
-            ..
-        } => {
-            let asset_in_denom =
-                get_opposite_denom(asset_out.denom(), &bridged_denom, &canonical_denom);
-
-            let spot_price = if include_spot_price {
-                Some(Decimal::one())
-            } else {
-                None
-            };
-
-            to_json_binary(&SimulateSwapExactAssetOutResponse {
-                asset_in: Asset::Native(Coin::new(asset_out.amount().u128(), asset_in_denom)),
-                spot_price,
-            })
-        }
-        QueryMsg::SimulateSmartSwapExactAssetIn { asset_in, .. } => {
-            let asset_out_denom =
-                get_opposite_denom(asset_in.denom(), &bridged_denom, &canonical_denom);
-
-            to_json_binary(&Asset::Native(Coin::new(
-                asset_in.amount().u128(),
-                asset_out_denom,
-            )))
-        }
-        QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
-            asset_in,
-            include_spot_price,
-            ..
-        } => {
-            let asset_out_denom =
-                get_opposite_denom(asset_in.denom(), &bridged_denom, &canonical_denom);
-
-            let spot_price = if include_spot_price {
-                Some(Decimal::one())
-            } else {
-                None
-            };
-
-            to_json_binary(&SimulateSwapExactAssetInResponse {
-                asset_out: Asset::Native(Coin::new(asset_in.amount().u128(), asset_out_denom)),
-                spot_price,
-            })
+    // The swap operations are not used since the denom in is the opposite denom of the asset out
+    fn simulate_swap_exact_asset_out(
+        deps: Deps,
+        asset_out: Asset,
+        _operations: &[SwapOperation],
+    ) -> ContractResult<Asset> {
+        convert_asset(deps, asset_out)
+    }
+
+    fn hop_spot_price(_deps: Deps, _operation: &SwapOperation) -> ContractResult<Decimal> {
+        Ok(Decimal::one())
+    }
+
+    // The swap returns the opposite denom of the coin sent, whatever the swap operations are
+    fn return_denom(
+        deps: Deps,
+        asset_in: &Asset,
+        _operations: &[SwapOperation],
+    ) -> ContractResult<String> {
+        get_opposite_denom(deps, asset_in.denom())
+    }
+
+    // The min amount out is not used since lido satellite mints and burns 1:1
+    fn swap_msgs(
+        deps: Deps,
+        _env: &Env,
+        asset_in: Asset,
+        _operations: Vec<SwapOperation>,
+        _min_amount_out: Option<Uint128>,
+    ) -> ContractResult<Vec<CosmosMsg>> {
+        // Lido Satellite only mints and burns native coins
+        let Asset::Native(coin_in) = asset_in else {
+            return Err(ContractError::UnsupportedDenom);
+        };
+
+        let bridged_denom = BRIDGED_DENOM.load(deps.storage)?;
+        let canonical_denom = CANONICAL_DENOM.load(deps.storage)?;
+
+        // Decide which message to Lido Satellite should be emitted
+        let lido_satellite_msg = if coin_in.denom == bridged_denom {
+            lido_satellite::msg::ExecuteMsg::Mint { receiver: None }
+        } else if coin_in.denom == canonical_denom {
+            lido_satellite::msg::ExecuteMsg::Burn { receiver: None }
+        } else {
+            return Err(ContractError::UnsupportedDenom);
+        };
+
+        let lido_satellite_contract_address = LIDO_SATELLITE_CONTRACT_ADDRESS.load(deps.storage)?;
+
+        let swap_msg = WasmMsg::Execute {
+            contract_addr: lido_satellite_contract_address.to_string(),
+            msg: to_json_binary(&lido_satellite_msg)?,
+            funds: vec![coin_in],
+        };
+
+        Ok(vec![swap_msg.into()])
+    }
+
+    // Returns the features supported by the lido satellite swap adapter contract
+    fn capabilities() -> CapabilitiesResponse {
+        CapabilitiesResponse {
+            swap_exact_asset_in: true,
+            swap_exact_asset_out: true,
+            swap_exact_asset_out_execution: false,
+            smart_swap: true,
+            smart_swap_execution: false,
+            cw20_input: false,
+            spot_price: true,
+            pool_identifiers: Self::POOL_IDENTIFIERS.to_vec(),
         }
     }
-    .map_err(From::from)
 }
 
-fn get_opposite_denom(denom: &str, bridged_denom: &str, canonical_denom: &str) -> String {
+// Returns the asset Lido Satellite converts the given asset to, which has the same amount
+fn convert_asset(deps: Deps, asset: Asset) -> ContractResult<Asset> {
+    let denom = get_opposite_denom(deps, asset.denom())?;
+
+    Ok(Asset::Native(Coin::new(asset.amount().u128(), denom)))
+}
+
+// Returns the denom Lido Satellite converts the given denom to
+fn get_opposite_denom(deps: Deps, denom: &str) -> ContractResult<String> {
+    let bridged_denom = BRIDGED_DENOM.load(deps.storage)?;
+    let canonical_denom = CANONICAL_DENOM.load(deps.storage)?;
+
     match denom {
-        denom if denom == bridged_denom => canonical_denom.to_string(),
-        denom if denom == canonical_denom => bridged_denom.to_string(),
-        _ => unimplemented!(),
+        denom if denom == bridged_denom => Ok(canonical_denom),
+        denom if denom == canonical_denom => Ok(bridged_denom),
+        _ => Err(ContractError::UnsupportedDenom),
     }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError};
 use skip::error::SkipError;
 use thiserror::Error;
 
@@ -13,10 +13,10 @@ pub enum ContractError {
     Skip(#[from] SkipError),
 
     #[error(transparent)]
-    Payment(#[from] cw_utils::PaymentError),
+    Overflow(#[from] OverflowError),
 
-    #[error("Unauthorized")]
-    Unauthorized,
+    #[error(transparent)]
+    Payment(#[from] cw_utils::PaymentError),
 
     #[error("this denom is not supported by Lido Satellite")]
     UnsupportedDenom,
@@ -1,7 +1,9 @@
 use cosmwasm_std::Addr;
 use cw_storage_plus::Item;
 
-pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+// The entry point contract address is stored by the swap adapter framework
+pub use skip::swap_adapter::ENTRY_POINT_CONTRACT_ADDRESS;
+
 pub const LIDO_SATELLITE_CONTRACT_ADDRESS: Item<Addr> =
     Item::new("lido_satellite_contract_address");
 
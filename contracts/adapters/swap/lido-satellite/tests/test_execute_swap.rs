@@ -5,7 +5,10 @@ use cosmwasm_std::{
     SubMsg, WasmMsg,
 };
 use lido_satellite::msg::ExecuteMsg as LidoSatelliteExecuteMsg;
-use skip::swap::{ExecuteMsg, SwapOperation};
+use skip::{
+    error::SkipError,
+    swap::{ExecuteMsg, SwapOperation},
+};
 use skip_go_swap_adapter_lido_satellite::{
     error::{ContractError, ContractResult},
     state::{
@@ -149,7 +152,7 @@ struct Params {
         ],
         swap_operations: vec![],
         expected_messages: vec![],
-        expected_error: Some(ContractError::Unauthorized),
+        expected_error: Some(ContractError::Skip(SkipError::Unauthorized)),
     };
     "Unauthorized Caller - Expect Error")]
 fn test_execute_swap(params: Params) -> ContractResult<()> {
@@ -186,6 +189,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: None,
         },
     );
 
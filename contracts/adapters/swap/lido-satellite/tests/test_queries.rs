@@ -0,0 +1,205 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    to_json_binary, Addr, Binary, Coin, Decimal,
+};
+use skip::{
+    asset::Asset,
+    swap::{
+        CapabilitiesResponse, PoolIdentifier, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation,
+    },
+};
+use skip_go_swap_adapter_lido_satellite::{
+    error::{ContractError, ContractResult},
+    state::{BRIDGED_DENOM, CANONICAL_DENOM, LIDO_SATELLITE_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - SimulateSwapExactAssetIn Query minting the canonical denom
+    - SimulateSwapExactAssetIn Query with a swap operation
+    - SimulateSwapExactAssetOut Query burning the canonical denom
+    - SimulateSwapExactAssetInWithMetadata Query including the spot price
+    - SimulateSwapExactAssetOutWithMetadata Query including the spot price
+    - SimulateSmartSwapExactAssetInWithMetadata Query summing the routes
+    - Capabilities Query
+
+Expect Error
+    - SimulateSwapExactAssetIn Query with an unsupported denom
+
+ */
+
+// Define test parameters
+struct Params {
+    query: QueryMsg,
+    response: Binary,
+    expected_error: Option<ContractError>,
+}
+
+// Test queries
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(Coin::new(100, "ibc/wstETH")),
+            swap_operations: vec![],
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(100, "factory/wstETH"))).unwrap(),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetIn Query")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(Coin::new(100, "ibc/wstETH")),
+            swap_operations: vec![SwapOperation {
+                pool: "".to_string(),
+                denom_in: "ibc/wstETH".to_string(),
+                denom_out: "factory/wstETH".to_string(),
+                interface: None,
+            }],
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(100, "factory/wstETH"))).unwrap(),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetIn Query With Swap Operation")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOut {
+            asset_out: Asset::Native(Coin::new(100, "ibc/wstETH")),
+            swap_operations: vec![],
+        },
+        response: to_json_binary(&Asset::Native(Coin::new(100, "factory/wstETH"))).unwrap(),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetOut Query")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(100, "factory/wstETH")),
+            swap_operations: vec![],
+            include_spot_price: true,
+        },
+        response: to_json_binary(&SimulateSwapExactAssetInResponse {
+            asset_out: Asset::Native(Coin::new(100, "ibc/wstETH")),
+            spot_price: Some(Decimal::one()),
+        }).unwrap(),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetInWithMetadata Query")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out: Asset::Native(Coin::new(100, "factory/wstETH")),
+            swap_operations: vec![],
+            include_spot_price: true,
+        },
+        response: to_json_binary(&SimulateSwapExactAssetOutResponse {
+            asset_in: Asset::Native(Coin::new(100, "ibc/wstETH")),
+            spot_price: Some(Decimal::one()),
+        }).unwrap(),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetOutWithMetadata Query")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(100, "ibc/wstETH")),
+            routes: vec![
+                Route {
+                    offer_asset: Asset::Native(Coin::new(60, "ibc/wstETH")),
+                    operations: vec![],
+                },
+                Route {
+                    offer_asset: Asset::Native(Coin::new(40, "ibc/wstETH")),
+                    operations: vec![],
+                },
+            ],
+            include_spot_price: true,
+        },
+        response: to_json_binary(&SimulateSmartSwapExactAssetInResponse {
+            asset_out: Asset::Native(Coin::new(100, "factory/wstETH")),
+            spot_price: Some(Decimal::one()),
+        }).unwrap(),
+        expected_error: None,
+    };
+    "SimulateSmartSwapExactAssetInWithMetadata Query")]
+#[test_case(
+    Params {
+        query: QueryMsg::Capabilities {},
+        response: to_json_binary(&CapabilitiesResponse {
+            swap_exact_asset_in: true,
+            swap_exact_asset_out: true,
+            swap_exact_asset_out_execution: false,
+            smart_swap: true,
+            smart_swap_execution: false,
+            cw20_input: false,
+            spot_price: true,
+            pool_identifiers: vec![PoolIdentifier::Unused],
+        }).unwrap(),
+        expected_error: None,
+    };
+    "Capabilities Query")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(Coin::new(100, "uosmo")),
+            swap_operations: vec![],
+        },
+        response: Binary::default(),
+        expected_error: Some(ContractError::UnsupportedDenom),
+    };
+    "SimulateSwapExactAssetIn Query Unsupported Denom - Expect Error")]
+fn test_queries(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Store the lido satellite contract address
+    LIDO_SATELLITE_CONTRACT_ADDRESS.save(
+        deps.as_mut().storage,
+        &Addr::unchecked("lido_satellite_contract"),
+    )?;
+
+    // Store Lido Satellite denoms
+    BRIDGED_DENOM.save(deps.as_mut().storage, &String::from("ibc/wstETH"))?;
+    CANONICAL_DENOM.save(deps.as_mut().storage, &String::from("factory/wstETH"))?;
+
+    // Call query with the given test parameters
+    let res =
+        skip_go_swap_adapter_lido_satellite::contract::query(deps.as_ref(), env, params.query);
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the response is correct
+            assert_eq!(res, params.response);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -12,10 +12,12 @@ use cw_utils::one_coin;
 use skip::swap::MantraDexInstantiateMsg;
 use skip::{
     asset::Asset,
+    error::SkipError,
     swap::{
-        get_ask_denom_for_routes, ExecuteMsg, MigrateMsg, QueryMsg, Route,
-        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
-        SimulateSwapExactAssetOutResponse, SwapOperation,
+        calculate_weighted_spot_price, get_ask_denom_for_routes, CapabilitiesResponse, ExecuteMsg,
+        MigrateMsg, PoolIdentifier, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
+        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SlippageInterface,
+        SwapOperation,
     },
 };
 
@@ -31,7 +33,7 @@ use crate::pool_manager::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -90,10 +92,14 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, info, operations),
-        _ => {
-            unimplemented!()
-        }
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, info, operations, min_amount_out),
+        // The MANTRA dex pool manager only swaps native coins and has no cw20
+        // receive hook, so cw20 assets cannot be routed through it
+        ExecuteMsg::Receive(_) => Err(SkipError::Cw20Unsupported.into()),
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -101,6 +107,7 @@ fn execute_swap(
     deps: DepsMut,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -139,9 +146,9 @@ fn execute_swap(
 
     let msg = MantraPoolManagerExecuteMsg::ExecuteSwapOperations {
         operations: mantra_swap_operations,
-        minimum_receive: None,
+        minimum_receive: min_amount_out,
         receiver: Some(entry_point_contract_address.to_string()),
-        max_spread: Some(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+        max_spread: Some(get_max_spread(&operations)?),
     };
 
     // Create swap message on MANTRA dex pool manager
@@ -217,10 +224,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the mantra dex swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: false,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::VenuePoolIdentifier],
+    }
+}
+
 /// Queries the MANTRA dex pool manager to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -402,8 +424,33 @@ fn query_simulate_smart_swap_exact_asset_in_with_metadata(
     Ok(response)
 }
 
-fn assert_max_spread(return_amount: Uint128, spread_amount: Uint128) -> ContractResult<()> {
-    let max_spread = MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?;
+// Returns the max spread of swapping through the swap operations. The pool manager applies
+// a single max spread to every swap operation, so the strictest max spread of the swap
+// operations' slippage interfaces is used, defaulting to the max allowed slippage. Errors
+// if a slippage interface has a belief price, since the pool manager does not take one.
+fn get_max_spread(swap_operations: &[SwapOperation]) -> ContractResult<Decimal> {
+    swap_operations.iter().try_fold(
+        MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?,
+        |max_spread, operation| -> ContractResult<Decimal> {
+            let SlippageInterface {
+                max_spread: operation_max_spread,
+                belief_price,
+            } = operation.slippage_interface()?;
+
+            if belief_price.is_some() {
+                return Err(ContractError::BeliefPriceUnsupported);
+            }
+
+            Ok(operation_max_spread.map_or(max_spread, |spread| max_spread.min(spread)))
+        },
+    )
+}
+
+fn assert_max_spread(
+    return_amount: Uint128,
+    spread_amount: Uint128,
+    max_spread: Decimal,
+) -> ContractResult<()> {
     if Decimal::from_ratio(spread_amount, return_amount + spread_amount) > max_spread {
         return Err(ContractError::MaxSpreadAssertion {});
     }
@@ -418,13 +465,14 @@ fn simulate_swap_exact_asset_in(
     include_responses: bool,
 ) -> ContractResult<(Asset, Vec<SimulationResponse>)> {
     let mantra_pool_manager = MANTRA_DEX_POOL_MANAGER_ADDRESS.load(deps.storage)?;
+    let max_spread = get_max_spread(&swap_operations)?;
 
     let (asset_out, responses) = swap_operations.iter().try_fold(
         (asset_in, Vec::new()),
         |(asset_out, mut responses), operation| -> Result<_, ContractError> {
             let offer_asset = match asset_out {
                 Asset::Native(coin) => coin,
-                Asset::Cw20(_) => unimplemented!("CW20 not supported"),
+                Asset::Cw20(_) => return Err(SkipError::Cw20Unsupported.into()),
             };
 
             // Query mantra's pool manager to get the simulation response
@@ -438,7 +486,7 @@ fn simulate_swap_exact_asset_in(
             )?;
 
             // Assert the operation does not exceed the max spread limit
-            assert_max_spread(res.return_amount, res.spread_amount)?;
+            assert_max_spread(res.return_amount, res.spread_amount, max_spread)?;
 
             if include_responses {
                 responses.push(res.clone());
@@ -462,13 +510,14 @@ fn simulate_swap_exact_asset_out(
     include_responses: bool,
 ) -> ContractResult<(Asset, Vec<ReverseSimulationResponse>)> {
     let mantra_pool_manager = MANTRA_DEX_POOL_MANAGER_ADDRESS.load(deps.storage)?;
+    let max_spread = get_max_spread(&swap_operations)?;
 
     let (asset_in, responses) = swap_operations.iter().rev().try_fold(
         (asset_out, Vec::new()),
         |(asset_in_needed, mut responses), operation| -> Result<_, ContractError> {
             let ask_asset = match asset_in_needed {
                 Asset::Native(coin) => coin,
-                Asset::Cw20(_) => unimplemented!("CW20 not supported"),
+                Asset::Cw20(_) => return Err(SkipError::Cw20Unsupported.into()),
             };
 
             // Query the mantra's pool manager to get the reverse simulation response
@@ -482,7 +531,7 @@ fn simulate_swap_exact_asset_out(
             )?;
 
             // Assert the operation does not exceed the max spread limit
-            assert_max_spread(res.offer_amount, res.spread_amount)?;
+            assert_max_spread(res.offer_amount, res.spread_amount, max_spread)?;
 
             if include_responses {
                 responses.push(res.clone());
@@ -535,23 +584,23 @@ fn calculate_weighted_spot_price_from_simulation_responses(
     routes: Vec<Route>,
     simulation_responses: Vec<Vec<SimulationResponse>>,
 ) -> ContractResult<Decimal> {
-    let spot_price = routes.into_iter().zip(simulation_responses).try_fold(
-        Decimal::zero(),
-        |curr_spot_price, (route, res)| -> ContractResult<Decimal> {
-            let route_spot_price = calculate_spot_price_from_simulation_responses(
-                deps,
-                asset_in.clone(),
-                route.operations,
-                res,
-            )?;
-
-            let weight = Decimal::from_ratio(route.offer_asset.amount(), asset_in.amount());
-
-            Ok(curr_spot_price + (route_spot_price * weight))
-        },
-    )?;
+    let route_spot_prices = routes
+        .into_iter()
+        .zip(simulation_responses)
+        .map(|(route, res)| {
+            Ok((
+                route.offer_asset.amount(),
+                calculate_spot_price_from_simulation_responses(
+                    deps,
+                    asset_in.clone(),
+                    route.operations,
+                    res,
+                )?,
+            ))
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
 
-    Ok(spot_price)
+    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
 }
 
 /// Calculates the spot price using simulation responses
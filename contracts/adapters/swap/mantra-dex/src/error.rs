@@ -35,4 +35,7 @@ pub enum ContractError {
 
     #[error("Contract has no balance of offer asset")]
     NoOfferAssetAmount,
+
+    #[error("Belief price not supported by the MANTRA dex pool manager swap operations")]
+    BeliefPriceUnsupported,
 }
@@ -0,0 +1,87 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Uint128,
+};
+use cw20::Cw20ReceiveMsg;
+use skip::{
+    error::SkipError,
+    swap::{Cw20HookMsg, ExecuteMsg, SwapOperation},
+};
+use skip_go_swap_adapter_mantra_dex::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, MANTRA_DEX_POOL_MANAGER_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Error
+    - Cw20 Swap (The MANTRA dex pool manager only swaps native coins)
+    - Cw20 Swap From Entry Point With Min Amount Out
+
+ */
+
+// Define test parameters
+struct Params {
+    sender: String,
+    min_amount_out: Option<Uint128>,
+    expected_error: ContractError,
+}
+
+// Test execute_receive
+#[test_case(
+    Params {
+        sender: "random".to_string(),
+        min_amount_out: None,
+        expected_error: SkipError::Cw20Unsupported.into(),
+    };
+    "Cw20 Swap - Expect Error")]
+#[test_case(
+    Params {
+        sender: "entry_point".to_string(),
+        min_amount_out: Some(Uint128::new(90)),
+        expected_error: SkipError::Cw20Unsupported.into(),
+    };
+    "Cw20 Swap From Entry Point With Min Amount Out - Expect Error")]
+fn test_execute_receive(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Create mock info with the cw20 contract as the caller
+    let info = mock_info("neutron123", &[]);
+
+    // Store the entry point and pool manager contract addresses
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+    MANTRA_DEX_POOL_MANAGER_ADDRESS
+        .save(deps.as_mut().storage, &Addr::unchecked("pool_manager"))?;
+
+    // Call execute with a cw20 receive message
+    let res = skip_go_swap_adapter_mantra_dex::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: params.sender,
+            amount: Uint128::new(100),
+            msg: to_json_binary(&Cw20HookMsg::Swap {
+                operations: vec![SwapOperation {
+                    pool: "o.neutron123.os".to_string(),
+                    denom_in: "neutron123".to_string(),
+                    denom_out: "os".to_string(),
+                    interface: None,
+                }],
+                min_amount_out: params.min_amount_out,
+            })?,
+        }),
+    );
+
+    // Assert the error is correct
+    assert_eq!(res.unwrap_err(), params.expected_error);
+
+    Ok(())
+}
@@ -2,9 +2,9 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     to_json_binary, Addr, Coin, Decimal,
     ReplyOn::Never,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
-use skip::swap::{ExecuteMsg, SwapOperation};
+use skip::swap::{ExecuteMsg, SlippageInterface, SwapOperation};
 use skip_go_swap_adapter_mantra_dex::error::{ContractError, ContractResult};
 use skip_go_swap_adapter_mantra_dex::state::{
     ENTRY_POINT_CONTRACT_ADDRESS, MANTRA_DEX_POOL_MANAGER_ADDRESS,
@@ -20,13 +20,16 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Min Amount Out
     - Multiple Swap Operations
+    - Multiple Swap Operations With Max Spread Interfaces (The strictest max spread is used for all swap operations)
     - No Swap Operations (This is prevented in the entry point contract; and will not add any swap messages to the response)
 
 Expect Error
     - Unauthorized Caller (Only the stored entry point contract can call this function)
     - No Coin Sent
     - More Than One Coin Sent
+    - Belief Price Interface (The pool manager swap operations do not take a belief price)
 
  */
 
@@ -35,6 +38,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error: Option<ContractError>,
 }
@@ -52,6 +56,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -76,6 +81,43 @@ struct Params {
         expected_error: None,
     };
     "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "mantra_pool_manager".to_string(),
+                    msg: to_json_binary(&MantraPoolManagerExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![MantraSwapOperation::MantraSwap {
+                            pool_identifier: "pool_1".to_string(),
+                            token_in_denom: "os".to_string(),
+                            token_out_denom: "ua".to_string(),
+                        }],
+                        minimum_receive: Some(Uint128::new(90)),
+                        receiver: Some("entry_point".to_string()),
+                        max_spread: Some(Decimal::percent(50)),
+                    })?,
+                    funds: vec![Coin::new(100, "os")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            }
+        ],
+        expected_error: None,
+    };
+    "One Swap Operation With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -94,6 +136,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -124,11 +167,67 @@ struct Params {
         expected_error: None,
     };
     "Multiple Swap Operations")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(5)),
+                    belief_price: None,
+                })?),
+            },
+            SwapOperation {
+                pool: "pool_2".to_string(),
+                denom_in: "ua".to_string(),
+                denom_out: "un".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: Some(Decimal::percent(1)),
+                    belief_price: None,
+                })?),
+            }
+        ],
+        min_amount_out: None,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "mantra_pool_manager".to_string(),
+                    msg: to_json_binary(&MantraPoolManagerExecuteMsg::ExecuteSwapOperations {
+                        operations: vec![MantraSwapOperation::MantraSwap {
+                            pool_identifier: "pool_1".to_string(),
+                            token_in_denom: "os".to_string(),
+                            token_out_denom: "ua".to_string(),
+                        },
+                        MantraSwapOperation::MantraSwap {
+                            pool_identifier: "pool_2".to_string(),
+                            token_in_denom: "ua".to_string(),
+                            token_out_denom: "un".to_string(),
+                        }
+                        ],
+                        minimum_receive: None,
+                        receiver: Some("entry_point".to_string()),
+                        max_spread: Some(Decimal::percent(1)),
+                    })?,
+                    funds: vec![Coin::new(100, "os")],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            }
+        ],
+        expected_error: None,
+    };
+    "Multiple Swap Operations With Max Spread Interfaces")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "os")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::SwapOperationsEmpty),
     };
@@ -138,6 +237,7 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::NoFunds{})),
     };
@@ -150,6 +250,7 @@ struct Params {
             Coin::new(100, "os"),
         ],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Payment(cw_utils::PaymentError::MultipleDenoms{})),
     };
@@ -161,10 +262,31 @@ struct Params {
             Coin::new(100, "un"),
         ],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error: Some(ContractError::Unauthorized),
     };
     "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "pool_1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "ua".to_string(),
+                interface: Some(to_json_binary(&SlippageInterface {
+                    max_spread: None,
+                    belief_price: Some(Decimal::one()),
+                })?),
+            }
+        ],
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::BeliefPriceUnsupported),
+    };
+    "Belief Price Interface - Expect Error")]
 fn test_execute_swap(params: Params) -> ContractResult<()> {
     // Create mock dependencies
     let mut deps = mock_dependencies();
@@ -193,6 +315,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: params.min_amount_out,
         },
     );
 
@@ -9,16 +9,21 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use cw_utils::one_coin;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::{
-    EstimateSwapExactAmountInResponse, EstimateSwapExactAmountOutResponse, MsgSwapExactAmountIn,
-    PoolmanagerQuerier, SpotPriceResponse, SwapAmountInRoute, SwapAmountOutRoute,
+    EstimateSwapExactAmountInResponse, EstimateSwapExactAmountOutResponse,
+    MsgSplitRouteSwapExactAmountIn, MsgSwapExactAmountIn, MsgSwapExactAmountOut,
+    PoolmanagerQuerier, SpotPriceResponse, SwapAmountInRoute, SwapAmountInSplitRoute,
+    SwapAmountOutRoute,
 };
 use skip::{
     asset::Asset,
+    error::SkipError,
     proto_coin::ProtoCoin,
     swap::{
-        convert_swap_operations, execute_transfer_funds_back, get_ask_denom_for_routes, ExecuteMsg,
-        InstantiateMsg, MigrateMsg, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
-        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
+        calculate_weighted_spot_price, convert_swap_operations, execute_refund_asset_in,
+        execute_transfer_funds_back, get_ask_denom_for_routes, CapabilitiesResponse, ExecuteMsg,
+        InstantiateMsg, MigrateMsg, PoolIdentifier, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation,
     },
 };
 use std::str::FromStr;
@@ -29,7 +34,7 @@ use std::str::FromStr;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -77,7 +82,29 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, operations, min_amount_out),
+        ExecuteMsg::SmartSwap {
+            routes,
+            min_amount_out,
+        } => execute_smart_swap(deps, env, info, routes, min_amount_out),
+        ExecuteMsg::SwapExactAssetOut {
+            operations,
+            amount_out,
+            refund_address,
+        } => execute_swap_exact_asset_out(deps, env, info, operations, amount_out, refund_address),
+        ExecuteMsg::RefundAssetIn {
+            refund_address,
+            denom_in,
+        } => Ok(execute_refund_asset_in(
+            deps,
+            env,
+            info,
+            refund_address,
+            denom_in,
+        )?),
         ExecuteMsg::TransferFundsBack {
             swapper,
             return_denom,
@@ -88,9 +115,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            unimplemented!()
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -100,6 +125,7 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -118,7 +144,7 @@ fn execute_swap(
     };
 
     // Create the osmosis poolmanager swap exact amount in message
-    let swap_msg = create_osmosis_swap_msg(&env, coin_in, operations)?;
+    let swap_msg = create_osmosis_swap_msg(&env, coin_in, operations, min_amount_out)?;
 
     // Create the transfer funds back message
     let transfer_funds_back_msg = WasmMsg::Execute {
@@ -136,6 +162,126 @@ fn execute_swap(
         .add_attribute("action", "dispatch_swap_and_transfer_back"))
 }
 
+// Executes a swap over all the given routes at once and then transfers the funds back to the caller
+fn execute_smart_swap(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    routes: Vec<Route>,
+    min_amount_out: Option<Uint128>,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Get the return denom from the last route, error if there are no routes
+    let return_denom = get_ask_denom_for_routes(&routes)?;
+
+    // Verify the route offer assets are of the coin in denom and add up to exactly the coin in
+    let mut total_offer_amount = Uint128::zero();
+    for route in &routes {
+        if route.offer_asset.denom() != coin_in.denom {
+            return Err(ContractError::RouteOfferAssetDenomMismatch);
+        }
+
+        total_offer_amount = total_offer_amount.checked_add(route.offer_asset.amount())?;
+    }
+
+    if total_offer_amount != coin_in.amount {
+        return Err(ContractError::RouteOfferAssetAmountMismatch);
+    }
+
+    // Create the osmosis poolmanager split route swap exact amount in message
+    let swap_msg = create_osmosis_split_route_swap_msg(&env, coin_in, routes, min_amount_out)?;
+
+    // Create the transfer funds back message
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+            return_denom,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_smart_swap_and_transfer_back"))
+}
+
+// Executes a swap for exactly the amount out with the given swap operations, using the coin sent
+// as the max amount in, then refunds the unused coin in to the refund address and transfers the
+// swap's funds back to the caller
+fn execute_swap_exact_asset_out(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operations: Vec<SwapOperation>,
+    amount_out: Uint128,
+    refund_address: String,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get coin in from the message info, error if there is not exactly one coin sent
+    let coin_in = one_coin(&info)?;
+
+    // Validate the refund address
+    let refund_address = deps.api.addr_validate(&refund_address)?;
+
+    let return_denom = match operations.last() {
+        Some(last_op) => last_op.denom_out.clone(),
+        None => return Err(ContractError::SwapOperationsEmpty),
+    };
+
+    // Create the osmosis poolmanager swap exact amount out message
+    let swap_msg = create_osmosis_swap_exact_amount_out_msg(
+        &env,
+        coin_in.clone(),
+        Coin::new(amount_out.u128(), return_denom.clone()),
+        operations,
+    )?;
+
+    // Create the refund asset in message, refunding the coin in the swap did not use
+    let refund_asset_in_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::RefundAssetIn {
+            refund_address,
+            denom_in: coin_in.denom,
+        })?,
+        funds: vec![],
+    };
+
+    // Create the transfer funds back message
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+            return_denom,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(refund_asset_in_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_swap_exact_asset_out_and_transfer_back"))
+}
+
 //////////////////////
 // HELPER FUNCTIONS //
 //////////////////////
@@ -145,6 +291,7 @@ fn create_osmosis_swap_msg(
     env: &Env,
     coin_in: Coin,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<CosmosMsg> {
     // Convert the swap operations to osmosis swap amount in routes
     // Return an error if there was an error converting the swap
@@ -153,14 +300,84 @@ fn create_osmosis_swap_msg(
         convert_swap_operations(swap_operations).map_err(ContractError::ParseIntPoolID)?;
 
     // Create the osmosis poolmanager swap exact amount in message
-    // The token out min amount is set to 1 because we are not concerned
-    // with the minimum amount in this contract, that gets verified in the
-    // entry point contract.
+    // The token out min amount is set to the min amount out if given, so the
+    // poolmanager reverts a swap that returns less. It is never set below 1 since
+    // the poolmanager requires it to be positive, in which case the minimum amount
+    // is only verified in the entry point contract.
     let swap_msg: CosmosMsg = MsgSwapExactAmountIn {
         sender: env.contract.address.to_string(),
         routes: osmosis_swap_amount_in_routes,
         token_in: Some(ProtoCoin(coin_in).into()),
-        token_out_min_amount: "1".to_string(),
+        token_out_min_amount: min_amount_out
+            .unwrap_or_default()
+            .max(Uint128::one())
+            .to_string(),
+    }
+    .into();
+
+    Ok(swap_msg)
+}
+
+// Creates the osmosis poolmanager split route swap exact amount in message
+fn create_osmosis_split_route_swap_msg(
+    env: &Env,
+    coin_in: Coin,
+    routes: Vec<Route>,
+    min_amount_out: Option<Uint128>,
+) -> ContractResult<CosmosMsg> {
+    // Convert each route to an osmosis swap amount in split route
+    // Return an error if there was an error converting the swap
+    // operations to osmosis swap amount in routes.
+    let osmosis_split_routes = routes
+        .into_iter()
+        .map(|route| -> ContractResult<SwapAmountInSplitRoute> {
+            Ok(SwapAmountInSplitRoute {
+                pools: convert_swap_operations(route.operations)
+                    .map_err(ContractError::ParseIntPoolID)?,
+                token_in_amount: route.offer_asset.amount().to_string(),
+            })
+        })
+        .collect::<ContractResult<Vec<SwapAmountInSplitRoute>>>()?;
+
+    // Create the osmosis poolmanager split route swap exact amount in message
+    // The token out min amount is set to the min amount out if given, so the
+    // poolmanager reverts a swap whose routes return less in total. It is never
+    // set below 1 since the poolmanager requires it to be positive.
+    let swap_msg: CosmosMsg = MsgSplitRouteSwapExactAmountIn {
+        sender: env.contract.address.to_string(),
+        routes: osmosis_split_routes,
+        token_in_denom: coin_in.denom,
+        token_out_min_amount: min_amount_out
+            .unwrap_or_default()
+            .max(Uint128::one())
+            .to_string(),
+    }
+    .into();
+
+    Ok(swap_msg)
+}
+
+// Creates the osmosis poolmanager swap exact amount out message
+fn create_osmosis_swap_exact_amount_out_msg(
+    env: &Env,
+    coin_in: Coin,
+    coin_out: Coin,
+    swap_operations: Vec<SwapOperation>,
+) -> ContractResult<CosmosMsg> {
+    // Convert the swap operations to osmosis swap amount out routes
+    // Return an error if there was an error converting the swap
+    // operations to osmosis swap amount out routes.
+    let osmosis_swap_amount_out_routes: Vec<SwapAmountOutRoute> =
+        convert_swap_operations(swap_operations).map_err(ContractError::ParseIntPoolID)?;
+
+    // Create the osmosis poolmanager swap exact amount out message
+    // The token in max amount is set to the coin sent, so the poolmanager
+    // reverts a swap that needs more than it to return the coin out.
+    let swap_msg: CosmosMsg = MsgSwapExactAmountOut {
+        sender: env.contract.address.to_string(),
+        routes: osmosis_swap_amount_out_routes,
+        token_in_max_amount: coin_in.amount.to_string(),
+        token_out: Some(ProtoCoin(coin_out).into()),
     }
     .into();
 
@@ -232,10 +449,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the osmosis poolmanager swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: true,
+        smart_swap: true,
+        smart_swap_execution: true,
+        cw20_input: false,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::PoolId],
+    }
+}
+
 // Queries the osmosis poolmanager module to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -355,7 +587,7 @@ fn query_simulate_smart_swap_exact_asset_in_with_metadata(
     };
 
     if include_spot_price {
-        response.spot_price = Some(calculate_weighted_spot_price(
+        response.spot_price = Some(calculate_smart_swap_spot_price(
             &PoolmanagerQuerier::new(&deps.querier),
             asset_in,
             routes,
@@ -429,23 +661,22 @@ fn query_simulate_swap_exact_asset_out_with_metadata(
     Ok(response)
 }
 
-fn calculate_weighted_spot_price(
+fn calculate_smart_swap_spot_price(
     querier: &PoolmanagerQuerier<Empty>,
     asset_in: Asset,
     routes: Vec<Route>,
 ) -> ContractResult<Decimal> {
-    let spot_price = routes.into_iter().try_fold(
-        Decimal::zero(),
-        |curr_spot_price, route| -> ContractResult<Decimal> {
-            let route_spot_price = calculate_spot_price(querier, route.operations)?;
-
-            let weight = Decimal::from_ratio(route.offer_asset.amount(), asset_in.amount());
-
-            Ok(curr_spot_price + (route_spot_price * weight))
-        },
-    )?;
-
-    Ok(spot_price)
+    let route_spot_prices = routes
+        .into_iter()
+        .map(|route| {
+            Ok((
+                route.offer_asset.amount(),
+                calculate_spot_price(querier, route.operations)?,
+            ))
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
+
+    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
 }
 
 // Calculates the spot price for a given vector of swap operations
@@ -35,4 +35,10 @@ pub enum ContractError {
 
     #[error("Asset Must Be Native, Osmosis Does Not Support CW20 Tokens")]
     AssetNotNative,
+
+    #[error("route offer asset denoms must match the coin_in denom")]
+    RouteOfferAssetDenomMismatch,
+
+    #[error("route offer asset amounts must add up to the coin_in amount")]
+    RouteOfferAssetAmountMismatch,
 }
@@ -0,0 +1,86 @@
+use cosmwasm_std::{
+    to_json_binary, Binary, ContractResult as SystemContractResult, QuerierResult, SystemResult,
+    Uint128,
+};
+use osmosis_std::types::osmosis::poolmanager::v1beta1::{
+    EstimateSwapExactAmountInRequest, EstimateSwapExactAmountInResponse,
+    EstimateSwapExactAmountOutRequest, EstimateSwapExactAmountOutResponse, SpotPriceResponse,
+};
+use skip::swap::{InstantiateMsg, SwapOperation};
+use skip_go_swap_adapter_osmosis_poolmanager::contract::{execute, instantiate, query};
+use skip_go_swap_adapter_testing::suite::{MockVenue, SwapAdapterSuite};
+
+// Mocks the osmosis poolmanager module with pools that swap at a rate of 2 ask asset per offer asset
+fn mock_poolmanager(path: &str, data: &Binary) -> QuerierResult {
+    let res = match path {
+        "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountIn" => {
+            let req = EstimateSwapExactAmountInRequest::try_from(data.clone()).unwrap();
+            to_json_binary(&EstimateSwapExactAmountInResponse {
+                token_out_amount: (coin_amount(&req.token_in) * rate(req.routes.len())).to_string(),
+            })
+        }
+        "/osmosis.poolmanager.v1beta1.Query/EstimateSwapExactAmountOut" => {
+            let req = EstimateSwapExactAmountOutRequest::try_from(data.clone()).unwrap();
+            to_json_binary(&EstimateSwapExactAmountOutResponse {
+                token_in_amount: (coin_amount(&req.token_out) / rate(req.routes.len())).to_string(),
+            })
+        }
+        "/osmosis.poolmanager.v1beta1.Query/SpotPrice" => to_json_binary(&SpotPriceResponse {
+            spot_price: "2".to_string(),
+        }),
+        _ => panic!("Unsupported stargate query: {path}"),
+    };
+
+    SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+}
+
+// Returns the amount of a coin string, e.g. 100 for "100os"
+fn coin_amount(coin: &str) -> Uint128 {
+    let amount: String = coin.chars().take_while(char::is_ascii_digit).collect();
+    amount.parse().unwrap()
+}
+
+// Returns the rate of swapping over the given number of pools
+fn rate(pools: usize) -> Uint128 {
+    Uint128::new(2u128.pow(pools as u32))
+}
+
+// Runs the swap adapter conformance suite against the mocked osmosis poolmanager module
+#[test]
+fn test_conformance() {
+    SwapAdapterSuite {
+        instantiate: |deps, env, info, entry_point_contract_address| {
+            instantiate(
+                deps,
+                env,
+                info,
+                InstantiateMsg {
+                    entry_point_contract_address,
+                },
+            )
+        },
+        execute,
+        query,
+        venue: MockVenue {
+            mock_querier: |_| {},
+            stargate_querier: Some(mock_poolmanager),
+            swap_operations: vec![
+                SwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "ua".to_string(),
+                    interface: None,
+                },
+                SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "ua".to_string(),
+                    denom_out: "un".to_string(),
+                    interface: None,
+                },
+            ],
+            cw20_swap_operations: None,
+            supports_exact_asset_out: true,
+        },
+    }
+    .run();
+}
@@ -0,0 +1,268 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Coin,
+    ReplyOn::Never,
+    SubMsg, Uint128, WasmMsg,
+};
+use osmosis_std::types::osmosis::poolmanager::v1beta1::{
+    MsgSplitRouteSwapExactAmountIn, SwapAmountInRoute, SwapAmountInSplitRoute,
+};
+use skip::{
+    asset::Asset,
+    swap::{ExecuteMsg, Route, SwapOperation},
+};
+use skip_go_swap_adapter_osmosis_poolmanager::{
+    error::ContractResult, state::ENTRY_POINT_CONTRACT_ADDRESS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Multiple Routes
+    - Multiple Routes With Min Amount Out
+
+Expect Error
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Coin Sent
+    - No Routes
+    - Route Offer Asset Denom Is Not The Same As The Coin Sent Denom
+    - Route Offer Asset Amounts Do Not Add Up To The Coin Sent Amount
+    - Invalid Pool ID Conversion For Swap Operations
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    routes: Vec<Route>,
+    min_amount_out: Option<Uint128>,
+    expected_messages: Vec<SubMsg>,
+    expected_error_string: String,
+}
+
+// Returns two routes from os to uatom, offering the given amounts of os
+fn routes(pool_1: &str, amount_1: u128, amount_2: u128) -> Vec<Route> {
+    vec![
+        Route {
+            offer_asset: Asset::Native(Coin::new(amount_1, "os")),
+            operations: vec![SwapOperation {
+                pool: pool_1.to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "uatom".to_string(),
+                interface: None,
+            }],
+        },
+        Route {
+            offer_asset: Asset::Native(Coin::new(amount_2, "os")),
+            operations: vec![
+                SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "untrn".to_string(),
+                    interface: None,
+                },
+                SwapOperation {
+                    pool: "3".to_string(),
+                    denom_in: "untrn".to_string(),
+                    denom_out: "uatom".to_string(),
+                    interface: None,
+                },
+            ],
+        },
+    ]
+}
+
+// Returns the expected messages of a smart swap over the routes
+fn expected_messages(token_out_min_amount: &str) -> Vec<SubMsg> {
+    vec![
+        SubMsg {
+            id: 0,
+            msg: MsgSplitRouteSwapExactAmountIn {
+                sender: "swap_contract_address".to_string(),
+                routes: vec![
+                    SwapAmountInSplitRoute {
+                        pools: vec![SwapAmountInRoute {
+                            pool_id: 1,
+                            token_out_denom: "uatom".to_string(),
+                        }],
+                        token_in_amount: "40".to_string(),
+                    },
+                    SwapAmountInSplitRoute {
+                        pools: vec![
+                            SwapAmountInRoute {
+                                pool_id: 2,
+                                token_out_denom: "untrn".to_string(),
+                            },
+                            SwapAmountInRoute {
+                                pool_id: 3,
+                                token_out_denom: "uatom".to_string(),
+                            },
+                        ],
+                        token_in_amount: "60".to_string(),
+                    },
+                ],
+                token_in_denom: "os".to_string(),
+                token_out_min_amount: token_out_min_amount.to_string(),
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+        SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_contract_address".to_string(),
+                msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                    return_denom: "uatom".to_string(),
+                    swapper: Addr::unchecked("entry_point"),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+    ]
+}
+
+// Test execute_smart_swap
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("1", 40, 60),
+        min_amount_out: None,
+        expected_messages: expected_messages("1"),
+        expected_error_string: "".to_string(),
+    };
+    "Multiple Routes")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("1", 40, 60),
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: expected_messages("90"),
+        expected_error_string: "".to_string(),
+    };
+    "Multiple Routes With Min Amount Out")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("1", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "Unauthorized".to_string(),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        routes: routes("1", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "No funds sent".to_string(),
+    };
+    "No Coin Sent - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: vec![],
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "Routes Empty".to_string(),
+    };
+    "No Routes - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "untrn")],
+        routes: routes("1", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "route offer asset denoms must match the coin_in denom".to_string(),
+    };
+    "Route Offer Asset Denom Mismatch - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("1", 40, 50),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "route offer asset amounts must add up to the coin_in amount".to_string(),
+    };
+    "Route Offer Asset Amount Mismatch - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        routes: routes("pool_1", 40, 60),
+        min_amount_out: None,
+        expected_messages: vec![],
+        expected_error_string: "Parse Int error raised: invalid pool String to pool id u64 conversion".to_string(),
+    };
+    "Invalid Pool ID Conversion For Swap Operations - Expect Error")]
+fn test_execute_smart_swap(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Convert info funds vector into a slice of Coin objects
+    let info_funds: &[Coin] = &params.info_funds;
+
+    // Create mock info with entry point contract address
+    let info = mock_info(&params.caller, info_funds);
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
+
+    // Call execute_smart_swap with the given test parameters
+    let res = skip_go_swap_adapter_osmosis_poolmanager::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::SmartSwap {
+            routes: params.routes,
+            min_amount_out: params.min_amount_out,
+        },
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error_string.is_empty(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error_string
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                !params.expected_error_string.is_empty(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err.to_string(), params.expected_error_string);
+        }
+    }
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use cosmwasm_std::{
     testing::{mock_dependencies, mock_env, mock_info},
     to_json_binary, Addr, Coin,
     ReplyOn::Never,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 use osmosis_std::types::cosmos::base::v1beta1::Coin as OsmosisStdCoin;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::{MsgSwapExactAmountIn, SwapAmountInRoute};
@@ -17,6 +17,7 @@ Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Min Amount Out
     - Multiple Swap Operations
     - No Swap Operations (This is prevented in the entry point contract; and will fail on Osmosis module if attempted)
 
@@ -33,6 +34,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
 }
@@ -50,6 +52,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -91,6 +94,60 @@ struct Params {
         expected_error_string: "".to_string(),
     };
 "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "os")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "1".to_string(),
+                denom_in: "os".to_string(),
+                denom_out: "uatom".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgSwapExactAmountIn {
+                    sender: "swap_contract_address".to_string(),
+                    routes: vec![
+                        SwapAmountInRoute {
+                            pool_id: 1,
+                            token_out_denom: "uatom".to_string(),
+                        }
+                    ],
+                    token_in: Some(
+                        OsmosisStdCoin {
+                            denom: "os".to_string(),
+                            amount: "100".to_string(),
+                        }
+                    ),
+                    token_out_min_amount: "90".to_string(),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "uatom".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+    };
+"One Swap Operation With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -109,6 +166,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -159,6 +217,7 @@ struct Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "os")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -207,6 +266,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "No funds sent".to_string(),
     };
@@ -226,6 +286,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Sent more than one denomination".to_string(),
     };
@@ -242,6 +303,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Parse Int error raised: invalid pool String to pool id u64 conversion".to_string(),
     };
@@ -254,6 +316,7 @@ struct Params {
             Coin::new(100, "os"),
         ],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Unauthorized".to_string(),
     };
@@ -282,6 +345,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: params.min_amount_out,
         },
     );
 
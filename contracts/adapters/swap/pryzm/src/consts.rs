@@ -6,3 +6,12 @@ pub const ICSTAKING_POOL_PREFIX: &str = "icstaking:";
 
 // The prefix of the icstaking module's cAsset denominations
 pub const C_ASSET_PREFIX: &str = "c:";
+
+// The pool prefix that identifies the swap operation as a refraction or merge on Pryzm's refractor module
+pub const REFRACTOR_POOL_PREFIX: &str = "refractor:";
+
+// The prefix of the refractor module's principal token denominations
+pub const P_ASSET_PREFIX: &str = "p:";
+
+// The prefix of the refractor module's yield token denominations
+pub const Y_ASSET_PREFIX: &str = "y:";
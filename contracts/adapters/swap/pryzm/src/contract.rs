@@ -2,27 +2,39 @@ use std::collections::VecDeque;
 
 use cosmwasm_std::{
     entry_point, to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Reply,
-    Response, SubMsg, SubMsgResponse, SubMsgResult, WasmMsg,
+    Response, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw_utils::one_coin;
-use pryzm_std::types::pryzm::{amm::v1::MsgBatchSwapResponse, icstaking::v1::MsgStakeResponse};
+use pryzm_std::types::pryzm::{
+    amm::v1::MsgBatchSwapResponse,
+    icstaking::v1::{MsgInstantUnstakeResponse, MsgStakeResponse},
+    refractor::v1::{MsgMergeResponse, MsgRefractResponse},
+};
 
-use skip::swap::{
-    execute_transfer_funds_back, get_ask_denom_for_routes, ExecuteMsg, InstantiateMsg, MigrateMsg,
-    QueryMsg, SwapOperation,
+use skip::{
+    error::SkipError,
+    swap::{
+        execute_transfer_funds_back, get_ask_denom_for_routes, CapabilitiesResponse, ExecuteMsg,
+        InstantiateMsg, MigrateMsg, PoolIdentifier, QueryMsg, SwapOperation,
+    },
 };
 
 use crate::{
     error::{ContractError, ContractResult},
-    execution::{extract_execution_steps, parse_coin, SwapExecutionStep},
+    execution::{
+        extract_execution_steps, get_refracted_sibling_denom, parse_coin, SwapExecutionStep,
+    },
     reply_id,
     simulate::{
         simulate_smart_swap_exact_asset_in, simulate_smart_swap_exact_asset_in_with_metadata,
         simulate_swap_exact_asset_in, simulate_swap_exact_asset_in_with_metadata,
         simulate_swap_exact_asset_out, simulate_swap_exact_asset_out_with_metadata,
     },
-    state::{ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_SWAP_OPERATIONS, IN_PROGRESS_SWAP_SENDER},
+    state::{
+        ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_SWAP_MIN_AMOUNT_OUT, IN_PROGRESS_SWAP_OPERATIONS,
+        IN_PROGRESS_SWAP_SENDER,
+    },
 };
 
 ///////////////
@@ -31,7 +43,7 @@ use crate::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -79,7 +91,10 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> ContractResult<Response> {
     match msg {
-        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, operations, min_amount_out),
         ExecuteMsg::TransferFundsBack {
             swapper,
             return_denom,
@@ -90,9 +105,7 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        _ => {
-            unimplemented!()
-        }
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -102,6 +115,7 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -118,7 +132,14 @@ fn execute_swap(
     let execution_steps = extract_execution_steps(operations)?;
 
     // Execute the swap
-    execute_steps(deps, env, info.sender, coin_in, execution_steps)
+    execute_steps(
+        deps,
+        env,
+        info.sender,
+        coin_in,
+        execution_steps,
+        min_amount_out,
+    )
 }
 
 // Executes the swap of the provided coin using the provided execution steps for the swapper
@@ -126,19 +147,38 @@ fn execute_steps(
     deps: DepsMut,
     env: Env,
     swapper: Addr,
-    coin_in: Coin,
+    mut coin_in: Coin,
     execution_steps: VecDeque<SwapExecutionStep>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // return error if execution_steps is empty
     if execution_steps.is_empty() {
         return Err(ContractError::SwapOperationsEmpty);
     }
 
-    // convert the first execution step to the appropriate cosmos message
     let first_step = execution_steps.front().unwrap();
-    let msg = first_step
-        .clone()
-        .to_cosmos_msg(env.contract.address.to_string(), coin_in)?;
+
+    // A merge burns as many of the other token as the token in, so only merge as much of the
+    // token in as the contract holds of the other token, what is not merged of either token
+    // is transferred back with the swap output
+    if let SwapExecutionStep::Merge { token_in, .. } = first_step {
+        let sibling_balance = deps
+            .querier
+            .query_balance(&env.contract.address, get_refracted_sibling_denom(token_in))?;
+        coin_in.amount = coin_in.amount.min(sibling_balance.amount);
+    }
+
+    // convert the first execution step to the appropriate cosmos message,
+    // only the last execution step enforces the min amount out
+    let msg = first_step.clone().to_cosmos_msg(
+        env.contract.address.to_string(),
+        coin_in,
+        if execution_steps.len() == 1 {
+            min_amount_out
+        } else {
+            None
+        },
+    )?;
 
     // If there is only one execution step, create the transfer funds back message since the swap is done is a single step
     if execution_steps.len() == 1 {
@@ -167,11 +207,23 @@ fn execute_steps(
         SwapExecutionStep::Stake { .. } => {
             SubMsg::reply_on_success(msg.clone(), reply_id::STAKE_REPLY_ID)
         }
+        SwapExecutionStep::Redeem { .. } => {
+            SubMsg::reply_on_success(msg.clone(), reply_id::INSTANT_UNSTAKE_REPLY_ID)
+        }
+        SwapExecutionStep::Refract { .. } => {
+            SubMsg::reply_on_success(msg.clone(), reply_id::REFRACT_REPLY_ID)
+        }
+        SwapExecutionStep::Merge { .. } => {
+            SubMsg::reply_on_success(msg.clone(), reply_id::MERGE_REPLY_ID)
+        }
     };
 
     // store the steps and the swapper to continue after the current step is executed in the reply entrypoint
     IN_PROGRESS_SWAP_OPERATIONS.save(deps.storage, &execution_steps)?;
     IN_PROGRESS_SWAP_SENDER.save(deps.storage, &swapper)?;
+    if let Some(min_amount_out) = min_amount_out {
+        IN_PROGRESS_SWAP_MIN_AMOUNT_OUT.save(deps.storage, &min_amount_out)?;
+    }
 
     Ok(Response::new()
         .add_submessage(sub_msg)
@@ -192,12 +244,16 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> ContractResult<Response>
         });
     };
 
+    // load the swap execution steps from the store
+    let mut in_progress_exec_steps = IN_PROGRESS_SWAP_OPERATIONS.load(deps.storage)?;
+    IN_PROGRESS_SWAP_OPERATIONS.remove(deps.storage);
+
     // handle the reply and use the output of the swap as the coin_in for the next swap steps
     let coin_in: Coin;
     match reply.id {
         reply_id::BATCH_SWAP_REPLY_ID => {
             // Parse the batch swap response from the sub message
-            let resp: MsgBatchSwapResponse = b.try_into().map_err(ContractError::Std).unwrap();
+            let resp: MsgBatchSwapResponse = b.try_into()?;
             if resp.amounts_out.len() != 1 {
                 return Err(ContractError::InvalidMsgResponse {
                     msg: "unexpected amounts out length is batch swap response".to_string(),
@@ -207,7 +263,7 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> ContractResult<Response>
         }
         reply_id::STAKE_REPLY_ID => {
             // Parse the stake response from the sub message
-            let resp: MsgStakeResponse = b.try_into().map_err(ContractError::Std).unwrap();
+            let resp: MsgStakeResponse = b.try_into()?;
             if let Some(c_amount) = resp.c_amount {
                 coin_in = parse_coin(&c_amount)
             } else {
@@ -216,6 +272,54 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> ContractResult<Response>
                 });
             }
         }
+        reply_id::INSTANT_UNSTAKE_REPLY_ID => {
+            // Parse the instant unstake response from the sub message
+            let resp: MsgInstantUnstakeResponse = b.try_into()?;
+            if let Some(amount) = resp.amount {
+                coin_in = parse_coin(&amount)
+            } else {
+                return Err(ContractError::InvalidMsgResponse {
+                    msg: "expected valid amount in instant unstake response, received None"
+                        .to_string(),
+                });
+            }
+        }
+        reply_id::REFRACT_REPLY_ID => {
+            // Parse the refract response from the sub message, only the refracted token
+            // out of the executed step continues the swap
+            let resp: MsgRefractResponse = b.try_into()?;
+            let Some(SwapExecutionStep::Refract { token_out, .. }) = in_progress_exec_steps.front()
+            else {
+                return Err(ContractError::InvalidState {
+                    msg: "expected in progress refract step for refract reply".to_string(),
+                });
+            };
+            if let Some(amount) = [resp.p_amount, resp.y_amount]
+                .into_iter()
+                .flatten()
+                .find(|amount| &amount.denom == token_out)
+            {
+                coin_in = parse_coin(&amount)
+            } else {
+                return Err(ContractError::InvalidMsgResponse {
+                    msg: format!(
+                        "expected {} amount in refract response, received None",
+                        token_out
+                    ),
+                });
+            }
+        }
+        reply_id::MERGE_REPLY_ID => {
+            // Parse the merge response from the sub message
+            let resp: MsgMergeResponse = b.try_into()?;
+            if let Some(c_amount) = resp.c_amount {
+                coin_in = parse_coin(&c_amount)
+            } else {
+                return Err(ContractError::InvalidMsgResponse {
+                    msg: "expected valid c_amount in merge response, received None".to_string(),
+                });
+            }
+        }
         _ => {
             return Err(ContractError::InvalidState {
                 msg: format!("unexpected reply id {}", reply.id),
@@ -223,19 +327,26 @@ pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> ContractResult<Response>
         }
     }
 
-    // load the swap execution steps from the store
-    let mut in_progress_exec_steps = IN_PROGRESS_SWAP_OPERATIONS.load(deps.storage)?;
-    IN_PROGRESS_SWAP_OPERATIONS.remove(deps.storage);
-
     // load the swapper address from the store
     let swapper = IN_PROGRESS_SWAP_SENDER.load(deps.storage)?;
     IN_PROGRESS_SWAP_SENDER.remove(deps.storage);
 
+    // load the min amount out from the store
+    let min_amount_out = IN_PROGRESS_SWAP_MIN_AMOUNT_OUT.may_load(deps.storage)?;
+    IN_PROGRESS_SWAP_MIN_AMOUNT_OUT.remove(deps.storage);
+
     // remove the first step (which is already executed)
     in_progress_exec_steps.pop_front();
 
     // continue the swap execution with the next steps
-    execute_steps(deps, env, swapper, coin_in, in_progress_exec_steps)
+    execute_steps(
+        deps,
+        env,
+        swapper,
+        coin_in,
+        in_progress_exec_steps,
+        min_amount_out,
+    )
 }
 
 /////////////
@@ -303,6 +414,21 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
+
+// Returns the features supported by the pryzm swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: false,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::VenuePoolIdentifier],
+    }
+}
@@ -47,4 +47,7 @@ pub enum ContractError {
 
     #[error("InvalidState: {msg:?}")]
     InvalidState { msg: String },
+
+    #[error("Instant redemption of a cAsset can only be simulated given the exact amount in")]
+    RedeemExactOutUnsupported,
 }
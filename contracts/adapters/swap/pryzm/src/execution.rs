@@ -5,7 +5,8 @@ use cosmwasm_std::{coin, Coin, CosmosMsg, Uint128};
 use pryzm_std::types::cosmos::base::v1beta1::Coin as CosmosCoin;
 use pryzm_std::types::pryzm::{
     amm::v1::{MsgBatchSwap, SwapStep, SwapType},
-    icstaking::v1::MsgStake,
+    icstaking::v1::{MsgInstantUnstake, MsgStake},
+    refractor::v1::{MsgMerge, MsgRefract},
 };
 use skip::swap::SwapOperation;
 use std::collections::VecDeque;
@@ -22,18 +23,41 @@ pub enum SwapExecutionStep {
         host_chain_id: String,    // the host chain id for staking
         transfer_channel: String, // the transfer channel of the tokens
     },
+    // Redeem represents an instant redemption of a cAsset on Pryzm's icstaking module
+    Redeem {
+        host_chain_id: String,    // the host chain id of the cAsset
+        transfer_channel: String, // the transfer channel of the tokens
+        token_out: String,        // the denom of the redeemed asset on Pryzm
+    },
+    // Refract represents splitting a cAsset into principal and yield tokens on Pryzm's refractor
+    // module, only the token out continues the swap while the other token stays in the contract
+    // until a later merge step burns it or it is transferred back with the swap output
+    Refract {
+        host_chain_id: String, // the host chain id of the cAsset
+        maturity: String,      // the maturity of the principal and yield tokens
+        token_out: String,     // the principal or yield token continuing the swap
+    },
+    // Merge represents merging principal and yield tokens back into the cAsset on Pryzm's refractor
+    // module, merging as much of the token in as the contract holds of the other token
+    Merge {
+        host_chain_id: String, // the host chain id of the cAsset
+        maturity: String,      // the maturity of the principal and yield tokens
+        token_in: String,      // the principal or yield token merged with the other token
+    },
 }
 
 impl SwapExecutionStep {
-    // Converts the step to the appropriate Pryzm message
+    // Converts the step to the appropriate Pryzm message, the min amount out is only
+    // enforced by batch swaps since icstaking and refractor messages have no minimum output
     pub fn to_cosmos_msg(
         &self,
         address: String,
         coin_in: Coin,
+        min_amount_out: Option<Uint128>,
     ) -> Result<CosmosMsg, ContractError> {
         match self {
             SwapExecutionStep::Swap { swap_steps } => {
-                create_amm_swap_msg(swap_steps, address, coin_in)
+                create_amm_swap_msg(swap_steps, address, coin_in, min_amount_out)
             }
             SwapExecutionStep::Stake {
                 host_chain_id,
@@ -44,6 +68,29 @@ impl SwapExecutionStep {
                 address,
                 coin_in,
             ),
+            SwapExecutionStep::Redeem {
+                host_chain_id,
+                transfer_channel,
+                ..
+            } => create_icstaking_instant_unstake_msg(
+                host_chain_id.clone(),
+                transfer_channel.clone(),
+                address,
+                coin_in,
+            ),
+            SwapExecutionStep::Refract { maturity, .. } => {
+                create_refractor_refract_msg(maturity.clone(), address, coin_in)
+            }
+            SwapExecutionStep::Merge {
+                host_chain_id,
+                maturity,
+                ..
+            } => create_refractor_merge_msg(
+                get_c_asset_denom(host_chain_id),
+                maturity.clone(),
+                address,
+                coin_in,
+            ),
         }
     }
 
@@ -58,14 +105,21 @@ impl SwapExecutionStep {
                 };
                 Ok(token_out)
             }
-            SwapExecutionStep::Stake { host_chain_id, .. } => {
-                // calculate the cAsset denom by prefixing "c:" to the host chain id
-                Ok(format!("{}{}", consts::C_ASSET_PREFIX, host_chain_id))
+            SwapExecutionStep::Stake { host_chain_id, .. }
+            | SwapExecutionStep::Merge { host_chain_id, .. } => {
+                Ok(get_c_asset_denom(host_chain_id))
             }
+            SwapExecutionStep::Redeem { token_out, .. }
+            | SwapExecutionStep::Refract { token_out, .. } => Ok(token_out.clone()),
         }
     }
 }
 
+// Calculates the cAsset denom by prefixing "c:" to the host chain id
+pub fn get_c_asset_denom(host_chain_id: &str) -> String {
+    format!("{}{}", consts::C_ASSET_PREFIX, host_chain_id)
+}
+
 // Iterates over the swap operations and aggregates the operations into execution steps
 pub fn extract_execution_steps(
     operations: Vec<SwapOperation>,
@@ -85,19 +139,54 @@ pub fn extract_execution_steps(
     let swap_operations_iter = operations.iter();
     for swap_op in swap_operations_iter {
         if swap_op.pool.starts_with(consts::ICSTAKING_POOL_PREFIX) {
-            // Validate that the icstaking operation is converting an asset to a cAsset,
-            // not a cAsset to an asset which is not supported
-            if swap_op.denom_in.starts_with(consts::C_ASSET_PREFIX)
-                || !swap_op.denom_out.starts_with(consts::C_ASSET_PREFIX)
-            {
+            // If there are AMM swap steps from before, aggregate and push them into the execution steps
+            if !amm_swap_steps.is_empty() {
+                execution_steps.push_back(SwapExecutionStep::Swap {
+                    swap_steps: amm_swap_steps,
+                });
+                amm_swap_steps = Vec::new();
+            }
+
+            // split and validate the pool string
+            let split: Vec<&str> = swap_op.pool.split(':').collect();
+            if split.len() != 3 {
                 return Err(ContractError::InvalidPool {
                     msg: format!(
-                        "icstaking swap operation can only convert an asset to cAsset: cannot convert {} to {}",
-                        swap_op.denom_in, swap_op.denom_out
+                        "icstaking pool string must be in the format \"icstaking:<host_chain_id>:<transfer_channel>\": {}",
+                        swap_op.pool
                     )
                 });
             }
+            let host_chain_id = split.get(1).unwrap().to_string();
+            let transfer_channel = split.get(2).unwrap().to_string();
+            let c_asset_denom = get_c_asset_denom(&host_chain_id);
 
+            // The icstaking operation either stakes an asset into the host chain's cAsset
+            // or instantly redeems the host chain's cAsset back into the asset
+            if swap_op.denom_out == c_asset_denom
+                && !swap_op.denom_in.starts_with(consts::C_ASSET_PREFIX)
+            {
+                execution_steps.push_back(SwapExecutionStep::Stake {
+                    host_chain_id,
+                    transfer_channel,
+                });
+            } else if swap_op.denom_in == c_asset_denom
+                && !swap_op.denom_out.starts_with(consts::C_ASSET_PREFIX)
+            {
+                execution_steps.push_back(SwapExecutionStep::Redeem {
+                    host_chain_id,
+                    transfer_channel,
+                    token_out: swap_op.denom_out.clone(),
+                });
+            } else {
+                return Err(ContractError::InvalidPool {
+                    msg: format!(
+                        "icstaking swap operation can only convert between an asset and the {} cAsset: cannot convert {} to {}",
+                        c_asset_denom, swap_op.denom_in, swap_op.denom_out
+                    )
+                });
+            }
+        } else if swap_op.pool.starts_with(consts::REFRACTOR_POOL_PREFIX) {
             // If there are AMM swap steps from before, aggregate and push them into the execution steps
             if !amm_swap_steps.is_empty() {
                 execution_steps.push_back(SwapExecutionStep::Swap {
@@ -106,22 +195,59 @@ pub fn extract_execution_steps(
                 amm_swap_steps = Vec::new();
             }
 
-            // split and validate the pool string
-            let split: Vec<&str> = swap_op.pool.split(':').collect();
-            if split.len() != 3 {
+            // replace the pool prefix to get the maturity
+            let maturity = swap_op.pool.replace(consts::REFRACTOR_POOL_PREFIX, "");
+            if maturity.is_empty() || maturity.contains(':') {
                 return Err(ContractError::InvalidPool {
                     msg: format!(
-                        "icstaking pool string must be in the format \"icstaking:<host_chain_id>:<transfer_channel>\": {}",
+                        "refractor pool string must be in the format \"refractor:<maturity>\": {}",
                         swap_op.pool
-                    )
+                    ),
                 });
             }
 
-            // Push the staking operation into the execution steps
-            execution_steps.push_back(SwapExecutionStep::Stake {
-                host_chain_id: split.get(1).unwrap().to_string(),
-                transfer_channel: split.get(2).unwrap().to_string(),
-            });
+            // The refractor operation either refracts a cAsset into its principal or yield
+            // token, or merges a principal or yield token back into the cAsset
+            if let Some(host_chain_id) = swap_op.denom_in.strip_prefix(consts::C_ASSET_PREFIX) {
+                if !is_refracted_denom(&swap_op.denom_out, host_chain_id, &maturity) {
+                    return Err(ContractError::InvalidPool {
+                        msg: format!(
+                            "refractor swap operation can only refract {} into its principal or yield token of maturity {}: cannot convert to {}",
+                            swap_op.denom_in, maturity, swap_op.denom_out
+                        )
+                    });
+                }
+
+                execution_steps.push_back(SwapExecutionStep::Refract {
+                    host_chain_id: host_chain_id.to_string(),
+                    maturity,
+                    token_out: swap_op.denom_out.clone(),
+                });
+            } else if let Some(host_chain_id) =
+                swap_op.denom_out.strip_prefix(consts::C_ASSET_PREFIX)
+            {
+                if !is_refracted_denom(&swap_op.denom_in, host_chain_id, &maturity) {
+                    return Err(ContractError::InvalidPool {
+                        msg: format!(
+                            "refractor swap operation can only merge the principal or yield token of maturity {} into {}: cannot convert {}",
+                            maturity, swap_op.denom_out, swap_op.denom_in
+                        )
+                    });
+                }
+
+                execution_steps.push_back(SwapExecutionStep::Merge {
+                    host_chain_id: host_chain_id.to_string(),
+                    maturity,
+                    token_in: swap_op.denom_in.clone(),
+                });
+            } else {
+                return Err(ContractError::InvalidPool {
+                    msg: format!(
+                        "refractor swap operation must refract or merge a cAsset: cannot convert {} to {}",
+                        swap_op.denom_in, swap_op.denom_out
+                    ),
+                });
+            }
         } else if swap_op.pool.starts_with(consts::AMM_POOL_PREFIX) {
             // replace the pool prefix and parse the pool id
             let pool_id = swap_op.pool.replace(consts::AMM_POOL_PREFIX, "");
@@ -141,7 +267,7 @@ pub fn extract_execution_steps(
         } else {
             return Err(ContractError::InvalidPool {
                 msg: format!(
-                    "pool must be started with \"amm\" or \"icstaking\": {}",
+                    "pool must be started with \"amm\", \"icstaking\" or \"refractor\": {}",
                     swap_op.pool
                 ),
             });
@@ -163,6 +289,7 @@ pub fn create_amm_swap_msg(
     swap_steps: &[SwapStep],
     address: String,
     coin_in: Coin,
+    min_amount_out: Option<Uint128>,
 ) -> Result<CosmosMsg, ContractError> {
     // take the last step token_out as the return denom
     let token_out = match swap_steps.last() {
@@ -176,13 +303,17 @@ pub fn create_amm_swap_msg(
         first_step.amount = coin_in.amount.to_string().into();
     }
 
-    // construct the message
+    // construct the message, the min amount out is never set below 1
+    // since the amm module requires it to be positive
     let swap_msg: CosmosMsg = MsgBatchSwap {
         creator: address,
         swap_type: SwapType::GivenIn.into(),
         max_amounts_in: vec![format_coin(coin_in)],
         min_amounts_out: vec![CosmosCoin {
-            amount: "1".to_string(),
+            amount: min_amount_out
+                .unwrap_or_default()
+                .max(Uint128::one())
+                .to_string(),
             denom: token_out.to_string(),
         }],
         steps,
@@ -211,6 +342,83 @@ fn create_icstaking_stake_msg(
     Ok(msg)
 }
 
+// create Pryzm MsgInstantUnstake using the provided host chain and transfer channel,
+// redeeming exactly the provided cAsset amount
+fn create_icstaking_instant_unstake_msg(
+    host_chain_id: String,
+    transfer_channel: String,
+    address: String,
+    coin_in: Coin,
+) -> Result<CosmosMsg, ContractError> {
+    // Construct the message
+    let msg: CosmosMsg = MsgInstantUnstake {
+        creator: address,
+        host_chain: host_chain_id,
+        transfer_channel,
+        min_c_amount: coin_in.amount.to_string(),
+        max_c_amount: coin_in.amount.to_string(),
+    }
+    .into();
+
+    Ok(msg)
+}
+
+// create Pryzm MsgRefract using the provided maturity
+fn create_refractor_refract_msg(
+    maturity: String,
+    address: String,
+    coin_in: Coin,
+) -> Result<CosmosMsg, ContractError> {
+    // Construct the message
+    let msg: CosmosMsg = MsgRefract {
+        creator: address,
+        amount: Some(format_coin(coin_in)),
+        maturity,
+    }
+    .into();
+
+    Ok(msg)
+}
+
+// create Pryzm MsgMerge using the provided cAsset denom and maturity
+fn create_refractor_merge_msg(
+    c_asset_denom: String,
+    maturity: String,
+    address: String,
+    coin_in: Coin,
+) -> Result<CosmosMsg, ContractError> {
+    // Construct the message
+    let msg: CosmosMsg = MsgMerge {
+        creator: address,
+        c_asset_denom,
+        maturity,
+        amount: coin_in.amount.to_string(),
+    }
+    .into();
+
+    Ok(msg)
+}
+
+// Returns the yield token of a principal token denom, or the principal token of a yield token denom
+pub fn get_refracted_sibling_denom(denom: &str) -> String {
+    if let Some(rest) = denom.strip_prefix(consts::P_ASSET_PREFIX) {
+        format!("{}{}", consts::Y_ASSET_PREFIX, rest)
+    } else {
+        format!(
+            "{}{}",
+            consts::P_ASSET_PREFIX,
+            denom.strip_prefix(consts::Y_ASSET_PREFIX).unwrap_or(denom)
+        )
+    }
+}
+
+// Returns true if the denom is the principal or yield token of the host chain's cAsset with the maturity
+fn is_refracted_denom(denom: &str, host_chain_id: &str, maturity: &str) -> bool {
+    [consts::P_ASSET_PREFIX, consts::Y_ASSET_PREFIX]
+        .iter()
+        .any(|prefix| denom == format!("{}{}:{}", prefix, host_chain_id, maturity))
+}
+
 pub fn parse_coin(c: &CosmosCoin) -> Coin {
     let p: Uint128 = c.amount.parse().unwrap();
     coin(p.u128(), &c.denom)
@@ -3,3 +3,12 @@ pub const BATCH_SWAP_REPLY_ID: u64 = 1;
 
 // The reply id for handling icstaking module's staking message
 pub const STAKE_REPLY_ID: u64 = 2;
+
+// The reply id for handling icstaking module's instant unstaking message
+pub const INSTANT_UNSTAKE_REPLY_ID: u64 = 3;
+
+// The reply id for handling refractor module's refract message
+pub const REFRACT_REPLY_ID: u64 = 4;
+
+// The reply id for handling refractor module's merge message
+pub const MERGE_REPLY_ID: u64 = 5;
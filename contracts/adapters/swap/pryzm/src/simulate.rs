@@ -1,19 +1,22 @@
 use std::str::FromStr;
 
-use cosmwasm_std::{Decimal, Deps, Uint128};
+use cosmwasm_std::{coin, Coin, Decimal, Deps, Empty, Uint128};
 use pryzm_std::types::pryzm::amm::v1::{
     AmmQuerier, QuerySimulateBatchSwapResponse, QuerySpotPriceResponse, SwapType,
 };
-use pryzm_std::types::pryzm::icstaking::v1::{IcstakingQuerier, QuerySimulateStakeResponse};
+use pryzm_std::types::pryzm::icstaking::v1::{
+    IcstakingQuerier, QueryGetHostChainStateResponse, QuerySimulateInstantUnstakeResponse,
+    QuerySimulateStakeResponse,
+};
 
 use skip::asset::Asset;
 use skip::swap::{
-    Route, SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
-    SimulateSwapExactAssetOutResponse, SwapOperation,
+    calculate_weighted_spot_price, Route, SimulateSmartSwapExactAssetInResponse,
+    SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
 };
 
 use crate::error::{ContractError, ContractResult};
-use crate::execution::{extract_execution_steps, parse_coin, SwapExecutionStep};
+use crate::execution::{extract_execution_steps, get_c_asset_denom, parse_coin, SwapExecutionStep};
 
 // Simulates a swap given the exact amount in
 pub fn simulate_swap_exact_asset_in(
@@ -87,6 +90,39 @@ pub fn simulate_swap_exact_asset_in(
                     });
                 }
             }
+            SwapExecutionStep::Redeem {
+                host_chain_id,
+                transfer_channel,
+                ..
+            } => {
+                // execute the instant redemption simulation query on the icstaking module
+                step_amount = simulate_instant_unstake(
+                    icstaking_querier,
+                    host_chain_id,
+                    transfer_channel,
+                    step_amount.amount,
+                )?;
+            }
+            SwapExecutionStep::Refract {
+                host_chain_id,
+                token_out,
+                ..
+            } => {
+                // refracting a cAsset mints principal and yield tokens at the cAsset exchange rate
+                let exchange_rate = get_c_asset_exchange_rate(icstaking_querier, &host_chain_id)?;
+                step_amount = coin(
+                    step_amount.amount.mul_floor(exchange_rate).u128(),
+                    token_out,
+                );
+            }
+            SwapExecutionStep::Merge { host_chain_id, .. } => {
+                // merging principal and yield tokens mints the cAsset at the cAsset exchange rate
+                let exchange_rate = get_c_asset_exchange_rate(icstaking_querier, &host_chain_id)?;
+                step_amount = coin(
+                    step_amount.amount.div_floor(exchange_rate).u128(),
+                    get_c_asset_denom(&host_chain_id),
+                );
+            }
         }
     }
 
@@ -187,6 +223,27 @@ pub fn simulate_swap_exact_asset_out(
                     });
                 }
             }
+            SwapExecutionStep::Redeem { .. } => {
+                // icstaking can only simulate an instant redemption given the cAsset amount in
+                return Err(ContractError::RedeemExactOutUnsupported);
+            }
+            SwapExecutionStep::Refract { host_chain_id, .. } => {
+                // the cAsset amount in is rounded up so refracting it never mints less than the amount out
+                let exchange_rate = get_c_asset_exchange_rate(icstaking_querier, &host_chain_id)?;
+                step_amount = coin(
+                    step_amount.amount.div_ceil(exchange_rate).u128(),
+                    get_c_asset_denom(&host_chain_id),
+                );
+            }
+            SwapExecutionStep::Merge {
+                host_chain_id,
+                token_in,
+                ..
+            } => {
+                // the token amount in is rounded up so merging it never mints less than the amount out
+                let exchange_rate = get_c_asset_exchange_rate(icstaking_querier, &host_chain_id)?;
+                step_amount = coin(step_amount.amount.mul_ceil(exchange_rate).u128(), token_in);
+            }
         }
     }
 
@@ -258,7 +315,7 @@ pub fn simulate_smart_swap_exact_asset_in_with_metadata(
 
     // calculate and include weighted spot price if requested
     if include_spot_price {
-        response.spot_price = Some(calculate_weighted_spot_price(deps, asset_in, routes)?)
+        response.spot_price = Some(calculate_smart_swap_spot_price(deps, asset_in, routes)?)
     }
 
     Ok(response)
@@ -328,6 +385,29 @@ fn calculate_spot_price(
                         });
                     }
                 }
+                SwapExecutionStep::Redeem {
+                    host_chain_id,
+                    transfer_channel,
+                    ..
+                } => {
+                    // calculate spot price for instant redemption, by simulating it for an amount
+                    let amount = Uint128::from(1_000_000_000_000_000_000u128); // 1e18
+                    let amount_out = simulate_instant_unstake(
+                        icstaking_querier,
+                        host_chain_id,
+                        transfer_channel,
+                        amount,
+                    )?;
+                    Ok(Decimal::checked_from_ratio(amount_out.amount, amount)?)
+                }
+                SwapExecutionStep::Refract { host_chain_id, .. } => {
+                    get_c_asset_exchange_rate(icstaking_querier, &host_chain_id)
+                }
+                SwapExecutionStep::Merge { host_chain_id, .. } => {
+                    let exchange_rate =
+                        get_c_asset_exchange_rate(icstaking_querier, &host_chain_id)?;
+                    Ok(Decimal::one().checked_div(exchange_rate)?)
+                }
             };
 
             Ok(curr_spot_price.checked_mul(step_spot_price?)?)
@@ -338,26 +418,65 @@ fn calculate_spot_price(
 }
 
 // Calculate weighted spot price for a set of routes
-fn calculate_weighted_spot_price(
+fn calculate_smart_swap_spot_price(
     deps: Deps,
     asset_in: Asset,
     routes: Vec<Route>,
 ) -> ContractResult<Decimal> {
-    // iterate over the routes, calculate each route spot price, and multiply them based on the
-    // weight of that route
-    let spot_price = routes.into_iter().try_fold(
-        Decimal::zero(),
-        |curr_spot_price, route| -> ContractResult<Decimal> {
-            // calculate route's spot price
-            let route_spot_price = calculate_spot_price(deps, route.operations)?;
-
-            // calculate the weight of the route, which is equal to the ratio of amount swapped on
-            // the route to the total amount being swapped
-            let weight = Decimal::from_ratio(route.offer_asset.amount(), asset_in.amount());
-
-            Ok(curr_spot_price + (route_spot_price * weight))
-        },
+    // calculate each route's spot price, weighted by the ratio of the amount swapped on the
+    // route to the total amount being swapped
+    let route_spot_prices = routes
+        .into_iter()
+        .map(|route| {
+            Ok((
+                route.offer_asset.amount(),
+                calculate_spot_price(deps, route.operations)?,
+            ))
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
+
+    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
+}
+
+// Simulates instantly redeeming the cAsset amount on the icstaking module
+fn simulate_instant_unstake(
+    icstaking_querier: &IcstakingQuerier<'_, Empty>,
+    host_chain_id: String,
+    transfer_channel: String,
+    c_amount: Uint128,
+) -> ContractResult<Coin> {
+    let res: QuerySimulateInstantUnstakeResponse = icstaking_querier.simulate_instant_unstake(
+        host_chain_id,
+        transfer_channel,
+        c_amount.to_string(),
+        c_amount.to_string(),
     )?;
 
-    Ok(spot_price)
+    match res.amount {
+        Some(amount) => Ok(parse_coin(&amount)),
+        None => Err(ContractError::InvalidQueryResponse {
+            msg: "unexpected amount in instant unstake simulation".to_string(),
+        }),
+    }
+}
+
+// Returns the exchange rate of the host chain's cAsset, which is the amount of
+// principal or yield tokens minted by refracting one cAsset
+fn get_c_asset_exchange_rate(
+    icstaking_querier: &IcstakingQuerier<'_, Empty>,
+    host_chain_id: &str,
+) -> ContractResult<Decimal> {
+    let res: QueryGetHostChainStateResponse =
+        icstaking_querier.host_chain_state(host_chain_id.to_string())?;
+
+    // parse the exchange rate, erroring instead of dividing by zero in the simulations
+    match res
+        .host_chain_state
+        .and_then(|state| Decimal::from_str(&state.exchange_rate).ok())
+    {
+        Some(exchange_rate) if !exchange_rate.is_zero() => Ok(exchange_rate),
+        _ => Err(ContractError::InvalidQueryResponse {
+            msg: "invalid exchange rate in host chain state query".to_string(),
+        }),
+    }
 }
@@ -1,5 +1,5 @@
 use crate::execution::SwapExecutionStep;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::Item;
 use std::collections::VecDeque;
 
@@ -11,3 +11,7 @@ pub const IN_PROGRESS_SWAP_OPERATIONS: Item<VecDeque<SwapExecutionStep>> =
 
 // stores the address of the swapper for the in progress swap, used by the reply entrypoint
 pub const IN_PROGRESS_SWAP_SENDER: Item<Addr> = Item::new("in_progress_swap_sender");
+
+// stores the min amount out of the in progress swap if given, used by the reply entrypoint
+pub const IN_PROGRESS_SWAP_MIN_AMOUNT_OUT: Item<Uint128> =
+    Item::new("in_progress_swap_min_amount_out");
@@ -8,23 +8,27 @@ use cosmwasm_std::{
     ReplyOn::Success,
     SubMsg, WasmMsg,
 };
-use cosmwasm_std::{Reply, SubMsgResponse, SubMsgResult};
+use cosmwasm_std::{Reply, SubMsgResponse, SubMsgResult, Uint128};
 #[allow(unused_imports)]
 use pryzm_std::types::{
     cosmos::base::v1beta1::Coin as CosmosCoin,
     pryzm::amm::v1::{MsgBatchSwap, MsgBatchSwapResponse, SwapStep, SwapType},
-    pryzm::icstaking::v1::{MsgStake, MsgStakeResponse},
+    pryzm::icstaking::v1::{MsgInstantUnstake, MsgStake, MsgStakeResponse},
+    pryzm::refractor::v1::{MsgMerge, MsgMergeResponse, MsgRefractResponse},
 };
 use test_case::test_case;
 
 #[allow(unused_imports)]
 use skip::swap::{ExecuteMsg, SwapOperation};
 use skip_go_swap_adapter_pryzm::execution::SwapExecutionStep;
-use skip_go_swap_adapter_pryzm::state::{IN_PROGRESS_SWAP_OPERATIONS, IN_PROGRESS_SWAP_SENDER};
+use skip_go_swap_adapter_pryzm::state::{
+    IN_PROGRESS_SWAP_MIN_AMOUNT_OUT, IN_PROGRESS_SWAP_OPERATIONS, IN_PROGRESS_SWAP_SENDER,
+};
 #[allow(unused_imports)]
 use skip_go_swap_adapter_pryzm::{
-    contract, error::ContractResult, reply_id::BATCH_SWAP_REPLY_ID, reply_id::STAKE_REPLY_ID,
-    state::ENTRY_POINT_CONTRACT_ADDRESS,
+    contract, error::ContractResult, reply_id::BATCH_SWAP_REPLY_ID,
+    reply_id::INSTANT_UNSTAKE_REPLY_ID, reply_id::MERGE_REPLY_ID, reply_id::REFRACT_REPLY_ID,
+    reply_id::STAKE_REPLY_ID, state::ENTRY_POINT_CONTRACT_ADDRESS,
 };
 
 /*
@@ -32,15 +36,25 @@ Test Cases:
 
 Expect Success
     - One-Step Left
+    - One-Step Left With Min Amount Out
     - Many Steps Left
+    - Refract With One Step Left (Only the refracted token out is swapped on the AMM, the other token is transferred back with the swap output)
+    - Refract With The Merge Step Left (Only the refracted token out continues the swap until it is merged back)
+    - Refract With The Merge Step Left And Less Of The Sibling Token (Only as much of the token in as the contract holds of the sibling token is merged)
+    - Merge With Many Steps Left
+
+Expect Error
+    - Refract Response Without The Refracted Token Out
 
  */
 
 // Define test parameters
 struct Params {
     swapper: String,
+    contract_balances: Vec<Coin>,
     reply_id: u64,
     swap_steps: Vec<SwapExecutionStep>,
+    min_amount_out: Option<Uint128>,
     response: Binary,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
@@ -52,6 +66,7 @@ struct Params {
 #[test_case(
     Params {
         swapper: "entry_point".to_string(),
+        contract_balances: vec![],
         reply_id: STAKE_REPLY_ID,
         swap_steps: vec![
             SwapExecutionStep::Stake {
@@ -69,6 +84,7 @@ struct Params {
                 ],
             },
         ],
+        min_amount_out: None,
         response: MsgStakeResponse {
             c_amount: Some(CosmosCoin {amount: "1800".to_string(), denom: "c:uatom".to_string()}),
             fee: None,
@@ -116,6 +132,73 @@ struct Params {
 #[test_case(
     Params {
         swapper: "entry_point".to_string(),
+        contract_balances: vec![],
+        reply_id: STAKE_REPLY_ID,
+        swap_steps: vec![
+            SwapExecutionStep::Stake {
+                host_chain_id: "uatom".to_string(),
+                transfer_channel: "channel-0".to_string()
+            },
+            SwapExecutionStep::Swap {
+                swap_steps: vec![
+                    SwapStep {
+                        pool_id: 4,
+                        token_in: "c:uatom".to_string(),
+                        token_out: "p:uatom:30Sep2024".to_string(),
+                        amount: None,
+                    },
+                ],
+            },
+        ],
+        min_amount_out: Some(Uint128::new(1500)),
+        response: MsgStakeResponse {
+            c_amount: Some(CosmosCoin {amount: "1800".to_string(), denom: "c:uatom".to_string()}),
+            fee: None,
+        }.into(),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgBatchSwap {
+                    creator: "swap_contract_address".to_string(),
+                    swap_type: SwapType::GivenIn.into(),
+                    max_amounts_in: vec![CosmosCoin{amount: "1800".to_string(), denom: "c:uatom".to_string()}],
+                    min_amounts_out: vec![CosmosCoin{amount: "1500".to_string(), denom: "p:uatom:30Sep2024".to_string()}],
+                    steps: vec![
+                        SwapStep {
+                            pool_id: 4,
+                            token_in: "c:uatom".to_string(),
+                            token_out: "p:uatom:30Sep2024".to_string(),
+                            amount: Some("1800".to_string()),
+                        }
+                    ],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "p:uatom:30Sep2024".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "".to_string(),
+        expected_stored_steps: vec![],
+    };
+"One Step Left With Min Amount Out")]
+#[test_case(
+    Params {
+        swapper: "entry_point".to_string(),
+        contract_balances: vec![],
         reply_id: BATCH_SWAP_REPLY_ID,
         swap_steps: vec![
             SwapExecutionStep::Swap {
@@ -149,6 +232,7 @@ struct Params {
                 ],
             },
         ],
+        min_amount_out: None,
         response: MsgBatchSwapResponse {
             amounts_out: vec![CosmosCoin {amount: "100".to_string(), denom: "ibc/uatom".to_string()}],
             amounts_in: vec![],
@@ -190,6 +274,276 @@ struct Params {
         ],
     };
 "Multi Step Left")]
+#[test_case(
+    Params {
+        swapper: "entry_point".to_string(),
+        contract_balances: vec![Coin::new(1100, "p:uatom:30Sep2024")],
+        reply_id: REFRACT_REPLY_ID,
+        swap_steps: vec![
+            SwapExecutionStep::Refract {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_out: "y:uatom:30Sep2024".to_string(),
+            },
+            SwapExecutionStep::Swap {
+                swap_steps: vec![
+                    SwapStep {
+                        pool_id: 5,
+                        token_in: "y:uatom:30Sep2024".to_string(),
+                        token_out: "ibc/uusdc".to_string(),
+                        amount: None,
+                    },
+                ],
+            },
+        ],
+        min_amount_out: None,
+        response: MsgRefractResponse {
+            p_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "p:uatom:30Sep2024".to_string()}),
+            y_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "y:uatom:30Sep2024".to_string()}),
+        }.into(),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgBatchSwap {
+                    creator: "swap_contract_address".to_string(),
+                    swap_type: SwapType::GivenIn.into(),
+                    max_amounts_in: vec![CosmosCoin{amount: "1100".to_string(), denom: "y:uatom:30Sep2024".to_string()}],
+                    min_amounts_out: vec![CosmosCoin{amount: "1".to_string(), denom: "ibc/uusdc".to_string()}],
+                    steps: vec![
+                        SwapStep {
+                            pool_id: 5,
+                            token_in: "y:uatom:30Sep2024".to_string(),
+                            token_out: "ibc/uusdc".to_string(),
+                            amount: Some("1100".to_string()),
+                        }
+                    ],
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "ibc/uusdc".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "".to_string(),
+        expected_stored_steps: vec![],
+    };
+"Refract With One Step Left")]
+#[test_case(
+    Params {
+        swapper: "entry_point".to_string(),
+        contract_balances: vec![Coin::new(1100, "p:uatom:30Sep2024")],
+        reply_id: REFRACT_REPLY_ID,
+        swap_steps: vec![
+            SwapExecutionStep::Refract {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_out: "y:uatom:30Sep2024".to_string(),
+            },
+            SwapExecutionStep::Merge {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_in: "y:uatom:30Sep2024".to_string(),
+            },
+        ],
+        min_amount_out: None,
+        response: MsgRefractResponse {
+            p_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "p:uatom:30Sep2024".to_string()}),
+            y_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "y:uatom:30Sep2024".to_string()}),
+        }.into(),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgMerge {
+                    creator: "swap_contract_address".to_string(),
+                    c_asset_denom: "c:uatom".to_string(),
+                    maturity: "30Sep2024".to_string(),
+                    amount: "1100".to_string(),
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "c:uatom".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "".to_string(),
+        expected_stored_steps: vec![],
+    };
+"Refract With The Merge Step Left")]
+#[test_case(
+    Params {
+        swapper: "entry_point".to_string(),
+        contract_balances: vec![Coin::new(1000, "p:uatom:30Sep2024")],
+        reply_id: REFRACT_REPLY_ID,
+        swap_steps: vec![
+            SwapExecutionStep::Refract {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_out: "y:uatom:30Sep2024".to_string(),
+            },
+            SwapExecutionStep::Merge {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_in: "y:uatom:30Sep2024".to_string(),
+            },
+        ],
+        min_amount_out: None,
+        response: MsgRefractResponse {
+            p_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "p:uatom:30Sep2024".to_string()}),
+            y_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "y:uatom:30Sep2024".to_string()}),
+        }.into(),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgMerge {
+                    creator: "swap_contract_address".to_string(),
+                    c_asset_denom: "c:uatom".to_string(),
+                    maturity: "30Sep2024".to_string(),
+                    amount: "1000".to_string(),
+                }.into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "c:uatom".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "".to_string(),
+        expected_stored_steps: vec![],
+    };
+"Refract With The Merge Step Left And Less Of The Sibling Token")]
+#[test_case(
+    Params {
+        swapper: "entry_point".to_string(),
+        contract_balances: vec![],
+        reply_id: MERGE_REPLY_ID,
+        swap_steps: vec![
+            SwapExecutionStep::Merge {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_in: "p:uatom:30Sep2024".to_string(),
+            },
+            SwapExecutionStep::Redeem {
+                host_chain_id: "uatom".to_string(),
+                transfer_channel: "channel-0".to_string(),
+                token_out: "ibc/uatom".to_string(),
+            },
+            SwapExecutionStep::Swap {
+                swap_steps: vec![
+                    SwapStep {
+                        pool_id: 2,
+                        token_in: "ibc/uatom".to_string(),
+                        token_out: "ibc/uusdc".to_string(),
+                        amount: None,
+                    }
+                ],
+            },
+        ],
+        min_amount_out: None,
+        response: MsgMergeResponse {
+            c_amount: Some(CosmosCoin {amount: "900".to_string(), denom: "c:uatom".to_string()}),
+        }.into(),
+        expected_messages: vec![
+            SubMsg {
+                id: INSTANT_UNSTAKE_REPLY_ID,
+                msg: MsgInstantUnstake {
+                    creator: "swap_contract_address".to_string(),
+                    host_chain: "uatom".to_string(),
+                    transfer_channel: "channel-0".to_string(),
+                    min_c_amount: "900".to_string(),
+                    max_c_amount: "900".to_string(),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Success,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "entry_point".to_string(),
+        expected_stored_steps: vec![
+            SwapExecutionStep::Redeem {
+                host_chain_id: "uatom".to_string(),
+                transfer_channel: "channel-0".to_string(),
+                token_out: "ibc/uatom".to_string(),
+            },
+            SwapExecutionStep::Swap {
+                swap_steps: vec![
+                    SwapStep {
+                        pool_id: 2,
+                        token_in: "ibc/uatom".to_string(),
+                        token_out: "ibc/uusdc".to_string(),
+                        amount: None,
+                    }
+                ],
+            },
+        ],
+    };
+"Merge With Many Steps Left")]
+#[test_case(
+    Params {
+        swapper: "entry_point".to_string(),
+        contract_balances: vec![],
+        reply_id: REFRACT_REPLY_ID,
+        swap_steps: vec![
+            SwapExecutionStep::Refract {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_out: "y:uatom:30Sep2024".to_string(),
+            },
+            SwapExecutionStep::Merge {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_in: "y:uatom:30Sep2024".to_string(),
+            },
+        ],
+        min_amount_out: None,
+        response: MsgRefractResponse {
+            p_amount: Some(CosmosCoin {amount: "1100".to_string(), denom: "p:uatom:30Sep2024".to_string()}),
+            y_amount: None,
+        }.into(),
+        expected_messages: vec![],
+        expected_error_string: "expected y:uatom:30Sep2024 amount in refract response".to_string(),
+        expected_stored_swapper: "".to_string(),
+        expected_stored_steps: vec![],
+    };
+"Refract Response Without The Refracted Token Out - Expect Error")]
 fn test_execute_reply(params: Params) -> ContractResult<()> {
     // Create mock dependencies
     let mut deps = mock_dependencies();
@@ -198,6 +552,10 @@ fn test_execute_reply(params: Params) -> ContractResult<()> {
     let mut env = mock_env();
     env.contract.address = Addr::unchecked("swap_contract_address");
 
+    // Set the contract balances
+    deps.querier
+        .update_balance("swap_contract_address", params.contract_balances);
+
     // Fill the storage
     ENTRY_POINT_CONTRACT_ADDRESS.save(deps.as_mut().storage, &Addr::unchecked("entry_point"))?;
     IN_PROGRESS_SWAP_OPERATIONS.save(deps.as_mut().storage, &VecDeque::from(params.swap_steps))?;
@@ -205,6 +563,9 @@ fn test_execute_reply(params: Params) -> ContractResult<()> {
         deps.as_mut().storage,
         &Addr::unchecked(params.swapper.as_str()),
     )?;
+    if let Some(min_amount_out) = params.min_amount_out {
+        IN_PROGRESS_SWAP_MIN_AMOUNT_OUT.save(deps.as_mut().storage, &min_amount_out)?;
+    }
 
     // Call execute_swap with the given test parameters
     let res = contract::reply(
@@ -254,6 +615,19 @@ fn test_execute_reply(params: Params) -> ContractResult<()> {
                 // Assert no address is stored
                 assert!(IN_PROGRESS_SWAP_SENDER.load(deps.as_ref().storage).is_err());
             }
+
+            // Assert the min amount out is only stored while there are steps left
+            assert_eq!(
+                IN_PROGRESS_SWAP_MIN_AMOUNT_OUT.may_load(deps.as_ref().storage)?,
+                if IN_PROGRESS_SWAP_OPERATIONS
+                    .may_load(deps.as_ref().storage)?
+                    .is_some()
+                {
+                    params.min_amount_out
+                } else {
+                    None
+                }
+            );
         }
         Err(err) => {
             // Assert the test expected an error
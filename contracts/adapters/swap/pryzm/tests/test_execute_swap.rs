@@ -4,13 +4,14 @@ use cosmwasm_std::{
     to_json_binary, Addr, Coin,
     ReplyOn::Never,
     ReplyOn::Success,
-    SubMsg, WasmMsg,
+    SubMsg, Uint128, WasmMsg,
 };
 #[allow(unused_imports)]
 use pryzm_std::types::{
     cosmos::base::v1beta1::Coin as CosmosCoin,
     pryzm::amm::v1::{MsgBatchSwap, SwapStep, SwapType},
     pryzm::icstaking::v1::MsgStake,
+    pryzm::refractor::v1::MsgRefract,
 };
 use std::collections::VecDeque;
 use test_case::test_case;
@@ -22,15 +23,18 @@ use skip_go_swap_adapter_pryzm::execution::SwapExecutionStep;
 use skip_go_swap_adapter_pryzm::state::{IN_PROGRESS_SWAP_OPERATIONS, IN_PROGRESS_SWAP_SENDER};
 #[allow(unused_imports)]
 use skip_go_swap_adapter_pryzm::{
-    contract, error::ContractResult, reply_id::BATCH_SWAP_REPLY_ID, reply_id::STAKE_REPLY_ID,
-    state::ENTRY_POINT_CONTRACT_ADDRESS,
+    contract, error::ContractResult, reply_id::BATCH_SWAP_REPLY_ID, reply_id::REFRACT_REPLY_ID,
+    reply_id::STAKE_REPLY_ID, state::ENTRY_POINT_CONTRACT_ADDRESS,
 };
 /*
 Test Cases:
 
 Expect Success
     - One Swap Operation
+    - One Swap Operation With Min Amount Out
     - Multiple Swap Operations
+    - First Step Liquid Staking
+    - First Step Refraction (Refracting into the yield token sold on the AMM)
     - No Swap Operations (This is prevented in the entry point contract)
 
 Expect Error
@@ -46,6 +50,7 @@ struct Params {
     caller: String,
     info_funds: Vec<Coin>,
     swap_operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
     expected_messages: Vec<SubMsg>,
     expected_error_string: String,
     expected_stored_swapper: String,
@@ -65,6 +70,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: 0,
@@ -106,6 +112,60 @@ struct Params {
         expected_stored_steps: vec![],
     };
 "One Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "pr")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "amm:1".to_string(),
+                denom_in: "pr".to_string(),
+                denom_out: "ibc/uusdc".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: Some(Uint128::new(90)),
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: MsgBatchSwap {
+                    creator: "swap_contract_address".to_string(),
+                    swap_type: SwapType::GivenIn.into(),
+                    max_amounts_in: vec![CosmosCoin {amount: "100".to_string(), denom: "pr".to_string()}],
+                    min_amounts_out: vec![CosmosCoin {amount: "90".to_string(), denom: "ibc/uusdc".to_string()}],
+                    steps: vec![
+                        SwapStep {
+                            pool_id: 1,
+                            token_in: "pr".to_string(),
+                            token_out: "ibc/uusdc".to_string(),
+                            amount: Some("100".to_string()),
+                        }
+                    ]
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_contract_address".to_string(),
+                    msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                        return_denom: "ibc/uusdc".to_string(),
+                        swapper: Addr::unchecked("entry_point"),
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "".to_string(),
+        expected_stored_steps: vec![],
+    };
+"One Swap Operation With Min Amount Out")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -136,6 +196,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: BATCH_SWAP_REPLY_ID,
@@ -224,6 +285,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![
             SubMsg {
                 id: STAKE_REPLY_ID,
@@ -258,11 +320,68 @@ struct Params {
         ],
     };
 "First Step Liquid Staking")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(1000, "c:uatom")],
+        swap_operations: vec![
+            SwapOperation {
+                pool: "refractor:30Sep2024".to_string(),
+                denom_in: "c:uatom".to_string(),
+                denom_out: "y:uatom:30Sep2024".to_string(),
+                interface: None,
+            },
+            SwapOperation {
+                pool: "amm:5".to_string(),
+                denom_in: "y:uatom:30Sep2024".to_string(),
+                denom_out: "ibc/uusdc".to_string(),
+                interface: None,
+            }
+        ],
+        min_amount_out: None,
+        expected_messages: vec![
+            SubMsg {
+                id: REFRACT_REPLY_ID,
+                msg: MsgRefract {
+                    creator: "swap_contract_address".to_string(),
+                    amount: Some(CosmosCoin {
+                        amount: "1000".to_string(),
+                        denom: "c:uatom".to_string(),
+                    }),
+                    maturity: "30Sep2024".to_string(),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Success,
+            },
+        ],
+        expected_error_string: "".to_string(),
+        expected_stored_swapper: "entry_point".to_string(),
+        expected_stored_steps: vec![
+            SwapExecutionStep::Refract {
+                host_chain_id: "uatom".to_string(),
+                maturity: "30Sep2024".to_string(),
+                token_out: "y:uatom:30Sep2024".to_string(),
+            },
+            SwapExecutionStep::Swap {
+                swap_steps: vec![
+                    SwapStep {
+                        pool_id: 5,
+                        token_in: "y:uatom:30Sep2024".to_string(),
+                        token_out: "ibc/uusdc".to_string(),
+                        amount: None,
+                    },
+                ],
+            },
+        ],
+    };
+"First Step Refraction")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
         info_funds: vec![Coin::new(100, "pr")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "swap_operations cannot be empty".to_string(),
         expected_stored_swapper: "".to_string(),
@@ -281,6 +400,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "No funds sent".to_string(),
         expected_stored_swapper: "".to_string(),
@@ -302,6 +422,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Sent more than one denomination".to_string(),
         expected_stored_swapper: "".to_string(),
@@ -320,6 +441,7 @@ struct Params {
                 interface: None,
             }
         ],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "provided pool string is not a valid swap route".to_string(),
         expected_stored_swapper: "".to_string(),
@@ -331,6 +453,7 @@ struct Params {
         caller: "random".to_string(),
         info_funds: vec![Coin::new(100, "pr")],
         swap_operations: vec![],
+        min_amount_out: None,
         expected_messages: vec![],
         expected_error_string: "Unauthorized".to_string(),
         expected_stored_swapper: "".to_string(),
@@ -361,6 +484,7 @@ fn test_execute_swap(params: Params) -> ContractResult<()> {
         info,
         ExecuteMsg::Swap {
             operations: params.swap_operations.clone(),
+            min_amount_out: params.min_amount_out,
         },
     );
 
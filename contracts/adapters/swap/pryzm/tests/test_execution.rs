@@ -1,8 +1,9 @@
-use cosmwasm_std::{coin, CosmosMsg};
+use cosmwasm_std::{coin, CosmosMsg, Uint128};
 use pryzm_std::types::cosmos::base::v1beta1::Coin as CosmosCoin;
 use pryzm_std::types::pryzm::{
     amm::v1::{MsgBatchSwap, SwapStep, SwapType},
-    icstaking::v1::MsgStake,
+    icstaking::v1::{MsgInstantUnstake, MsgStake},
+    refractor::v1::{MsgMerge, MsgRefract},
 };
 
 use skip::swap::SwapOperation;
@@ -60,6 +61,33 @@ fn test_execution_step_return_denom() {
     };
     assert!(step.get_return_denom().is_ok());
     assert_eq!("c:uatom", step.get_return_denom().unwrap());
+
+    // redeem step
+    let step = SwapExecutionStep::Redeem {
+        host_chain_id: "uatom".to_string(),
+        transfer_channel: "channel-0".to_string(),
+        token_out: "ibc/uatom".to_string(),
+    };
+    assert!(step.get_return_denom().is_ok());
+    assert_eq!("ibc/uatom", step.get_return_denom().unwrap());
+
+    // refract step
+    let step = SwapExecutionStep::Refract {
+        host_chain_id: "uatom".to_string(),
+        maturity: "30Sep2024".to_string(),
+        token_out: "y:uatom:30Sep2024".to_string(),
+    };
+    assert!(step.get_return_denom().is_ok());
+    assert_eq!("y:uatom:30Sep2024", step.get_return_denom().unwrap());
+
+    // merge step
+    let step = SwapExecutionStep::Merge {
+        host_chain_id: "uatom".to_string(),
+        maturity: "30Sep2024".to_string(),
+        token_in: "p:uatom:30Sep2024".to_string(),
+    };
+    assert!(step.get_return_denom().is_ok());
+    assert_eq!("c:uatom", step.get_return_denom().unwrap());
 }
 
 #[test]
@@ -69,7 +97,7 @@ fn test_execution_step_cosmos_msg() {
     // empty swap steps
     let step = SwapExecutionStep::Swap { swap_steps: vec![] };
     assert!(step
-        .to_cosmos_msg(address.to_string(), coin(1000, "a"))
+        .to_cosmos_msg(address.to_string(), coin(1000, "a"), None)
         .is_err());
 
     // single step Swap type step
@@ -81,7 +109,7 @@ fn test_execution_step_cosmos_msg() {
             amount: None,
         }],
     };
-    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "a"));
+    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "a"), None);
     assert!(result.is_ok());
     assert_eq!(
         <MsgBatchSwap as Into<CosmosMsg>>::into(MsgBatchSwap {
@@ -105,6 +133,35 @@ fn test_execution_step_cosmos_msg() {
         result.unwrap()
     );
 
+    // single step Swap type step with min amount out
+    let result = step.to_cosmos_msg(
+        address.to_string(),
+        coin(1000, "a"),
+        Some(Uint128::new(900)),
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        <MsgBatchSwap as Into<CosmosMsg>>::into(MsgBatchSwap {
+            creator: address.to_string(),
+            swap_type: SwapType::GivenIn.into(),
+            max_amounts_in: vec![CosmosCoin {
+                amount: "1000".to_string(),
+                denom: "a".to_string()
+            }],
+            min_amounts_out: vec![CosmosCoin {
+                amount: "900".to_string(),
+                denom: "b".to_string()
+            }],
+            steps: vec![SwapStep {
+                pool_id: 1,
+                token_in: "a".to_string(),
+                token_out: "b".to_string(),
+                amount: "1000".to_string().into()
+            }],
+        }),
+        result.unwrap()
+    );
+
     // multistep Swap type step
     let step = SwapExecutionStep::Swap {
         swap_steps: vec![
@@ -128,7 +185,7 @@ fn test_execution_step_cosmos_msg() {
             },
         ],
     };
-    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "a"));
+    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "a"), None);
     assert!(result.is_ok());
     assert_eq!(
         <MsgBatchSwap as Into<CosmosMsg>>::into(MsgBatchSwap {
@@ -166,12 +223,16 @@ fn test_execution_step_cosmos_msg() {
         result.unwrap()
     );
 
-    // stake step
+    // stake step, which ignores the min amount out
     let step = SwapExecutionStep::Stake {
         host_chain_id: "uatom".to_string(),
         transfer_channel: "channel-0".to_string(),
     };
-    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "uatom"));
+    let result = step.to_cosmos_msg(
+        address.to_string(),
+        coin(1000, "uatom"),
+        Some(Uint128::new(900)),
+    );
     assert!(result.is_ok());
     assert_eq!(
         <MsgStake as Into<CosmosMsg>>::into(MsgStake {
@@ -182,6 +243,67 @@ fn test_execution_step_cosmos_msg() {
         }),
         result.unwrap()
     );
+
+    // redeem step, which redeems exactly the cAsset in and ignores the min amount out
+    let step = SwapExecutionStep::Redeem {
+        host_chain_id: "uatom".to_string(),
+        transfer_channel: "channel-0".to_string(),
+        token_out: "ibc/uatom".to_string(),
+    };
+    let result = step.to_cosmos_msg(
+        address.to_string(),
+        coin(1000, "c:uatom"),
+        Some(Uint128::new(900)),
+    );
+    assert!(result.is_ok());
+    assert_eq!(
+        <MsgInstantUnstake as Into<CosmosMsg>>::into(MsgInstantUnstake {
+            creator: address.to_string(),
+            host_chain: "uatom".to_string(),
+            transfer_channel: "channel-0".to_string(),
+            min_c_amount: "1000".to_string(),
+            max_c_amount: "1000".to_string(),
+        }),
+        result.unwrap()
+    );
+
+    // refract step
+    let step = SwapExecutionStep::Refract {
+        host_chain_id: "uatom".to_string(),
+        maturity: "30Sep2024".to_string(),
+        token_out: "y:uatom:30Sep2024".to_string(),
+    };
+    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "c:uatom"), None);
+    assert!(result.is_ok());
+    assert_eq!(
+        <MsgRefract as Into<CosmosMsg>>::into(MsgRefract {
+            creator: address.to_string(),
+            amount: Some(CosmosCoin {
+                amount: "1000".to_string(),
+                denom: "c:uatom".to_string()
+            }),
+            maturity: "30Sep2024".to_string(),
+        }),
+        result.unwrap()
+    );
+
+    // merge step
+    let step = SwapExecutionStep::Merge {
+        host_chain_id: "uatom".to_string(),
+        maturity: "30Sep2024".to_string(),
+        token_in: "p:uatom:30Sep2024".to_string(),
+    };
+    let result = step.to_cosmos_msg(address.to_string(), coin(1000, "p:uatom:30Sep2024"), None);
+    assert!(result.is_ok());
+    assert_eq!(
+        <MsgMerge as Into<CosmosMsg>>::into(MsgMerge {
+            creator: address.to_string(),
+            c_asset_denom: "c:uatom".to_string(),
+            maturity: "30Sep2024".to_string(),
+            amount: "1000".to_string(),
+        }),
+        result.unwrap()
+    );
 }
 
 #[test]
@@ -348,6 +470,126 @@ fn test_extract_execution_step() {
         vec.pop_front().unwrap().clone()
     );
 
+    // redeem, refract and merge steps
+    let result = extract_execution_steps(vec![
+        SwapOperation {
+            pool: "icstaking:uatom:channel-0".to_string(),
+            denom_in: "c:uatom".to_string(),
+            denom_out: "uatom".to_string(),
+            interface: None,
+        },
+        SwapOperation {
+            pool: "icstaking:uatom:channel-0".to_string(),
+            denom_in: "uatom".to_string(),
+            denom_out: "c:uatom".to_string(),
+            interface: None,
+        },
+        SwapOperation {
+            pool: "refractor:30Sep2024".to_string(),
+            denom_in: "c:uatom".to_string(),
+            denom_out: "p:uatom:30Sep2024".to_string(),
+            interface: None,
+        },
+        SwapOperation {
+            pool: "refractor:30Sep2024".to_string(),
+            denom_in: "p:uatom:30Sep2024".to_string(),
+            denom_out: "c:uatom".to_string(),
+            interface: None,
+        },
+    ]);
+    assert!(result.is_ok());
+    let mut vec = result.unwrap();
+    assert_eq!(4, vec.len());
+    assert_eq!(
+        SwapExecutionStep::Redeem {
+            host_chain_id: "uatom".to_string(),
+            transfer_channel: "channel-0".to_string(),
+            token_out: "uatom".to_string(),
+        },
+        vec.pop_front().unwrap().clone()
+    );
+    assert_eq!(
+        SwapExecutionStep::Stake {
+            host_chain_id: "uatom".to_string(),
+            transfer_channel: "channel-0".to_string(),
+        },
+        vec.pop_front().unwrap().clone()
+    );
+    assert_eq!(
+        SwapExecutionStep::Refract {
+            host_chain_id: "uatom".to_string(),
+            maturity: "30Sep2024".to_string(),
+            token_out: "p:uatom:30Sep2024".to_string(),
+        },
+        vec.pop_front().unwrap().clone()
+    );
+    assert_eq!(
+        SwapExecutionStep::Merge {
+            host_chain_id: "uatom".to_string(),
+            maturity: "30Sep2024".to_string(),
+            token_in: "p:uatom:30Sep2024".to_string(),
+        },
+        vec.pop_front().unwrap().clone()
+    );
+
+    // refraction into the yield token sold on the amm, the principal token is left
+    // in the contract to be transferred back with the swap output
+    let result = extract_execution_steps(vec![
+        SwapOperation {
+            pool: "refractor:30Sep2024".to_string(),
+            denom_in: "c:uatom".to_string(),
+            denom_out: "y:uatom:30Sep2024".to_string(),
+            interface: None,
+        },
+        SwapOperation {
+            pool: "amm:5".to_string(),
+            denom_in: "y:uatom:30Sep2024".to_string(),
+            denom_out: "ibc/uusdc".to_string(),
+            interface: None,
+        },
+    ]);
+    assert!(result.is_ok());
+    let mut vec = result.unwrap();
+    assert_eq!(2, vec.len());
+    assert_eq!(
+        SwapExecutionStep::Refract {
+            host_chain_id: "uatom".to_string(),
+            maturity: "30Sep2024".to_string(),
+            token_out: "y:uatom:30Sep2024".to_string(),
+        },
+        vec.pop_front().unwrap().clone()
+    );
+    assert_eq!(
+        SwapExecutionStep::Swap {
+            swap_steps: vec![SwapStep {
+                pool_id: 5,
+                token_in: "y:uatom:30Sep2024".to_string(),
+                token_out: "ibc/uusdc".to_string(),
+                amount: None,
+            }],
+        },
+        vec.pop_front().unwrap().clone()
+    );
+
+    // merge without a refraction before it
+    let result = extract_execution_steps(vec![SwapOperation {
+        pool: "refractor:30Sep2024".to_string(),
+        denom_in: "y:uatom:30Sep2024".to_string(),
+        denom_out: "c:uatom".to_string(),
+        interface: None,
+    }]);
+    assert!(result.is_ok());
+    let mut vec = result.unwrap();
+    assert_eq!(1, vec.len());
+    assert_eq!(
+        SwapExecutionStep::Merge {
+            host_chain_id: "uatom".to_string(),
+            maturity: "30Sep2024".to_string(),
+            token_in: "y:uatom:30Sep2024".to_string(),
+        },
+        vec.pop_front().unwrap().clone()
+    );
+
     // invalid pools
     let result = extract_execution_steps(vec![SwapOperation {
         pool: "amm:invalid".to_string(),
@@ -387,8 +629,8 @@ fn test_extract_execution_step() {
 
     let result = extract_execution_steps(vec![SwapOperation {
         pool: "icstaking:uatom:channel-0".to_string(),
-        denom_in: "c:uatom".to_string(),
-        denom_out: "uatom".to_string(),
+        denom_in: "c:uosmo".to_string(),
+        denom_out: "uosmo".to_string(),
         interface: None,
     }]);
     assert!(result.is_err());
@@ -432,4 +674,52 @@ fn test_extract_execution_step() {
         result.err().unwrap(),
         ContractError::InvalidPool { .. }
     ));
+
+    let result = extract_execution_steps(vec![SwapOperation {
+        pool: "refractor:".to_string(),
+        denom_in: "c:uatom".to_string(),
+        denom_out: "p:uatom:30Sep2024".to_string(),
+        interface: None,
+    }]);
+    assert!(result.is_err());
+    assert!(matches!(
+        result.err().unwrap(),
+        ContractError::InvalidPool { .. }
+    ));
+
+    let result = extract_execution_steps(vec![SwapOperation {
+        pool: "refractor:31Dec2024".to_string(),
+        denom_in: "c:uatom".to_string(),
+        denom_out: "p:uatom:30Sep2024".to_string(),
+        interface: None,
+    }]);
+    assert!(result.is_err());
+    assert!(matches!(
+        result.err().unwrap(),
+        ContractError::InvalidPool { .. }
+    ));
+
+    let result = extract_execution_steps(vec![SwapOperation {
+        pool: "refractor:30Sep2024".to_string(),
+        denom_in: "y:uosmo:30Sep2024".to_string(),
+        denom_out: "c:uatom".to_string(),
+        interface: None,
+    }]);
+    assert!(result.is_err());
+    assert!(matches!(
+        result.err().unwrap(),
+        ContractError::InvalidPool { .. }
+    ));
+
+    let result = extract_execution_steps(vec![SwapOperation {
+        pool: "refractor:30Sep2024".to_string(),
+        denom_in: "p:uatom:30Sep2024".to_string(),
+        denom_out: "y:uatom:30Sep2024".to_string(),
+        interface: None,
+    }]);
+    assert!(result.is_err());
+    assert!(matches!(
+        result.err().unwrap(),
+        ContractError::InvalidPool { .. }
+    ));
 }
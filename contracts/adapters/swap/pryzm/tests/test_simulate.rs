@@ -9,6 +9,8 @@ use pryzm_std::types::pryzm::amm::v1::{
     QuerySpotPriceResponse, SwapStep, SwapType,
 };
 use pryzm_std::types::pryzm::icstaking::v1::{
+    HostChainState, QueryGetHostChainStateRequest, QueryGetHostChainStateResponse,
+    QuerySimulateInstantUnstakeRequest, QuerySimulateInstantUnstakeResponse,
     QuerySimulateStakeRequest, QuerySimulateStakeResponse,
 };
 
@@ -518,9 +520,216 @@ fn test_simulate_smart_swap() {
     );
 }
 
+#[test]
+fn test_simulate_redeem_and_refractor() {
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: setup_mocks(),
+        custom_query_type: PhantomData,
+    };
+
+    // valid instant redemption with spot price
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(coin(1000, "c:uatom")),
+            swap_operations: vec![SwapOperation {
+                pool: "icstaking:uatom:channel-0".to_string(),
+                denom_in: "c:uatom".to_string(),
+                denom_out: "ibc/uatom".to_string(),
+                interface: None,
+            }],
+            include_spot_price: true,
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<SimulateSwapExactAssetInResponse> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let response = output.unwrap();
+    assert_eq!("ibc/uatom", response.asset_out.denom());
+    assert_eq!(1050, response.asset_out.amount().u128());
+    assert_eq!(
+        Decimal::from_str("1.05").unwrap(),
+        response.spot_price.unwrap()
+    );
+
+    // instant redemption can not be simulated given the amount out
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out: Asset::Native(coin(1050, "ibc/uatom")),
+            swap_operations: vec![SwapOperation {
+                pool: "icstaking:uatom:channel-0".to_string(),
+                denom_in: "c:uatom".to_string(),
+                denom_out: "ibc/uatom".to_string(),
+                interface: None,
+            }],
+        },
+    );
+    assert!(res.is_err());
+    assert!(matches!(
+        res.err().unwrap(),
+        ContractError::RedeemExactOutUnsupported
+    ));
+
+    // valid refraction, minting yield tokens at the cAsset exchange rate
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(coin(1000, "c:uatom")),
+            swap_operations: vec![SwapOperation {
+                pool: "refractor:30Sep2024".to_string(),
+                denom_in: "c:uatom".to_string(),
+                denom_out: "y:uatom:30Sep2024".to_string(),
+                interface: None,
+            }],
+            include_spot_price: true,
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<SimulateSwapExactAssetInResponse> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let response = output.unwrap();
+    assert_eq!("y:uatom:30Sep2024", response.asset_out.denom());
+    assert_eq!(1100, response.asset_out.amount().u128());
+    assert_eq!(
+        Decimal::from_str("1.1").unwrap(),
+        response.spot_price.unwrap()
+    );
+
+    // valid merge
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(coin(1100, "p:uatom:30Sep2024")),
+            swap_operations: vec![SwapOperation {
+                pool: "refractor:30Sep2024".to_string(),
+                denom_in: "p:uatom:30Sep2024".to_string(),
+                denom_out: "c:uatom".to_string(),
+                interface: None,
+            }],
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<Asset> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let token_out = output.unwrap();
+    assert_eq!("c:uatom", token_out.denom());
+    assert_eq!(1000, token_out.amount().u128());
+
+    // valid refraction given the amount out, the cAsset in is rounded up
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out: Asset::Native(coin(1101, "y:uatom:30Sep2024")),
+            swap_operations: vec![SwapOperation {
+                pool: "refractor:30Sep2024".to_string(),
+                denom_in: "c:uatom".to_string(),
+                denom_out: "y:uatom:30Sep2024".to_string(),
+                interface: None,
+            }],
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<Asset> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let token_in = output.unwrap();
+    assert_eq!("c:uatom", token_in.denom());
+    assert_eq!(1001, token_in.amount().u128());
+
+    // valid merge given the amount out
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out: Asset::Native(coin(1000, "c:uatom")),
+            swap_operations: vec![SwapOperation {
+                pool: "refractor:30Sep2024".to_string(),
+                denom_in: "p:uatom:30Sep2024".to_string(),
+                denom_out: "c:uatom".to_string(),
+                interface: None,
+            }],
+            include_spot_price: true,
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<SimulateSwapExactAssetOutResponse> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let response = output.unwrap();
+    assert_eq!("p:uatom:30Sep2024", response.asset_in.denom());
+    assert_eq!(1100, response.asset_in.amount().u128());
+    assert_eq!(
+        Decimal::from_str("0.909090909090909090").unwrap(),
+        response.spot_price.unwrap()
+    );
+
+    // valid refraction merged back, minting yield tokens at the cAsset exchange rate
+    // and merging them with the principal tokens back into the cAsset
+    let refract_and_merge_operations = vec![
+        SwapOperation {
+            pool: "refractor:30Sep2024".to_string(),
+            denom_in: "c:uatom".to_string(),
+            denom_out: "y:uatom:30Sep2024".to_string(),
+            interface: None,
+        },
+        SwapOperation {
+            pool: "refractor:30Sep2024".to_string(),
+            denom_in: "y:uatom:30Sep2024".to_string(),
+            denom_out: "c:uatom".to_string(),
+            interface: None,
+        },
+    ];
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in: Asset::Native(coin(1000, "c:uatom")),
+            swap_operations: refract_and_merge_operations.clone(),
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<Asset> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let token_out = output.unwrap();
+    assert_eq!("c:uatom", token_out.denom());
+    assert_eq!(1000, token_out.amount().u128());
+
+    // valid refraction merged back given the amount out
+    let res = contract::query(
+        deps.as_mut().as_ref(),
+        mock_env(),
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out: Asset::Native(coin(1000, "c:uatom")),
+            swap_operations: refract_and_merge_operations,
+        },
+    );
+    assert!(res.is_ok());
+    let output: StdResult<Asset> = from_json(res.unwrap());
+    assert!(output.is_ok());
+    let token_in = output.unwrap();
+    assert_eq!("c:uatom", token_in.denom());
+    assert_eq!(1000, token_in.amount().u128());
+}
+
 fn setup_mocks() -> MockQuerier {
     let mut querier = MockQuerier::new();
 
+    mock_instant_unstake(&mut querier, "uatom", "channel-0", "1000", "1050");
+    mock_instant_unstake(
+        &mut querier,
+        "uatom",
+        "channel-0",
+        "1000000000000000000",
+        "1050000000000000000",
+    );
+    mock_host_chain_state(&mut querier, "uatom", "1.1");
+
     mock_stake_given_in(&mut querier, "uatom", "channel-0", "1000", "950");
     mock_stake_given_out(&mut querier, "uatom", "channel-0", "950", "1000");
     mock_stake_given_in(
@@ -697,6 +906,47 @@ fn mock_stake_given_out(
     );
 }
 
+fn mock_instant_unstake(
+    querier: &mut MockQuerier,
+    host_chain: &str,
+    channel: &str,
+    c_amount: &str,
+    amount_out: &str,
+) {
+    querier.mock_query(
+        QuerySimulateInstantUnstakeRequest {
+            host_chain: host_chain.to_string(),
+            transfer_channel: channel.to_string(),
+            min_c_amount: c_amount.to_string(),
+            max_c_amount: c_amount.to_string(),
+        }
+        .into(),
+        &QuerySimulateInstantUnstakeResponse {
+            amount: Some(CosmosCoin {
+                amount: amount_out.to_string(),
+                denom: format!("ibc/{}", host_chain),
+            }),
+            ..Default::default()
+        },
+    );
+}
+
+fn mock_host_chain_state(querier: &mut MockQuerier, host_chain: &str, exchange_rate: &str) {
+    querier.mock_query(
+        QueryGetHostChainStateRequest {
+            host_chain_id: host_chain.to_string(),
+        }
+        .into(),
+        &QueryGetHostChainStateResponse {
+            host_chain_state: Some(HostChainState {
+                host_chain_id: host_chain.to_string(),
+                exchange_rate: exchange_rate.to_string(),
+                ..Default::default()
+            }),
+        },
+    );
+}
+
 fn mock_batch_swap_given_in(
     querier: &mut MockQuerier,
     swap_steps: Vec<SwapStep>,
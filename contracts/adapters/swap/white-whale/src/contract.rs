@@ -11,10 +11,12 @@ use cw20::{Cw20Coin, Cw20ReceiveMsg};
 use cw_utils::one_coin;
 use skip::{
     asset::{get_current_asset_available, Asset},
+    error::SkipError,
     swap::{
-        execute_transfer_funds_back, get_ask_denom_for_routes, Cw20HookMsg, ExecuteMsg,
-        InstantiateMsg, MigrateMsg, QueryMsg, Route, SimulateSmartSwapExactAssetInResponse,
-        SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse, SwapOperation,
+        calculate_weighted_spot_price, execute_transfer_funds_back, get_ask_denom_for_routes,
+        CapabilitiesResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolIdentifier,
+        QueryMsg, Route, SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SlippageInterface, SwapOperation,
     },
 };
 use white_whale_std::pool_network::{
@@ -31,7 +33,7 @@ use white_whale_std::pool_network::{
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
-    unimplemented!()
+    Err(SkipError::MigrationUnsupported.into())
 }
 
 /////////////////
@@ -90,7 +92,10 @@ pub fn receive_cw20(
     info.sender = deps.api.addr_validate(&cw20_msg.sender)?;
 
     match from_json(&cw20_msg.msg)? {
-        Cw20HookMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        Cw20HookMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap(deps, env, info, operations, min_amount_out),
     }
 }
 
@@ -107,9 +112,12 @@ pub fn execute(
 ) -> ContractResult<Response> {
     match msg {
         ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
-        ExecuteMsg::Swap { operations } => {
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => {
             one_coin(&info)?;
-            execute_swap(deps, env, info, operations)
+            execute_swap(deps, env, info, operations, min_amount_out)
         }
         ExecuteMsg::TransferFundsBack {
             swapper,
@@ -121,12 +129,11 @@ pub fn execute(
             swapper,
             return_denom,
         )?),
-        ExecuteMsg::WhiteWhalePoolSwap { operation } => {
-            execute_white_whale_pool_swap(deps, env, info, operation)
-        }
-        _ => {
-            unimplemented!()
-        }
+        ExecuteMsg::WhiteWhalePoolSwap {
+            operation,
+            min_amount_out,
+        } => execute_white_whale_pool_swap(deps, env, info, operation, min_amount_out),
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
     }
 }
 
@@ -135,6 +142,7 @@ fn execute_swap(
     env: Env,
     info: MessageInfo,
     operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Get entry point contract address from storage
     let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
@@ -147,12 +155,18 @@ fn execute_swap(
     // Create a response object to return
     let mut response: Response = Response::new().add_attribute("action", "execute_swap");
 
-    // Add a white whale pool swap message to the response for each swap operation
-    for operation in &operations {
+    // Add a white whale pool swap message to the response for each swap operation,
+    // only the last swap operation's pool swap enforces the min amount out
+    for (i, operation) in operations.iter().enumerate() {
         let swap_msg = WasmMsg::Execute {
             contract_addr: env.contract.address.to_string(),
             msg: to_json_binary(&ExecuteMsg::WhiteWhalePoolSwap {
                 operation: operation.clone(),
+                min_amount_out: if i == operations.len() - 1 {
+                    min_amount_out
+                } else {
+                    None
+                },
             })?,
             funds: vec![],
         };
@@ -184,6 +198,7 @@ fn execute_white_whale_pool_swap(
     env: Env,
     info: MessageInfo,
     operation: SwapOperation,
+    min_amount_out: Option<Uint128>,
 ) -> ContractResult<Response> {
     // Ensure the caller is the contract itself
     if info.sender != env.contract.address {
@@ -198,17 +213,24 @@ fn execute_white_whale_pool_swap(
         return Err(ContractError::NoOfferAssetAmount);
     }
 
+    // Get the belief price and max spread the pool swap is executed with
+    let (belief_price, max_spread) = get_belief_price_and_max_spread(
+        offer_asset.amount(),
+        min_amount_out,
+        &operation.slippage_interface()?,
+    )?;
+
     // Create the whitewhale pool swap msg depending on the offer asset type
     let msg = match offer_asset {
         Asset::Native(_) => to_json_binary(&PairExecuteMsg::Swap {
             offer_asset: offer_asset.into_white_whale_asset(deps.api)?,
-            belief_price: None,
-            max_spread: Some(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+            belief_price,
+            max_spread: Some(max_spread),
             to: None,
         })?,
         Asset::Cw20(_) => to_json_binary(&PairCw20HookMsg::Swap {
-            belief_price: None,
-            max_spread: Some(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+            belief_price,
+            max_spread: Some(max_spread),
             to: None,
         })?,
     };
@@ -221,6 +243,54 @@ fn execute_white_whale_pool_swap(
         .add_attribute("action", "dispatch_white_whale_pool_swap"))
 }
 
+// Returns the belief price and max spread of a pool swap. With a min amount out, the
+// belief price is set so the pair's expected return is the min amount out (rounded up
+// so it is never stricter than it) and the max spread is zero, so the pair reverts any
+// swap returning less. If the swap operation's slippage interface also sets a belief
+// price, the stricter of the two is used: the one whose lowest accepted return is higher.
+// Without a min amount out, the swap operation's slippage interface is used.
+fn get_belief_price_and_max_spread(
+    offer_amount: Uint128,
+    min_amount_out: Option<Uint128>,
+    slippage_interface: &SlippageInterface,
+) -> ContractResult<(Option<Decimal>, Decimal)> {
+    let max_spread = get_max_spread(slippage_interface)?;
+
+    if let Some(min_amount_out) = min_amount_out.filter(|amount| !amount.is_zero()) {
+        if let Ok(mut min_out_belief_price) =
+            Decimal::checked_from_ratio(offer_amount, min_amount_out)
+        {
+            if min_amount_out * min_out_belief_price < offer_amount {
+                min_out_belief_price += Decimal::raw(1);
+            }
+
+            // The pair accepts returns down to (1 - max spread) of the return expected at
+            // the interface belief price, so the interface is stricter when its belief price
+            // is below the min amount out belief price scaled by (1 - max spread)
+            return match slippage_interface.belief_price {
+                Some(belief_price)
+                    if belief_price
+                        < min_out_belief_price * Decimal::one().saturating_sub(max_spread) =>
+                {
+                    Ok((Some(belief_price), max_spread))
+                }
+                _ => Ok((Some(min_out_belief_price), Decimal::zero())),
+            };
+        }
+    }
+
+    Ok((slippage_interface.belief_price, max_spread))
+}
+
+// Returns the max spread of the swap operation's slippage interface,
+// defaulting to the max allowed slippage if it is not set
+fn get_max_spread(slippage_interface: &SlippageInterface) -> ContractResult<Decimal> {
+    match slippage_interface.max_spread {
+        Some(max_spread) => Ok(max_spread),
+        None => Ok(MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?),
+    }
+}
+
 /////////////
 /// QUERY ///
 /////////////
@@ -286,10 +356,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
                 include_spot_price,
             )?)
         }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
     }
     .map_err(From::from)
 }
 
+// Returns the features supported by the white whale swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: true,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::ContractAddress],
+    }
+}
+
 // Queries the white whale pool contracts to simulate a swap exact amount in
 fn query_simulate_swap_exact_asset_in(
     deps: Deps,
@@ -471,14 +556,40 @@ fn query_simulate_smart_swap_exact_asset_in_with_metadata(
     Ok(response)
 }
 
-fn assert_max_spread(return_amount: Uint128, spread_amount: Uint128) -> ContractResult<()> {
-    let max_spread = MAX_ALLOWED_SLIPPAGE.parse::<Decimal>()?;
+fn assert_max_spread(
+    return_amount: Uint128,
+    spread_amount: Uint128,
+    max_spread: Decimal,
+) -> ContractResult<()> {
     if Decimal::from_ratio(spread_amount, return_amount + spread_amount) > max_spread {
         return Err(ContractError::MaxSpreadAssertion {});
     }
     Ok(())
 }
 
+// Asserts the return does not fall short of the return expected at the belief price
+// by more than the max spread, as the pair does when swapping with a belief price
+fn assert_belief_price(
+    offer_amount: Uint128,
+    return_amount: Uint128,
+    belief_price: Decimal,
+    max_spread: Decimal,
+) -> ContractResult<()> {
+    let Some(inverse_belief_price) = belief_price.inv() else {
+        return Err(ContractError::MaxSpreadAssertion {});
+    };
+
+    let expected_return = offer_amount * inverse_belief_price;
+    let spread_amount = expected_return.saturating_sub(return_amount);
+
+    if return_amount < expected_return
+        && Decimal::from_ratio(spread_amount, expected_return) > max_spread
+    {
+        return Err(ContractError::MaxSpreadAssertion {});
+    }
+    Ok(())
+}
+
 // Simulates a swap exact amount in request, returning the asset out and optionally the reverse simulation responses
 fn simulate_swap_exact_asset_in(
     deps: Deps,
@@ -500,8 +611,19 @@ fn simulate_swap_exact_asset_in(
                 },
             )?;
 
-            // Assert the operation does not exceed the max spread limit
-            assert_max_spread(res.return_amount, res.spread_amount)?;
+            // Assert the operation does not exceed the max spread limit, as the
+            // pair would given the swap operation's slippage interface
+            let slippage_interface = operation.slippage_interface()?;
+            let max_spread = get_max_spread(&slippage_interface)?;
+            match slippage_interface.belief_price {
+                Some(belief_price) => assert_belief_price(
+                    asset_out.amount(),
+                    res.return_amount,
+                    belief_price,
+                    max_spread,
+                )?,
+                None => assert_max_spread(res.return_amount, res.spread_amount, max_spread)?,
+            }
 
             if include_responses {
                 responses.push(res.clone());
@@ -538,8 +660,19 @@ fn simulate_swap_exact_asset_out(
                 },
             )?;
 
-            // Assert the operation does not exceed the max spread limit
-            assert_max_spread(res.offer_amount, res.spread_amount)?;
+            // Assert the operation does not exceed the max spread limit, as the
+            // pair would given the swap operation's slippage interface
+            let slippage_interface = operation.slippage_interface()?;
+            let max_spread = get_max_spread(&slippage_interface)?;
+            match slippage_interface.belief_price {
+                Some(belief_price) => assert_belief_price(
+                    res.offer_amount,
+                    asset_in_needed.amount(),
+                    belief_price,
+                    max_spread,
+                )?,
+                None => assert_max_spread(res.offer_amount, res.spread_amount, max_spread)?,
+            }
 
             if include_responses {
                 responses.push(res.clone());
@@ -592,23 +725,23 @@ fn calculate_weighted_spot_price_from_simulation_responses(
     routes: Vec<Route>,
     simulation_responses: Vec<Vec<SimulationResponse>>,
 ) -> ContractResult<Decimal> {
-    let spot_price = routes.into_iter().zip(simulation_responses).try_fold(
-        Decimal::zero(),
-        |curr_spot_price, (route, res)| -> ContractResult<Decimal> {
-            let route_spot_price = calculate_spot_price_from_simulation_responses(
-                deps,
-                asset_in.clone(),
-                route.operations,
-                res,
-            )?;
-
-            let weight = Decimal::from_ratio(route.offer_asset.amount(), asset_in.amount());
-
-            Ok(curr_spot_price + (route_spot_price * weight))
-        },
-    )?;
+    let route_spot_prices = routes
+        .into_iter()
+        .zip(simulation_responses)
+        .map(|(route, res)| {
+            Ok((
+                route.offer_asset.amount(),
+                calculate_spot_price_from_simulation_responses(
+                    deps,
+                    asset_in.clone(),
+                    route.operations,
+                    res,
+                )?,
+            ))
+        })
+        .collect::<ContractResult<Vec<_>>>()?;
 
-    Ok(spot_price)
+    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
 }
 
 // Calculate the spot price using simulation responses
@@ -0,0 +1,86 @@
+use cosmwasm_std::{
+    from_json, testing::MockQuerier, to_json_binary, ContractResult as SystemContractResult,
+    QuerierResult, SystemResult, Uint128, WasmQuery,
+};
+use skip::swap::{InstantiateMsg, SwapOperation};
+use skip_go_swap_adapter_testing::suite::{MockVenue, SwapAdapterSuite};
+use skip_go_swap_adapter_white_whale::contract::{execute, instantiate, query};
+use white_whale_std::pool_network::pair::{
+    QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse,
+};
+
+// Mocks white whale pair contracts that swap at a rate of 2 ask asset per offer asset
+fn mock_white_whale_pairs(querier: &mut MockQuerier) {
+    querier.update_wasm(|query: &WasmQuery| -> QuerierResult {
+        let WasmQuery::Smart { msg, .. } = query else {
+            panic!("Unsupported query: {:?}", query);
+        };
+
+        let res = match from_json(msg).unwrap() {
+            PairQueryMsg::Simulation { offer_asset } => to_json_binary(&SimulationResponse {
+                return_amount: offer_asset.amount * Uint128::new(2),
+                spread_amount: Uint128::zero(),
+                swap_fee_amount: Uint128::zero(),
+                protocol_fee_amount: Uint128::zero(),
+                burn_fee_amount: Uint128::zero(),
+            }),
+            PairQueryMsg::ReverseSimulation { ask_asset } => {
+                to_json_binary(&ReverseSimulationResponse {
+                    offer_amount: ask_asset.amount / Uint128::new(2),
+                    spread_amount: Uint128::zero(),
+                    swap_fee_amount: Uint128::zero(),
+                    protocol_fee_amount: Uint128::zero(),
+                    burn_fee_amount: Uint128::zero(),
+                })
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        };
+
+        SystemResult::Ok(SystemContractResult::Ok(res.unwrap()))
+    });
+}
+
+// Runs the swap adapter conformance suite against mocked white whale pairs
+#[test]
+fn test_conformance() {
+    SwapAdapterSuite {
+        instantiate: |deps, env, info, entry_point_contract_address| {
+            instantiate(
+                deps,
+                env,
+                info,
+                InstantiateMsg {
+                    entry_point_contract_address,
+                },
+            )
+        },
+        execute,
+        query,
+        venue: MockVenue {
+            mock_querier: mock_white_whale_pairs,
+            stargate_querier: None,
+            swap_operations: vec![
+                SwapOperation {
+                    pool: "pool_1".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "ua".to_string(),
+                    interface: None,
+                },
+                SwapOperation {
+                    pool: "pool_2".to_string(),
+                    denom_in: "ua".to_string(),
+                    denom_out: "un".to_string(),
+                    interface: None,
+                },
+            ],
+            cw20_swap_operations: Some(vec![SwapOperation {
+                pool: "pool_3".to_string(),
+                denom_in: "whale123".to_string(),
+                denom_out: "os".to_string(),
+                interface: None,
+            }]),
+            supports_exact_asset_out: true,
+        },
+    }
+    .run();
+}
@@ -0,0 +1,12 @@
+use cosmwasm_schema::write_api;
+use skip::swap::{
+    QueryMsg, WrapperExecuteMsg as ExecuteMsg, WrapperInstantiateMsg as InstantiateMsg,
+};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg
+    }
+}
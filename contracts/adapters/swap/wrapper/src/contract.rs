@@ -0,0 +1,501 @@
+use crate::{
+    error::{ContractError, ContractResult},
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, WRAPPER_PAIRS},
+};
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, Api, Binary, Decimal, Deps, DepsMut, Env, MessageInfo,
+    QueryRequest, Response, Storage, Uint128, WasmMsg, WasmQuery,
+};
+use cw2::set_contract_version;
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use cw_utils::one_coin;
+use skip::{
+    admin::assert_contract_admin,
+    asset::Asset,
+    error::SkipError,
+    swap::{
+        execute_transfer_funds_back, get_ask_denom_for_routes, CapabilitiesResponse, Cw20HookMsg,
+        ExecuteMsg, MigrateMsg, PoolIdentifier, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation, WrapperAction, WrapperExecuteMsg,
+        WrapperInstantiateMsg, WrapperPair, WrapperPairDenoms, WrapperPairsExecuteMsg, WrapperRate,
+    },
+};
+
+///////////////
+/// MIGRATE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    Err(SkipError::MigrationUnsupported.into())
+}
+
+/////////////////
+// INSTANTIATE //
+/////////////////
+
+// Contract name and version used for migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: WrapperInstantiateMsg,
+) -> ContractResult<Response> {
+    // Set contract version
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // Validate entry point contract address
+    let checked_entry_point_contract_address =
+        deps.api.addr_validate(&msg.entry_point_contract_address)?;
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
+
+    let mut response = Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute(
+            "entry_point_contract_address",
+            checked_entry_point_contract_address.to_string(),
+        );
+
+    // Validate and store the wrapper pairs
+    for pair in msg.pairs {
+        response = response.add_attribute(
+            "wrapper_pair",
+            save_wrapper_pair(deps.storage, deps.api, pair)?,
+        );
+    }
+
+    Ok(response)
+}
+
+/////////////
+// RECEIVE //
+/////////////
+
+// Receive is the main entry point for the contract to
+// receive cw20 tokens and execute the swap
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    mut info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> ContractResult<Response> {
+    let sent_asset = Asset::Cw20(Cw20Coin {
+        address: info.sender.to_string(),
+        amount: cw20_msg.amount,
+    });
+    sent_asset.validate(&deps, &env, &info)?;
+
+    // Set the sender to the originating address that triggered the cw20 send call
+    // This is later validated / enforced to be the entry point contract address
+    info.sender = deps.api.addr_validate(&cw20_msg.sender)?;
+
+    match from_json(&cw20_msg.msg)? {
+        Cw20HookMsg::Swap { operations, .. } => {
+            execute_swap(deps, env, info, sent_asset, operations)
+        }
+    }
+}
+
+///////////////
+/// EXECUTE ///
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: WrapperExecuteMsg,
+) -> ContractResult<Response> {
+    match msg {
+        WrapperExecuteMsg::SwapAdapter(msg) => execute_swap_adapter(deps, env, info, msg),
+        WrapperExecuteMsg::WrapperPairs(WrapperPairsExecuteMsg::UpdateWrapperPairs {
+            add_pairs,
+            remove_pairs,
+        }) => execute_update_wrapper_pairs(deps, env, info, add_pairs, remove_pairs),
+    }
+}
+
+fn execute_swap_adapter(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResult<Response> {
+    match msg {
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, cw20_msg),
+        // The min amount out is not used since the wrapper pairs convert at a set rate,
+        // it is verified by the entry point contract
+        ExecuteMsg::Swap { operations, .. } => {
+            let coin_in = one_coin(&info)?;
+            execute_swap(deps, env, info, coin_in.into(), operations)
+        }
+        ExecuteMsg::TransferFundsBack {
+            swapper,
+            return_denom,
+        } => Ok(execute_transfer_funds_back(
+            deps,
+            env,
+            info,
+            swapper,
+            return_denom,
+        )?),
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
+    }
+}
+
+fn execute_swap(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset_in: Asset,
+    operations: Vec<SwapOperation>,
+) -> ContractResult<Response> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Get the wrapper pair converting the swap operation's denoms
+    let operation = get_single_operation(&operations)?;
+    let (pair, wrap) = get_wrapper_pair(deps.as_ref(), operation)?;
+
+    // Ensure the asset in denom is the same as the swap operation's denom in
+    if asset_in.denom() != operation.denom_in {
+        return Err(ContractError::CoinInDenomMismatch);
+    }
+
+    // Create the wrap or unwrap message, sending the asset in
+    let WrapperAction {
+        contract_address,
+        msg,
+    } = if wrap { pair.wrap } else { pair.unwrap };
+    let swap_msg = asset_in.into_wasm_msg(contract_address, msg)?;
+
+    // Create the transfer funds back message
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+            return_denom: operation.denom_out.clone(),
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(swap_msg)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_swap_and_transfer_back"))
+}
+
+// Removes and then adds wrapper pairs, validating the added pairs the same as on instantiate
+// Can only be called by the contract admin
+fn execute_update_wrapper_pairs(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    add_pairs: Vec<WrapperPair>,
+    remove_pairs: Vec<WrapperPairDenoms>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract admin
+    assert_contract_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let mut response = Response::new().add_attribute("action", "update_wrapper_pairs");
+
+    // Remove the wrapper pairs first, so a pair can be replaced in the same message
+    for WrapperPairDenoms {
+        unwrapped_denom,
+        wrapped_denom,
+    } in remove_pairs
+    {
+        WRAPPER_PAIRS.remove(deps.storage, (&unwrapped_denom, &wrapped_denom));
+
+        response = response.add_attribute(
+            "remove_wrapper_pair",
+            format!("{} <> {}", unwrapped_denom, wrapped_denom),
+        );
+    }
+
+    // Validate and store the added wrapper pairs
+    for pair in add_pairs {
+        response = response.add_attribute(
+            "add_wrapper_pair",
+            save_wrapper_pair(deps.storage, deps.api, pair)?,
+        );
+    }
+
+    Ok(response)
+}
+
+/////////////
+/// QUERY ///
+/////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    match msg {
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in,
+            swap_operations,
+        } => {
+            let (asset_out, _) = simulate_swap_exact_asset_in(deps, &asset_in, &swap_operations)?;
+
+            to_json_binary(&asset_out)
+        }
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out,
+            swap_operations,
+        } => {
+            let (asset_in, _) = simulate_swap_exact_asset_out(deps, &asset_out, &swap_operations)?;
+
+            to_json_binary(&asset_in)
+        }
+        QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in,
+            swap_operations,
+            include_spot_price,
+        } => {
+            let (asset_out, spot_price) =
+                simulate_swap_exact_asset_in(deps, &asset_in, &swap_operations)?;
+
+            to_json_binary(&SimulateSwapExactAssetInResponse {
+                asset_out,
+                spot_price: include_spot_price.then_some(spot_price),
+            })
+        }
+        QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out,
+            swap_operations,
+            include_spot_price,
+        } => {
+            let (asset_in, spot_price) =
+                simulate_swap_exact_asset_out(deps, &asset_out, &swap_operations)?;
+
+            to_json_binary(&SimulateSwapExactAssetOutResponse {
+                asset_in,
+                spot_price: include_spot_price.then_some(spot_price),
+            })
+        }
+        QueryMsg::SimulateSmartSwapExactAssetIn { routes, .. } => {
+            let (asset_out, _) = simulate_smart_swap_exact_asset_in(deps, &routes)?;
+
+            to_json_binary(&asset_out)
+        }
+        QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
+            routes,
+            include_spot_price,
+            ..
+        } => {
+            let (asset_out, spot_price) = simulate_smart_swap_exact_asset_in(deps, &routes)?;
+
+            to_json_binary(&SimulateSmartSwapExactAssetInResponse {
+                asset_out,
+                spot_price: include_spot_price.then_some(spot_price),
+            })
+        }
+        QueryMsg::Capabilities {} => to_json_binary(&query_capabilities()),
+    }
+    .map_err(From::from)
+}
+
+// Returns the features supported by the wrapper swap adapter contract
+fn query_capabilities() -> CapabilitiesResponse {
+    CapabilitiesResponse {
+        swap_exact_asset_in: true,
+        swap_exact_asset_out: true,
+        swap_exact_asset_out_execution: false,
+        smart_swap: true,
+        smart_swap_execution: false,
+        cw20_input: true,
+        spot_price: true,
+        pool_identifiers: vec![PoolIdentifier::Unused],
+    }
+}
+
+// Simulates converting the asset in, returning the asset out and the spot price.
+// The asset out is rounded down, the same as the wrapper contracts.
+fn simulate_swap_exact_asset_in(
+    deps: Deps,
+    asset_in: &Asset,
+    swap_operations: &[SwapOperation],
+) -> ContractResult<(Asset, Decimal)> {
+    let operation = get_single_operation(swap_operations)?;
+
+    // Ensure the asset in denom is the same as the swap operation's denom in
+    if asset_in.denom() != operation.denom_in {
+        return Err(ContractError::CoinInDenomMismatch);
+    }
+
+    let (pair, wrap) = get_wrapper_pair(deps, operation)?;
+    let rate = get_wrapper_rate(deps, &pair.rate)?;
+
+    let (amount_out, spot_price) = if wrap {
+        (asset_in.amount().mul_floor(rate), rate)
+    } else {
+        (asset_in.amount().div_floor(rate), Decimal::one() / rate)
+    };
+
+    Ok((
+        Asset::new(deps.api, &operation.denom_out, amount_out),
+        spot_price,
+    ))
+}
+
+// Simulates converting for the asset out, returning the asset in needed and the spot price.
+// The asset in is rounded up so that converting it never returns less than the asset out.
+fn simulate_swap_exact_asset_out(
+    deps: Deps,
+    asset_out: &Asset,
+    swap_operations: &[SwapOperation],
+) -> ContractResult<(Asset, Decimal)> {
+    let operation = get_single_operation(swap_operations)?;
+
+    // Ensure the asset out denom is the same as the swap operation's denom out
+    if asset_out.denom() != operation.denom_out {
+        return Err(ContractError::CoinOutDenomMismatch);
+    }
+
+    let (pair, wrap) = get_wrapper_pair(deps, operation)?;
+    let rate = get_wrapper_rate(deps, &pair.rate)?;
+
+    let (amount_in, spot_price) = if wrap {
+        (asset_out.amount().div_ceil(rate), rate)
+    } else {
+        (asset_out.amount().mul_ceil(rate), Decimal::one() / rate)
+    };
+
+    Ok((
+        Asset::new(deps.api, &operation.denom_in, amount_in),
+        spot_price,
+    ))
+}
+
+// Simulates converting the offer asset of each route, returning the summed asset out and the
+// spot price. All routes convert through the same wrapper pair so they share the spot price.
+fn simulate_smart_swap_exact_asset_in(
+    deps: Deps,
+    routes: &[Route],
+) -> ContractResult<(Asset, Decimal)> {
+    let ask_denom = get_ask_denom_for_routes(routes)?;
+
+    let mut asset_out = Asset::new(deps.api, &ask_denom, Uint128::zero());
+    let mut spot_price = Decimal::zero();
+
+    for route in routes {
+        let (route_asset_out, route_spot_price) =
+            simulate_swap_exact_asset_in(deps, &route.offer_asset, &route.operations)?;
+
+        asset_out.add(route_asset_out.amount())?;
+        spot_price = route_spot_price;
+    }
+
+    Ok((asset_out, spot_price))
+}
+
+// Validates and stores the wrapper pair, returning the pair's attribute value
+fn save_wrapper_pair(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    pair: WrapperPair,
+) -> ContractResult<String> {
+    if pair.unwrapped_denom == pair.wrapped_denom {
+        return Err(ContractError::SameWrapperPairDenoms);
+    }
+
+    // Error if the pair is already stored in either direction, since
+    // the swap operation denoms would not identify a single pair
+    if WRAPPER_PAIRS.has(storage, (&pair.unwrapped_denom, &pair.wrapped_denom))
+        || WRAPPER_PAIRS.has(storage, (&pair.wrapped_denom, &pair.unwrapped_denom))
+    {
+        return Err(ContractError::DuplicateWrapperPair {
+            unwrapped_denom: pair.unwrapped_denom,
+            wrapped_denom: pair.wrapped_denom,
+        });
+    }
+
+    // Validate the wrap and unwrap contract addresses
+    api.addr_validate(&pair.wrap.contract_address)?;
+    api.addr_validate(&pair.unwrap.contract_address)?;
+
+    // Validate the rate, a queried rate is checked each time it is queried
+    match &pair.rate {
+        WrapperRate::Fixed(rate) => {
+            if rate.is_zero() {
+                return Err(ContractError::ZeroWrapperRate);
+            }
+        }
+        WrapperRate::Query {
+            contract_address, ..
+        } => {
+            api.addr_validate(contract_address)?;
+        }
+    }
+
+    WRAPPER_PAIRS.save(storage, (&pair.unwrapped_denom, &pair.wrapped_denom), &pair)?;
+
+    Ok(format!(
+        "{} <> {}",
+        pair.unwrapped_denom, pair.wrapped_denom
+    ))
+}
+
+// Returns the only swap operation, since each wrapper swap converts a single pair
+fn get_single_operation(operations: &[SwapOperation]) -> ContractResult<&SwapOperation> {
+    match operations {
+        [] => Err(ContractError::SwapOperationsEmpty),
+        [operation] => Ok(operation),
+        _ => Err(ContractError::MultipleSwapOperations),
+    }
+}
+
+// Returns the wrapper pair converting the swap operation's denoms, and
+// whether the swap operation wraps (true) or unwraps (false) the denom in
+fn get_wrapper_pair(deps: Deps, operation: &SwapOperation) -> ContractResult<(WrapperPair, bool)> {
+    if let Some(pair) =
+        WRAPPER_PAIRS.may_load(deps.storage, (&operation.denom_in, &operation.denom_out))?
+    {
+        return Ok((pair, true));
+    }
+
+    if let Some(pair) =
+        WRAPPER_PAIRS.may_load(deps.storage, (&operation.denom_out, &operation.denom_in))?
+    {
+        return Ok((pair, false));
+    }
+
+    Err(ContractError::UnsupportedWrapperPair {
+        denom_in: operation.denom_in.clone(),
+        denom_out: operation.denom_out.clone(),
+    })
+}
+
+// Returns the amount of the wrapped denom minted per unwrapped denom,
+// erroring instead of dividing by zero in the simulations
+fn get_wrapper_rate(deps: Deps, rate: &WrapperRate) -> ContractResult<Decimal> {
+    let rate = match rate {
+        WrapperRate::Fixed(rate) => *rate,
+        WrapperRate::Query {
+            contract_address,
+            msg,
+        } => deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: contract_address.clone(),
+            msg: msg.clone(),
+        }))?,
+    };
+
+    if rate.is_zero() {
+        return Err(ContractError::ZeroWrapperRate);
+    }
+
+    Ok(rate)
+}
@@ -0,0 +1,47 @@
+use cosmwasm_std::StdError;
+use skip::error::SkipError;
+use thiserror::Error;
+
+pub type ContractResult<T> = core::result::Result<T, ContractError>;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Skip(#[from] SkipError),
+
+    #[error(transparent)]
+    Payment(#[from] cw_utils::PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("swap_operations cannot be empty")]
+    SwapOperationsEmpty,
+
+    #[error("wrapper swaps must have exactly one swap operation")]
+    MultipleSwapOperations,
+
+    #[error("coin_in denom must match the first swap operation's denom in")]
+    CoinInDenomMismatch,
+
+    #[error("coin_out denom must match the last swap operation's denom out")]
+    CoinOutDenomMismatch,
+
+    #[error("no wrapper pair converts {denom_in} to {denom_out}")]
+    UnsupportedWrapperPair { denom_in: String, denom_out: String },
+
+    #[error("wrapper pair denoms must be different")]
+    SameWrapperPairDenoms,
+
+    #[error("wrapper pair {unwrapped_denom} <> {wrapped_denom} is configured more than once")]
+    DuplicateWrapperPair {
+        unwrapped_denom: String,
+        wrapped_denom: String,
+    },
+
+    #[error("wrapper rate is zero")]
+    ZeroWrapperRate,
+}
@@ -0,0 +1,8 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use skip::swap::WrapperPair;
+
+pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+
+// Maps the (unwrapped denom, wrapped denom) of each wrapper pair to the pair
+pub const WRAPPER_PAIRS: Map<(&str, &str), WrapperPair> = Map::new("wrapper_pairs");
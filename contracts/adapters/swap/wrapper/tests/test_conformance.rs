@@ -0,0 +1,92 @@
+use cosmwasm_std::{
+    testing::MockQuerier, to_json_binary, ContractResult as SystemContractResult, Decimal,
+    QuerierResult, SystemResult, WasmQuery,
+};
+use skip::swap::{
+    SwapOperation, WrapperAction, WrapperExecuteMsg, WrapperInstantiateMsg, WrapperPair,
+    WrapperRate,
+};
+use skip_go_swap_adapter_testing::suite::{MockVenue, SwapAdapterSuite};
+use skip_go_swap_adapter_wrapper::contract::{execute, instantiate, query};
+
+// Mocks the rate contract of the queried rate wrapper pair, which mints 0.5 wrapped per unwrapped
+fn mock_rate_contract(querier: &mut MockQuerier) {
+    querier.update_wasm(|query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::Smart { contract_addr, .. } if contract_addr == "rate_contract" => {
+                SystemResult::Ok(SystemContractResult::Ok(
+                    to_json_binary(&Decimal::percent(50)).unwrap(),
+                ))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    });
+}
+
+// Returns a wrapper pair converted by the wrapper contract with the given rate
+fn wrapper_pair(unwrapped_denom: &str, wrapped_denom: &str, rate: WrapperRate) -> WrapperPair {
+    WrapperPair {
+        unwrapped_denom: unwrapped_denom.to_string(),
+        wrapped_denom: wrapped_denom.to_string(),
+        wrap: WrapperAction {
+            contract_address: "wrapper_contract".to_string(),
+            msg: to_json_binary(&"wrap").unwrap(),
+        },
+        unwrap: WrapperAction {
+            contract_address: "wrapper_contract".to_string(),
+            msg: to_json_binary(&"unwrap").unwrap(),
+        },
+        rate,
+    }
+}
+
+// Runs the swap adapter conformance suite against a fixed rate native wrapper
+// pair, and a queried rate cw20 wrapper pair
+#[test]
+fn test_conformance() {
+    SwapAdapterSuite {
+        instantiate: |deps, env, info, entry_point_contract_address| {
+            instantiate(
+                deps,
+                env,
+                info,
+                WrapperInstantiateMsg {
+                    entry_point_contract_address,
+                    pairs: vec![
+                        wrapper_pair("un", "wn", WrapperRate::Fixed(Decimal::percent(200))),
+                        wrapper_pair(
+                            "neutron123",
+                            "os",
+                            WrapperRate::Query {
+                                contract_address: "rate_contract".to_string(),
+                                msg: to_json_binary(&"rate").unwrap(),
+                            },
+                        ),
+                    ],
+                },
+            )
+        },
+        execute: |deps, env, info, msg| {
+            execute(deps, env, info, WrapperExecuteMsg::SwapAdapter(msg))
+        },
+        query,
+        venue: MockVenue {
+            mock_querier: mock_rate_contract,
+            stargate_querier: None,
+            swap_operations: vec![SwapOperation {
+                pool: "".to_string(),
+                denom_in: "un".to_string(),
+                denom_out: "wn".to_string(),
+                interface: None,
+            }],
+            cw20_swap_operations: Some(vec![SwapOperation {
+                pool: "".to_string(),
+                denom_in: "neutron123".to_string(),
+                denom_out: "os".to_string(),
+                interface: None,
+            }]),
+            supports_exact_asset_out: true,
+        },
+    }
+    .run();
+}
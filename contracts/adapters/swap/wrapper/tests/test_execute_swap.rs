@@ -0,0 +1,227 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, Coin, Decimal,
+    ReplyOn::Never,
+    SubMsg, WasmMsg,
+};
+use skip::swap::{
+    ExecuteMsg, SwapOperation, WrapperAction, WrapperExecuteMsg, WrapperInstantiateMsg,
+    WrapperPair, WrapperRate,
+};
+use skip_go_swap_adapter_wrapper::error::{ContractError, ContractResult};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Wrap Swap Operation
+    - Unwrap Swap Operation
+
+Expect Error
+    - Unsupported Wrapper Pair
+    - Coin In Denom Mismatch
+    - Multiple Swap Operations
+    - No Swap Operations
+    - Unauthorized Caller (Only the stored entry point contract can call this function)
+    - No Coin Sent
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    info_funds: Vec<Coin>,
+    swap_operations: Vec<SwapOperation>,
+    expected_messages: Vec<SubMsg>,
+    expected_error: Option<ContractError>,
+}
+
+// Returns a swap operation converting the denom in to the denom out
+fn swap_operation(denom_in: &str, denom_out: &str) -> SwapOperation {
+    SwapOperation {
+        pool: "".to_string(),
+        denom_in: denom_in.to_string(),
+        denom_out: denom_out.to_string(),
+        interface: None,
+    }
+}
+
+// Returns the expected messages of a swap sending the coin in with the wrapper contract message
+fn expected_messages(coin_in: Coin, wrapper_msg: &str, return_denom: &str) -> Vec<SubMsg> {
+    vec![
+        SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "wrapper_contract".to_string(),
+                msg: to_json_binary(&wrapper_msg).unwrap(),
+                funds: vec![coin_in],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+        SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_contract_address".to_string(),
+                msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                    swapper: Addr::unchecked("entry_point"),
+                    return_denom: return_denom.to_string(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+    ]
+}
+
+// Test execute_swap
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "ua")],
+        swap_operations: vec![swap_operation("ua", "wa")],
+        expected_messages: expected_messages(Coin::new(100, "ua"), "wrap", "wa"),
+        expected_error: None,
+    };
+    "Wrap Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "wa")],
+        swap_operations: vec![swap_operation("wa", "ua")],
+        expected_messages: expected_messages(Coin::new(100, "wa"), "unwrap", "ua"),
+        expected_error: None,
+    };
+    "Unwrap Swap Operation")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "ua")],
+        swap_operations: vec![swap_operation("ua", "wo")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::UnsupportedWrapperPair {
+            denom_in: "ua".to_string(),
+            denom_out: "wo".to_string(),
+        }),
+    };
+    "Unsupported Wrapper Pair - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "wa")],
+        swap_operations: vec![swap_operation("ua", "wa")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::CoinInDenomMismatch),
+    };
+    "Coin In Denom Mismatch - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "ua")],
+        swap_operations: vec![swap_operation("ua", "wa"), swap_operation("wa", "ua")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::MultipleSwapOperations),
+    };
+    "Multiple Swap Operations - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![Coin::new(100, "ua")],
+        swap_operations: vec![],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::SwapOperationsEmpty),
+    };
+    "No Swap Operations - Expect Error")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        info_funds: vec![Coin::new(100, "ua")],
+        swap_operations: vec![swap_operation("ua", "wa")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        info_funds: vec![],
+        swap_operations: vec![swap_operation("ua", "wa")],
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Payment(cw_utils::PaymentError::NoFunds{})),
+    };
+    "No Coin Sent - Expect Error")]
+fn test_execute_swap(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Instantiate the contract with a single wrapper pair
+    skip_go_swap_adapter_wrapper::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        WrapperInstantiateMsg {
+            entry_point_contract_address: "entry_point".to_string(),
+            pairs: vec![WrapperPair {
+                unwrapped_denom: "ua".to_string(),
+                wrapped_denom: "wa".to_string(),
+                wrap: WrapperAction {
+                    contract_address: "wrapper_contract".to_string(),
+                    msg: to_json_binary(&"wrap")?,
+                },
+                unwrap: WrapperAction {
+                    contract_address: "wrapper_contract".to_string(),
+                    msg: to_json_binary(&"unwrap")?,
+                },
+                rate: WrapperRate::Fixed(Decimal::one()),
+            }],
+        },
+    )?;
+
+    // Call execute_swap with the given test parameters
+    let res = skip_go_swap_adapter_wrapper::contract::execute(
+        deps.as_mut(),
+        env,
+        mock_info(&params.caller, &params.info_funds),
+        WrapperExecuteMsg::SwapAdapter(ExecuteMsg::Swap {
+            operations: params.swap_operations,
+            min_amount_out: None,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the messages are correct
+            assert_eq!(res.messages, params.expected_messages);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, ContractInfoResponse, ContractResult as SystemContractResult, Decimal,
+    Order, QuerierResult, SystemResult, WasmQuery,
+};
+use skip::{
+    error::SkipError,
+    swap::{
+        WrapperAction, WrapperExecuteMsg, WrapperInstantiateMsg, WrapperPair, WrapperPairDenoms,
+        WrapperPairsExecuteMsg, WrapperRate,
+    },
+};
+use skip_go_swap_adapter_wrapper::{
+    error::{ContractError, ContractResult},
+    state::WRAPPER_PAIRS,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Add Wrapper Pair
+    - Replace Wrapper Pair Rate (The pair is removed and added in the same message)
+    - Remove Wrapper Pair
+
+Expect Error
+    - Unauthorized Caller (Only the contract admin can update the wrapper pairs)
+    - Add Wrapper Pair Already Stored In The Other Direction
+    - Add Wrapper Pair With The Same Denoms
+    - Add Wrapper Pair With A Zero Rate
+
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    add_pairs: Vec<WrapperPair>,
+    remove_pairs: Vec<WrapperPairDenoms>,
+    expected_pairs: Vec<WrapperPair>,
+    expected_error: Option<ContractError>,
+}
+
+// Returns a wrapper pair converting the unwrapped denom to the wrapped denom at the fixed rate
+fn wrapper_pair(unwrapped_denom: &str, wrapped_denom: &str, rate: Decimal) -> WrapperPair {
+    WrapperPair {
+        unwrapped_denom: unwrapped_denom.to_string(),
+        wrapped_denom: wrapped_denom.to_string(),
+        wrap: WrapperAction {
+            contract_address: "wrapper_contract".to_string(),
+            msg: to_json_binary(&"wrap").unwrap(),
+        },
+        unwrap: WrapperAction {
+            contract_address: "wrapper_contract".to_string(),
+            msg: to_json_binary(&"unwrap").unwrap(),
+        },
+        rate: WrapperRate::Fixed(rate),
+    }
+}
+
+// Returns the denoms identifying the wrapper pair
+fn wrapper_pair_denoms(unwrapped_denom: &str, wrapped_denom: &str) -> WrapperPairDenoms {
+    WrapperPairDenoms {
+        unwrapped_denom: unwrapped_denom.to_string(),
+        wrapped_denom: wrapped_denom.to_string(),
+    }
+}
+
+// Test execute_update_wrapper_pairs, with the contract instantiated with the ua <> wa pair
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        add_pairs: vec![wrapper_pair("uo", "wo", Decimal::percent(50))],
+        remove_pairs: vec![],
+        expected_pairs: vec![
+            wrapper_pair("ua", "wa", Decimal::one()),
+            wrapper_pair("uo", "wo", Decimal::percent(50)),
+        ],
+        expected_error: None,
+    };
+    "Add Wrapper Pair")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        add_pairs: vec![wrapper_pair("ua", "wa", Decimal::percent(90))],
+        remove_pairs: vec![wrapper_pair_denoms("ua", "wa")],
+        expected_pairs: vec![wrapper_pair("ua", "wa", Decimal::percent(90))],
+        expected_error: None,
+    };
+    "Replace Wrapper Pair Rate")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        add_pairs: vec![],
+        remove_pairs: vec![wrapper_pair_denoms("ua", "wa")],
+        expected_pairs: vec![],
+        expected_error: None,
+    };
+    "Remove Wrapper Pair")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        add_pairs: vec![wrapper_pair("uo", "wo", Decimal::one())],
+        remove_pairs: vec![],
+        expected_pairs: vec![],
+        expected_error: Some(ContractError::Skip(SkipError::Unauthorized)),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        add_pairs: vec![wrapper_pair("wa", "ua", Decimal::one())],
+        remove_pairs: vec![],
+        expected_pairs: vec![],
+        expected_error: Some(ContractError::DuplicateWrapperPair {
+            unwrapped_denom: "wa".to_string(),
+            wrapped_denom: "ua".to_string(),
+        }),
+    };
+    "Add Wrapper Pair Already Stored In The Other Direction - Expect Error")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        add_pairs: vec![wrapper_pair("uo", "uo", Decimal::one())],
+        remove_pairs: vec![],
+        expected_pairs: vec![],
+        expected_error: Some(ContractError::SameWrapperPairDenoms),
+    };
+    "Add Wrapper Pair With The Same Denoms - Expect Error")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        add_pairs: vec![wrapper_pair("uo", "wo", Decimal::zero())],
+        remove_pairs: vec![],
+        expected_pairs: vec![],
+        expected_error: Some(ContractError::ZeroWrapperRate),
+    };
+    "Add Wrapper Pair With A Zero Rate - Expect Error")]
+fn test_execute_update_wrapper_pairs(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the contract info query
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { contract_addr }
+                if contract_addr == "swap_contract_address" =>
+            {
+                let mut contract_info = ContractInfoResponse::new(1, "creator");
+                contract_info.admin = Some("admin".to_string());
+
+                SystemResult::Ok(SystemContractResult::Ok(
+                    to_json_binary(&contract_info).unwrap(),
+                ))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("swap_contract_address");
+
+    // Instantiate the contract with a single wrapper pair
+    skip_go_swap_adapter_wrapper::contract::instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("creator", &[]),
+        WrapperInstantiateMsg {
+            entry_point_contract_address: "entry_point".to_string(),
+            pairs: vec![wrapper_pair("ua", "wa", Decimal::one())],
+        },
+    )?;
+
+    // Call execute_update_wrapper_pairs with the given test parameters
+    let res = skip_go_swap_adapter_wrapper::contract::execute(
+        deps.as_mut(),
+        env,
+        mock_info(&params.caller, &[]),
+        WrapperExecuteMsg::WrapperPairs(WrapperPairsExecuteMsg::UpdateWrapperPairs {
+            add_pairs: params.add_pairs,
+            remove_pairs: params.remove_pairs,
+        }),
+    );
+
+    // Assert the behavior is correct
+    match res {
+        Ok(_) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the stored wrapper pairs are correct
+            let stored_pairs = WRAPPER_PAIRS
+                .range(deps.as_ref().storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, pair)| pair))
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(stored_pairs, params.expected_pairs);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
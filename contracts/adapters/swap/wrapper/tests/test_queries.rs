@@ -0,0 +1,287 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Coin, ContractResult as SystemContractResult, Decimal, QuerierResult,
+    SystemResult, WasmQuery,
+};
+use skip::{
+    asset::Asset,
+    swap::{
+        QueryMsg, SimulateSwapExactAssetInResponse, SimulateSwapExactAssetOutResponse,
+        SwapOperation, WrapperAction, WrapperInstantiateMsg, WrapperPair, WrapperRate,
+    },
+};
+use skip_go_swap_adapter_wrapper::error::{ContractError, ContractResult};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Simulate Wrap Exact Asset In With A Fixed Rate
+    - Simulate Unwrap Exact Asset In Rounds Down
+    - Simulate Wrap Exact Asset Out Rounds Up
+    - Simulate Unwrap Exact Asset Out
+    - Simulate Wrap Exact Asset In With A Queried Rate
+    - Simulate Unwrap Exact Asset In With A Queried Rate
+
+Expect Error
+    - Queried Rate Is Zero
+    - Unsupported Wrapper Pair
+    - Multiple Swap Operations
+
+ */
+
+// Define test parameters
+struct Params {
+    query: QueryMsg,
+    expected_response: Option<(Asset, Decimal)>,
+    expected_error: Option<ContractError>,
+}
+
+// Returns a swap operation converting the denom in to the denom out
+fn swap_operation(denom_in: &str, denom_out: &str) -> SwapOperation {
+    SwapOperation {
+        pool: "".to_string(),
+        denom_in: denom_in.to_string(),
+        denom_out: denom_out.to_string(),
+        interface: None,
+    }
+}
+
+// Returns a wrapper pair using the given rate
+fn wrapper_pair(unwrapped_denom: &str, wrapped_denom: &str, rate: WrapperRate) -> WrapperPair {
+    WrapperPair {
+        unwrapped_denom: unwrapped_denom.to_string(),
+        wrapped_denom: wrapped_denom.to_string(),
+        wrap: WrapperAction {
+            contract_address: "wrapper_contract".to_string(),
+            msg: to_json_binary(&"wrap").unwrap(),
+        },
+        unwrap: WrapperAction {
+            contract_address: "wrapper_contract".to_string(),
+            msg: to_json_binary(&"unwrap").unwrap(),
+        },
+        rate,
+    }
+}
+
+// Answers the rate queries of the queried rate wrapper pairs
+fn mock_rate_query(query: &WasmQuery) -> QuerierResult {
+    match query {
+        WasmQuery::Smart { contract_addr, .. } => {
+            let rate = match contract_addr.as_str() {
+                "rate_contract" => Decimal::percent(50),
+                _ => Decimal::zero(),
+            };
+
+            SystemResult::Ok(SystemContractResult::Ok(to_json_binary(&rate).unwrap()))
+        }
+        _ => panic!("Unsupported query: {:?}", query),
+    }
+}
+
+// Test queries
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(101, "ua")),
+            swap_operations: vec![swap_operation("ua", "wa")],
+            include_spot_price: true,
+        },
+        expected_response: Some((
+            Asset::Native(Coin::new(202, "wa")),
+            Decimal::from_ratio(2u128, 1u128),
+        )),
+        expected_error: None,
+    };
+    "Simulate Wrap Exact Asset In With A Fixed Rate")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(101, "wa")),
+            swap_operations: vec![swap_operation("wa", "ua")],
+            include_spot_price: true,
+        },
+        expected_response: Some((
+            Asset::Native(Coin::new(50, "ua")),
+            Decimal::percent(50),
+        )),
+        expected_error: None,
+    };
+    "Simulate Unwrap Exact Asset In Rounds Down")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out: Asset::Native(Coin::new(101, "wa")),
+            swap_operations: vec![swap_operation("ua", "wa")],
+            include_spot_price: true,
+        },
+        expected_response: Some((
+            Asset::Native(Coin::new(51, "ua")),
+            Decimal::from_ratio(2u128, 1u128),
+        )),
+        expected_error: None,
+    };
+    "Simulate Wrap Exact Asset Out Rounds Up")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out: Asset::Native(Coin::new(50, "ua")),
+            swap_operations: vec![swap_operation("wa", "ua")],
+            include_spot_price: true,
+        },
+        expected_response: Some((
+            Asset::Native(Coin::new(100, "wa")),
+            Decimal::percent(50),
+        )),
+        expected_error: None,
+    };
+    "Simulate Unwrap Exact Asset Out")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(100, "uo")),
+            swap_operations: vec![swap_operation("uo", "wo")],
+            include_spot_price: true,
+        },
+        expected_response: Some((
+            Asset::Native(Coin::new(50, "wo")),
+            Decimal::percent(50),
+        )),
+        expected_error: None,
+    };
+    "Simulate Wrap Exact Asset In With A Queried Rate")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(100, "wo")),
+            swap_operations: vec![swap_operation("wo", "uo")],
+            include_spot_price: true,
+        },
+        expected_response: Some((
+            Asset::Native(Coin::new(200, "uo")),
+            Decimal::from_ratio(2u128, 1u128),
+        )),
+        expected_error: None,
+    };
+    "Simulate Unwrap Exact Asset In With A Queried Rate")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(100, "uz")),
+            swap_operations: vec![swap_operation("uz", "wz")],
+            include_spot_price: true,
+        },
+        expected_response: None,
+        expected_error: Some(ContractError::ZeroWrapperRate),
+    };
+    "Queried Rate Is Zero - Expect Error")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in: Asset::Native(Coin::new(100, "ua")),
+            swap_operations: vec![swap_operation("ua", "wo")],
+            include_spot_price: true,
+        },
+        expected_response: None,
+        expected_error: Some(ContractError::UnsupportedWrapperPair {
+            denom_in: "ua".to_string(),
+            denom_out: "wo".to_string(),
+        }),
+    };
+    "Unsupported Wrapper Pair - Expect Error")]
+#[test_case(
+    Params {
+        query: QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out: Asset::Native(Coin::new(100, "ua")),
+            swap_operations: vec![swap_operation("ua", "wa"), swap_operation("wa", "ua")],
+            include_spot_price: true,
+        },
+        expected_response: None,
+        expected_error: Some(ContractError::MultipleSwapOperations),
+    };
+    "Multiple Swap Operations - Expect Error")]
+fn test_queries(params: Params) -> ContractResult<()> {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(mock_rate_query);
+
+    // Instantiate the contract with a fixed rate pair and two queried rate pairs
+    skip_go_swap_adapter_wrapper::contract::instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        WrapperInstantiateMsg {
+            entry_point_contract_address: "entry_point".to_string(),
+            pairs: vec![
+                wrapper_pair(
+                    "ua",
+                    "wa",
+                    WrapperRate::Fixed(Decimal::from_ratio(2u128, 1u128)),
+                ),
+                wrapper_pair(
+                    "uo",
+                    "wo",
+                    WrapperRate::Query {
+                        contract_address: "rate_contract".to_string(),
+                        msg: to_json_binary(&"rate")?,
+                    },
+                ),
+                wrapper_pair(
+                    "uz",
+                    "wz",
+                    WrapperRate::Query {
+                        contract_address: "zero_rate_contract".to_string(),
+                        msg: to_json_binary(&"rate")?,
+                    },
+                ),
+            ],
+        },
+    )?;
+
+    // Call the query with the given test parameters
+    let is_exact_asset_in = matches!(
+        params.query,
+        QueryMsg::SimulateSwapExactAssetInWithMetadata { .. }
+    );
+    let res =
+        skip_go_swap_adapter_wrapper::contract::query(deps.as_ref(), mock_env(), params.query);
+
+    // Assert the behavior is correct
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the simulated asset and spot price are correct
+            let (expected_asset, expected_spot_price) = params.expected_response.unwrap();
+            if is_exact_asset_in {
+                let res: SimulateSwapExactAssetInResponse = from_json(res)?;
+                assert_eq!(res.asset_out, expected_asset);
+                assert_eq!(res.spot_price, Some(expected_spot_price));
+            } else {
+                let res: SimulateSwapExactAssetOutResponse = from_json(res)?;
+                assert_eq!(res.asset_in, expected_asset);
+                assert_eq!(res.spot_price, Some(expected_spot_price));
+            }
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+
+    Ok(())
+}
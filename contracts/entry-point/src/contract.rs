@@ -2,10 +2,16 @@ use crate::{
     error::{ContractError, ContractResult},
     execute::{
         execute_action, execute_action_with_recover, execute_post_swap_action,
-        execute_swap_and_action, execute_swap_and_action_with_recover, execute_user_swap,
-        receive_cw20,
+        execute_set_ibc_receiver_formats, execute_set_route_allowlist_enforced,
+        execute_swap_and_action, execute_swap_and_action_with_recover,
+        execute_update_route_allowlist, execute_user_swap, receive_cw20,
+    },
+    query::{
+        query_allowed_ibc_channels, query_allowed_warp_routes, query_bech32_to_bytes32,
+        query_bytes32_to_bech32, query_bytes32_to_evm_address, query_evm_address_to_bytes32,
+        query_ibc_receiver_format, query_ibc_transfer_adapter_contract,
+        query_route_allowlist_enforced, query_swap_venue_adapter_contract,
     },
-    query::{query_ibc_transfer_adapter_contract, query_swap_venue_adapter_contract},
     reply::{reply_swap_and_action_with_recover, RECOVER_REPLY_ID},
     state::{
         BLOCKED_CONTRACT_ADDRESSES, HYPERLANE_TRANSFER_CONTRACT_ADDRESS,
@@ -239,6 +245,26 @@ pub fn execute(
             min_asset,
             recovery_addr,
         ),
+        ExecuteMsg::SetIbcReceiverFormats { receiver_formats } => {
+            execute_set_ibc_receiver_formats(deps, env, info, receiver_formats)
+        }
+        ExecuteMsg::SetRouteAllowlistEnforced { enforced } => {
+            execute_set_route_allowlist_enforced(deps, env, info, enforced)
+        }
+        ExecuteMsg::UpdateRouteAllowlist {
+            add_ibc_channels,
+            remove_ibc_channels,
+            add_warp_routes,
+            remove_warp_routes,
+        } => execute_update_route_allowlist(
+            deps,
+            env,
+            info,
+            add_ibc_channels,
+            remove_ibc_channels,
+            add_warp_routes,
+            remove_warp_routes,
+        ),
     }
 }
 
@@ -263,5 +289,25 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::IbcTransferAdapterContract {} => {
             to_json_binary(&query_ibc_transfer_adapter_contract(deps)?)
         }
+        QueryMsg::IbcReceiverFormat { source_channel } => {
+            to_json_binary(&query_ibc_receiver_format(deps, source_channel)?)
+        }
+        QueryMsg::RouteAllowlistEnforced {} => {
+            to_json_binary(&query_route_allowlist_enforced(deps)?)
+        }
+        QueryMsg::AllowedIbcChannels {} => to_json_binary(&query_allowed_ibc_channels(deps)?),
+        QueryMsg::AllowedWarpRoutes { dest_domain } => {
+            to_json_binary(&query_allowed_warp_routes(deps, dest_domain)?)
+        }
+        QueryMsg::Bech32ToBytes32 { address } => to_json_binary(&query_bech32_to_bytes32(address)?),
+        QueryMsg::Bytes32ToBech32 { bytes32, prefix } => {
+            to_json_binary(&query_bytes32_to_bech32(bytes32, prefix)?)
+        }
+        QueryMsg::EvmAddressToBytes32 { address } => {
+            to_json_binary(&query_evm_address_to_bytes32(address)?)
+        }
+        QueryMsg::Bytes32ToEvmAddress { bytes32 } => {
+            to_json_binary(&query_bytes32_to_evm_address(bytes32)?)
+        }
     }
 }
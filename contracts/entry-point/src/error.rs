@@ -33,12 +33,18 @@ pub enum ContractError {
     #[error("IBC fee denom differs from asset received without a fee swap to convert")]
     IBCFeeDenomDiffersFromAssetReceived,
 
+    #[error("Hyperlane fee denom differs from asset received without a fee swap to convert")]
+    HplFeeDenomDiffersFromAssetReceived,
+
     //////////////
     // FEE SWAP //
     //////////////
     #[error("Fee Swap Not Allowed: No IBC Fees Provided")]
     FeeSwapWithoutIbcFees,
 
+    #[error("Fee Swap Not Allowed: No Hyperlane Fee Provided")]
+    FeeSwapWithoutHplFee,
+
     #[error("Fee Swap Asset In Denom Differs From Asset Sent To Contract")]
     FeeSwapAssetInDenomMismatch,
 
@@ -65,8 +71,8 @@ pub enum ContractError {
     )]
     NonNativeIbcTransfer,
 
-    #[error("Hyperlane Transfer Adapter Only Supports Native Coins")]
-    NonNativeHplTransfer,
+    #[error("Limit Orders Only Support Native Coins")]
+    NonNativeLimitOrder,
 
     #[error("Reply id: {0} not valid")]
     ReplyIdError(u64),
@@ -88,4 +94,16 @@ pub enum ContractError {
 
     #[error("Eureka Fee Timeout Timestamp Less Than Current Timestamp")]
     EurekaFeeTimeout,
+
+    /////////////////////
+    // ROUTE ALLOWLIST //
+    /////////////////////
+    #[error("IBC Source Channel {source_channel} Is Not Allowed")]
+    IbcChannelNotAllowed { source_channel: String },
+
+    #[error("Warp Route {warp_address} To Destination Domain {dest_domain} Is Not Allowed")]
+    WarpRouteNotAllowed {
+        dest_domain: u32,
+        warp_address: String,
+    },
 }
@@ -4,9 +4,9 @@ use crate::{
     error::{ContractError, ContractResult},
     reply::{RecoverTempStorage, RECOVER_REPLY_ID},
     state::{
-        BLOCKED_CONTRACT_ADDRESSES, HYPERLANE_TRANSFER_CONTRACT_ADDRESS,
-        IBC_TRANSFER_CONTRACT_ADDRESS, PRE_SWAP_OUT_ASSET_AMOUNT, RECOVER_TEMP_STORAGE,
-        SWAP_VENUE_MAP,
+        ALLOWED_IBC_CHANNELS, ALLOWED_WARP_ROUTES, BLOCKED_CONTRACT_ADDRESSES,
+        HYPERLANE_TRANSFER_CONTRACT_ADDRESS, IBC_RECEIVER_FORMATS, IBC_TRANSFER_CONTRACT_ADDRESS,
+        PRE_SWAP_OUT_ASSET_AMOUNT, RECOVER_TEMP_STORAGE, ROUTE_ALLOWLIST_ENFORCED, SWAP_VENUE_MAP,
     },
 };
 use cosmwasm_std::{
@@ -16,12 +16,18 @@ use cosmwasm_std::{
 use cw20::{Cw20Coin, Cw20ReceiveMsg};
 use cw_utils::one_coin;
 use skip::{
+    address::{validate_bytes32, validate_evm_address},
+    admin::assert_contract_admin,
     asset::{get_current_asset_available, Asset},
     entry_point::{Action, Affiliate, Cw20HookMsg, ExecuteMsg},
-    hyperlane::{ExecuteMsg as HplExecuteMsg, ExecuteMsg::HplTransfer},
-    ibc::{ExecuteMsg as IbcTransferExecuteMsg, IbcInfo, IbcTransfer},
+    hyperlane::{Cw20HookMsg as HplCw20HookMsg, ExecuteMsg as HplExecuteMsg, HplWarpRoute},
+    ibc::{
+        ExecuteMsg as IbcTransferExecuteMsg, IbcChannelReceiverFormat, IbcInfo, IbcTransfer,
+        IBC_SOLIDITY_ABI_ENCODING,
+    },
     swap::{
-        validate_swap_operations, ExecuteMsg as SwapExecuteMsg, QueryMsg as SwapQueryMsg, Swap,
+        validate_swap_operations, CapabilitiesResponse, DualityExecuteMsg,
+        DualityLimitOrderExecuteMsg, ExecuteMsg as SwapExecuteMsg, QueryMsg as SwapQueryMsg, Swap,
         SwapExactAssetOut,
     },
 };
@@ -164,6 +170,12 @@ pub fn execute_swap_and_action(
             handle_ibc_transfer_fees(&deps, ibc_info, fee_swap, &mut remaining_asset, response)?;
     }
 
+    // If the post swap action is a Hyperlane transfer, then handle the interchain
+    // gas payment the same way as the ibc fees.
+    if let Action::HplTransfer { fee, fee_swap, .. } = &post_swap_action {
+        response = handle_hpl_transfer_fees(&deps, fee, fee_swap, &mut remaining_asset, response)?;
+    }
+
     // Set a boolean to determine if the user swap is exact out or not
     let exact_out = match &user_swap {
         Swap::SwapExactAssetIn(_) => false,
@@ -347,8 +359,16 @@ pub fn execute_user_swap(
             let user_swap_adapter_contract_address =
                 SWAP_VENUE_MAP.load(deps.storage, &swap.swap_venue_name)?;
 
-            // Create the user swap message args
-            let user_swap_msg_args: SwapExecuteMsg = swap.into();
+            // Create the user swap message args, passing the min asset amount plus the
+            // total affiliate fee amount as the min amount out the swap venue must return
+            let user_swap_msg_args = SwapExecuteMsg::Swap {
+                operations: swap.operations,
+                min_amount_out: Some(
+                    min_asset
+                        .amount()
+                        .saturating_add(total_affiliate_fee_amount),
+                ),
+            };
 
             // Create the user swap message
             let user_swap_msg = remaining_asset.into_wasm_msg(
@@ -371,87 +391,156 @@ pub fn execute_user_swap(
             // Calculate the swap asset out by adding the min asset amount to the total affiliate fee amount
             min_asset.add(total_affiliate_fee_amount)?;
 
-            // Query the swap adapter to get the asset in needed to obtain the min asset plus affiliates
-            let user_swap_asset_in = query_swap_asset_in(
-                &deps,
-                &user_swap_adapter_contract_address,
-                &swap,
-                &min_asset,
-            )?;
-
-            // Verify the user swap in denom is the same as the denom received from the message to the contract
-            if user_swap_asset_in.denom() != remaining_asset.denom() {
-                return Err(ContractError::UserSwapAssetInDenomMismatch);
-            }
-
-            // Calculate refund amount to send back to the user
-            remaining_asset.sub(user_swap_asset_in.amount())?;
-
-            // If refund amount gt zero, then create the refund message and add it to the response
-            if remaining_asset.amount() > Uint128::zero() {
-                // Get the refund address from the swap
-                let to_address = swap
-                    .refund_address
-                    .clone()
-                    .ok_or(ContractError::NoRefundAddress)?;
-
+            // If the swap adapter can execute swaps of an exact asset out and there is a refund
+            // address, swap the remaining asset for exactly the min asset plus affiliates, with
+            // the swap adapter refunding the unused remaining asset. This way a price move between
+            // the simulation and the swap does not change the amount received.
+            if let Some(refund_address) = swap.refund_address.clone().filter(|_| {
+                query_swap_adapter_capabilities(&deps, &user_swap_adapter_contract_address)
+                    .is_some_and(|capabilities| capabilities.swap_exact_asset_out_execution)
+            }) {
                 // Validate the refund address
-                deps.api.addr_validate(&to_address)?;
+                deps.api.addr_validate(&refund_address)?;
 
-                // Get the refund amount
-                let refund_amount = remaining_asset.amount();
+                // Create the user swap message args
+                let user_swap_msg_args = SwapExecuteMsg::SwapExactAssetOut {
+                    operations: swap.operations,
+                    amount_out: min_asset.amount(),
+                    refund_address,
+                };
 
-                // Create the refund message
-                let refund_msg = remaining_asset.transfer(&to_address);
+                // Create the user swap message, sending the remaining asset as the max amount in
+                let user_swap_msg = remaining_asset.into_wasm_msg(
+                    user_swap_adapter_contract_address.to_string(),
+                    to_json_binary(&user_swap_msg_args)?,
+                )?;
 
-                // Add the refund message and attributes to the response
                 response = response
-                    .add_message(refund_msg)
-                    .add_attribute("action", "dispatch_refund")
-                    .add_attribute("address", &to_address)
-                    .add_attribute("amount", refund_amount);
-            }
+                    .add_message(user_swap_msg)
+                    .add_attribute("action", "dispatch_user_swap_exact_asset_out");
+            } else {
+                // Query the swap adapter to get the asset in needed to obtain the min asset plus affiliates
+                let user_swap_asset_in = query_swap_asset_in(
+                    &deps,
+                    &user_swap_adapter_contract_address,
+                    &swap,
+                    &min_asset,
+                )?;
 
-            // Create the user swap message args
-            let user_swap_msg_args: SwapExecuteMsg = swap.into();
+                // Verify the user swap in denom is the same as the denom received from the message to the contract
+                if user_swap_asset_in.denom() != remaining_asset.denom() {
+                    return Err(ContractError::UserSwapAssetInDenomMismatch);
+                }
+
+                // Calculate refund amount to send back to the user
+                remaining_asset.sub(user_swap_asset_in.amount())?;
+
+                // If refund amount gt zero, then create the refund message and add it to the response
+                if remaining_asset.amount() > Uint128::zero() {
+                    // Get the refund address from the swap
+                    let to_address = swap
+                        .refund_address
+                        .clone()
+                        .ok_or(ContractError::NoRefundAddress)?;
+
+                    // Validate the refund address
+                    deps.api.addr_validate(&to_address)?;
+
+                    // Get the refund amount
+                    let refund_amount = remaining_asset.amount();
+
+                    // Create the refund message
+                    let refund_msg = remaining_asset.transfer(&to_address);
+
+                    // Add the refund message and attributes to the response
+                    response = response
+                        .add_message(refund_msg)
+                        .add_attribute("action", "dispatch_refund")
+                        .add_attribute("address", &to_address)
+                        .add_attribute("amount", refund_amount);
+                }
+
+                // Create the user swap message args, passing the min asset amount, which already
+                // includes the total affiliate fee amount, as the min amount out the swap venue must return
+                let user_swap_msg_args = SwapExecuteMsg::Swap {
+                    operations: swap.operations,
+                    min_amount_out: Some(min_asset.amount()),
+                };
 
-            // Create the user swap message
-            let user_swap_msg = user_swap_asset_in.into_wasm_msg(
-                user_swap_adapter_contract_address.to_string(),
-                to_json_binary(&user_swap_msg_args)?,
-            )?;
+                // Create the user swap message
+                let user_swap_msg = user_swap_asset_in.into_wasm_msg(
+                    user_swap_adapter_contract_address.to_string(),
+                    to_json_binary(&user_swap_msg_args)?,
+                )?;
 
-            response = response
-                .add_message(user_swap_msg)
-                .add_attribute("action", "dispatch_user_swap_exact_asset_out");
+                response = response
+                    .add_message(user_swap_msg)
+                    .add_attribute("action", "dispatch_user_swap_exact_asset_out");
+            }
         }
         Swap::SmartSwapExactAssetIn(swap) => {
-            for route in swap.routes {
-                // Validate swap operations
+            // Validate swap operations of each route
+            for route in &swap.routes {
                 validate_swap_operations(
                     &route.operations,
                     remaining_asset.denom(),
                     min_asset.denom(),
                 )?;
+            }
 
-                // Get swap adapter contract address from venue name
-                let user_swap_adapter_contract_address =
-                    SWAP_VENUE_MAP.load(deps.storage, &swap.swap_venue_name)?;
+            // Get swap adapter contract address from venue name
+            let user_swap_adapter_contract_address =
+                SWAP_VENUE_MAP.load(deps.storage, &swap.swap_venue_name)?;
 
-                // Create the user swap message args
-                let user_swap_msg_args = SwapExecuteMsg::Swap {
-                    operations: route.operations,
+            // If the swap adapter can execute smart swaps, swap over all the routes at once
+            if query_swap_adapter_capabilities(&deps, &user_swap_adapter_contract_address)
+                .is_some_and(|capabilities| capabilities.smart_swap_execution)
+            {
+                // Get the user swap asset in as the sum of the route offer assets
+                let user_swap_asset_in =
+                    Asset::new(deps.api, remaining_asset.denom(), swap.amount());
+
+                // Create the user swap message args, passing the min asset amount plus the
+                // total affiliate fee amount as the min amount out all the routes must return
+                let user_swap_msg_args = SwapExecuteMsg::SmartSwap {
+                    routes: swap.routes,
+                    min_amount_out: Some(
+                        min_asset
+                            .amount()
+                            .saturating_add(total_affiliate_fee_amount),
+                    ),
                 };
 
                 // Create the user swap message
-                let user_swap_msg = route.offer_asset.into_wasm_msg(
+                let user_swap_msg = user_swap_asset_in.into_wasm_msg(
                     user_swap_adapter_contract_address.to_string(),
                     to_json_binary(&user_swap_msg_args)?,
                 )?;
 
                 response = response
                     .add_message(user_swap_msg)
-                    .add_attribute("action", "dispatch_user_swap_exact_asset_in");
+                    .add_attribute("action", "dispatch_user_smart_swap_exact_asset_in");
+            } else {
+                for route in swap.routes {
+                    // Create the user swap message args
+                    // The min amount out is only enforced over all routes by the post swap
+                    // action, since splitting it across the routes could revert a smart swap
+                    // whose routes returned enough in total.
+                    let user_swap_msg_args = SwapExecuteMsg::Swap {
+                        operations: route.operations,
+                        min_amount_out: None,
+                    };
+
+                    // Create the user swap message
+                    let user_swap_msg = route.offer_asset.into_wasm_msg(
+                        user_swap_adapter_contract_address.to_string(),
+                        to_json_binary(&user_swap_msg_args)?,
+                    )?;
+
+                    response = response
+                        .add_message(user_swap_msg)
+                        .add_attribute("action", "dispatch_user_swap_exact_asset_in");
+                }
             }
         }
     }
@@ -578,6 +667,12 @@ pub fn execute_action(
         response = handle_eureka_fee(env, ibc_info, &mut remaining_asset, response)?;
     }
 
+    // If the action is a Hyperlane transfer, then handle the interchain
+    // gas payment the same way as the ibc fees.
+    if let Action::HplTransfer { fee, fee_swap, .. } = &action {
+        response = handle_hpl_transfer_fees(&deps, fee, fee_swap, &mut remaining_asset, response)?;
+    }
+
     // Validate and determine the asset to be used for the action
     let action_asset = if exact_out {
         let min_asset = min_asset.ok_or(ContractError::NoMinAssetProvided)?;
@@ -655,6 +750,110 @@ pub fn execute_action_with_recover(
     Ok(Response::new().add_submessage(sub_msg))
 }
 
+// Sets the receiver format IBC transfers over each of the given source channels must have
+// Can only be called by the contract admin
+pub fn execute_set_ibc_receiver_formats(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receiver_formats: Vec<IbcChannelReceiverFormat>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract admin
+    assert_contract_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let mut response = Response::new().add_attribute("action", "set_ibc_receiver_formats");
+
+    // Store the receiver format of each source channel
+    for IbcChannelReceiverFormat {
+        source_channel,
+        receiver_format,
+    } in receiver_formats
+    {
+        IBC_RECEIVER_FORMATS.save(deps.storage, &source_channel, &receiver_format)?;
+
+        response = response.add_attribute("source_channel", source_channel);
+    }
+
+    Ok(response)
+}
+
+// Sets whether transfers are restricted to the allowlisted channels and warp routes
+// Can only be called by the contract admin
+pub fn execute_set_route_allowlist_enforced(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    enforced: bool,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract admin
+    assert_contract_admin(deps.as_ref(), &env, &info.sender)?;
+
+    ROUTE_ALLOWLIST_ENFORCED.save(deps.storage, &enforced)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_route_allowlist_enforced")
+        .add_attribute("enforced", enforced.to_string()))
+}
+
+// Adds and removes IBC source channels and Hyperlane warp routes from the route allowlist
+// Can only be called by the contract admin
+pub fn execute_update_route_allowlist(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    add_ibc_channels: Vec<String>,
+    remove_ibc_channels: Vec<String>,
+    add_warp_routes: Vec<HplWarpRoute>,
+    remove_warp_routes: Vec<HplWarpRoute>,
+) -> ContractResult<Response> {
+    // Enforce the caller is the contract admin
+    assert_contract_admin(deps.as_ref(), &env, &info.sender)?;
+
+    let mut response = Response::new().add_attribute("action", "update_route_allowlist");
+
+    // Add and remove the IBC source channels
+    for source_channel in add_ibc_channels {
+        ALLOWED_IBC_CHANNELS.save(deps.storage, &source_channel, &())?;
+
+        response = response.add_attribute("add_ibc_channel", source_channel);
+    }
+    for source_channel in remove_ibc_channels {
+        ALLOWED_IBC_CHANNELS.remove(deps.storage, &source_channel);
+
+        response = response.add_attribute("remove_ibc_channel", source_channel);
+    }
+
+    // Add and remove the Hyperlane warp routes, validating the warp route addresses
+    for HplWarpRoute {
+        dest_domain,
+        warp_address,
+    } in add_warp_routes
+    {
+        let checked_warp_address = deps.api.addr_validate(&warp_address)?;
+        ALLOWED_WARP_ROUTES.save(deps.storage, (dest_domain, &checked_warp_address), &())?;
+
+        response = response.add_attribute(
+            "add_warp_route",
+            format!("{dest_domain}/{checked_warp_address}"),
+        );
+    }
+    for HplWarpRoute {
+        dest_domain,
+        warp_address,
+    } in remove_warp_routes
+    {
+        let checked_warp_address = deps.api.addr_validate(&warp_address)?;
+        ALLOWED_WARP_ROUTES.remove(deps.storage, (dest_domain, &checked_warp_address));
+
+        response = response.add_attribute(
+            "remove_warp_route",
+            format!("{dest_domain}/{checked_warp_address}"),
+        );
+    }
+
+    Ok(response)
+}
+
 //////////////////////
 // HELPER FUNCTIONS //
 //////////////////////
@@ -704,6 +903,27 @@ fn validate_and_dispatch_action(
             // Validates recover address, errors if invalid
             deps.api.addr_validate(&ibc_info.recover_address)?;
 
+            // Validates the receiver of solidity ABI encoded transfers is an EVM address
+            if ibc_info.encoding.as_deref() == Some(IBC_SOLIDITY_ABI_ENCODING) {
+                validate_evm_address(&ibc_info.receiver)?;
+            }
+
+            // Validates the receiver has the format registered for the source channel, if any
+            if let Some(receiver_format) =
+                IBC_RECEIVER_FORMATS.may_load(deps.storage, &ibc_info.source_channel)?
+            {
+                receiver_format.validate(&ibc_info.receiver)?;
+            }
+
+            // Error if the route allowlist is enforced and the source channel is not allowed
+            if is_route_allowlist_enforced(&deps)?
+                && !ALLOWED_IBC_CHANNELS.has(deps.storage, &ibc_info.source_channel)
+            {
+                return Err(ContractError::IbcChannelNotAllowed {
+                    source_channel: ibc_info.source_channel,
+                });
+            }
+
             let transfer_out_coin = match action_asset {
                 Asset::Native(coin) => coin,
                 _ => return Err(ContractError::NonNativeIbcTransfer),
@@ -758,19 +978,51 @@ fn validate_and_dispatch_action(
             hook,
             metadata,
             warp_address,
+            recover_address,
+            fee,
+            ..
         } => {
-            let transfer_out_coin = match action_asset {
-                Asset::Native(coin) => coin,
-                _ => return Err(ContractError::NonNativeHplTransfer),
-            };
+            // Validates recover address if provided, errors if invalid
+            if let Some(recover_address) = &recover_address {
+                deps.api.addr_validate(recover_address)?;
+            }
+
+            // Validates the recipient is a 32 byte address, errors if invalid
+            validate_bytes32(&recipient)?;
+
+            // Error if the route allowlist is enforced and the warp route
+            // is not allowed for the destination domain
+            if is_route_allowlist_enforced(&deps)? {
+                let checked_warp_address = deps.api.addr_validate(&warp_address)?;
+                if !ALLOWED_WARP_ROUTES.has(deps.storage, (dest_domain, &checked_warp_address)) {
+                    return Err(ContractError::WarpRouteNotAllowed {
+                        dest_domain,
+                        warp_address,
+                    });
+                }
+            }
 
-            // Create the Hyperlane transfer message
-            let hpl_transfer_msg: HplExecuteMsg = HplTransfer {
-                dest_domain,
-                recipient,
-                hook,
-                metadata,
-                warp_address,
+            // Create the Hyperlane transfer message, cw20 assets are sent
+            // to the Hyperlane transfer contract with the cw20 hook message
+            let hpl_transfer_msg = match &action_asset {
+                Asset::Native(_) => to_json_binary(&HplExecuteMsg::HplTransfer {
+                    dest_domain,
+                    recipient,
+                    hook,
+                    metadata,
+                    warp_address,
+                    recover_address,
+                    fee,
+                })?,
+                Asset::Cw20(_) => to_json_binary(&HplCw20HookMsg::HplTransfer {
+                    dest_domain,
+                    recipient,
+                    hook,
+                    metadata,
+                    warp_address,
+                    recover_address,
+                    fee,
+                })?,
             };
 
             // Get the Hyperlane transfer adapter contract address
@@ -778,22 +1030,59 @@ fn validate_and_dispatch_action(
                 HYPERLANE_TRANSFER_CONTRACT_ADDRESS.load(deps.storage)?;
 
             // Send the Hyperlane transfer by calling the Hyperlane transfer contract
-            let hpl_transfer_msg = WasmMsg::Execute {
-                contract_addr: hpl_transfer_contract_address.to_string(),
-                msg: to_json_binary(&hpl_transfer_msg)?,
-                funds: vec![transfer_out_coin],
-            };
+            let hpl_transfer_msg = action_asset
+                .into_wasm_msg(hpl_transfer_contract_address.to_string(), hpl_transfer_msg)?;
 
             // Add the Hyperlane transfer message to the response
             response = response
                 .add_message(hpl_transfer_msg)
                 .add_attribute("action", "dispatch_action_ibc_transfer");
         }
+        Action::PlaceLimitOrder {
+            swap_venue_name,
+            limit_order,
+        } => {
+            // Error if the limit order receiver is not a valid address on the current chain
+            deps.api.addr_validate(&limit_order.receiver)?;
+
+            let limit_order_coin = match action_asset {
+                Asset::Native(coin) => coin,
+                _ => return Err(ContractError::NonNativeLimitOrder),
+            };
+
+            // Get the swap adapter contract address of the swap venue placing the limit order
+            let swap_adapter_contract_address =
+                SWAP_VENUE_MAP.load(deps.storage, &swap_venue_name)?;
+
+            // Place the limit order by calling the swap adapter contract
+            let place_limit_order_msg = WasmMsg::Execute {
+                contract_addr: swap_adapter_contract_address.to_string(),
+                msg: to_json_binary(&DualityExecuteMsg::LimitOrder(
+                    DualityLimitOrderExecuteMsg::PlaceLimitOrder { limit_order },
+                ))?,
+                funds: vec![limit_order_coin],
+            };
+
+            // Add the place limit order message to the response
+            response = response
+                .add_message(place_limit_order_msg)
+                .add_attribute("action", "dispatch_action_place_limit_order");
+        }
     };
 
     Ok(response)
 }
 
+// ROUTE ALLOWLIST HELPER FUNCTIONS
+
+// Returns whether transfers are restricted to the allowlisted channels and warp routes,
+// transfers are unrestricted if enforcement was never set
+fn is_route_allowlist_enforced(deps: &DepsMut) -> ContractResult<bool> {
+    Ok(ROUTE_ALLOWLIST_ENFORCED
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
+
 // IBC FEE HELPER FUNCTIONS
 
 // Creates the fee swap and ibc transfer messages and adds them to the response
@@ -852,6 +1141,57 @@ fn handle_ibc_transfer_fees(
     Ok(response)
 }
 
+// Creates the fee swap and interchain gas payment messages and adds them to the response
+fn handle_hpl_transfer_fees(
+    deps: &DepsMut,
+    hpl_fee_coin: &Option<Coin>,
+    fee_swap: &Option<SwapExactAssetOut>,
+    remaining_asset: &mut Asset,
+    mut response: Response,
+) -> Result<Response, ContractError> {
+    if let Some(fee_swap) = fee_swap {
+        let hpl_fee_coin = hpl_fee_coin
+            .as_ref()
+            .ok_or(ContractError::FeeSwapWithoutHplFee)?;
+
+        // NOTE: this call mutates remaining_asset by deducting the fee swap asset in amount from it
+        let fee_swap_msg =
+            verify_and_create_fee_swap_msg(deps, fee_swap, remaining_asset, hpl_fee_coin)?;
+
+        // Add the fee swap message to the response
+        response = response
+            .add_message(fee_swap_msg)
+            .add_attribute("action", "dispatch_fee_swap");
+    } else if let Some(hpl_fee_coin) = hpl_fee_coin {
+        if remaining_asset.denom() != hpl_fee_coin.denom {
+            return Err(ContractError::HplFeeDenomDiffersFromAssetReceived);
+        }
+
+        // Deduct the hpl_fee_coin amount from the remaining asset amount
+        remaining_asset.sub(hpl_fee_coin.amount)?;
+    }
+
+    // Dispatch the interchain gas payment bank send to the hyperlane transfer adapter contract if needed
+    if let Some(hpl_fee_coin) = hpl_fee_coin {
+        // Get the hyperlane transfer adapter contract address
+        let hpl_transfer_contract_address =
+            HYPERLANE_TRANSFER_CONTRACT_ADDRESS.load(deps.storage)?;
+
+        // Create the interchain gas payment bank send message
+        let hpl_fee_msg = BankMsg::Send {
+            to_address: hpl_transfer_contract_address.to_string(),
+            amount: vec![hpl_fee_coin.clone()],
+        };
+
+        // Add the interchain gas payment message to the response
+        response = response
+            .add_message(hpl_fee_msg)
+            .add_attribute("action", "dispatch_hpl_fee_bank_send");
+    }
+
+    Ok(response)
+}
+
 // Verify and dispath the eureka fee payment,
 // deducting the eureka fee amount from the remaining asset amount
 fn handle_eureka_fee(
@@ -900,13 +1240,13 @@ fn verify_and_create_fee_swap_msg(
     deps: &DepsMut,
     fee_swap: &SwapExactAssetOut,
     remaining_asset: &mut Asset,
-    ibc_fee_coin: &Coin,
+    fee_coin: &Coin,
 ) -> ContractResult<WasmMsg> {
     // Validate swap operations
     validate_swap_operations(
         &fee_swap.operations,
         remaining_asset.denom(),
-        &ibc_fee_coin.denom,
+        &fee_coin.denom,
     )?;
 
     // Get swap adapter contract address from venue name
@@ -918,7 +1258,7 @@ fn verify_and_create_fee_swap_msg(
         deps,
         &fee_swap_adapter_contract_address,
         fee_swap,
-        &ibc_fee_coin.clone().into(),
+        &fee_coin.clone().into(),
     )?;
 
     // Verify the fee swap in denom is the same as the denom received from the message to the contract
@@ -930,8 +1270,12 @@ fn verify_and_create_fee_swap_msg(
     // Error if swap requires more than the remaining asset amount
     remaining_asset.sub(fee_swap_asset_in.amount())?;
 
-    // Create the fee swap message args
-    let fee_swap_msg_args: SwapExecuteMsg = fee_swap.clone().into();
+    // Create the fee swap message args, passing the fee amount
+    // as the min amount out the swap venue must return
+    let fee_swap_msg_args = SwapExecuteMsg::Swap {
+        operations: fee_swap.operations.clone(),
+        min_amount_out: Some(fee_coin.amount),
+    };
 
     // Create the fee swap message
     let fee_swap_msg = fee_swap_asset_in.into_wasm_msg(
@@ -985,3 +1329,18 @@ fn query_swap_asset_in(
 
     Ok(fee_swap_asset_in)
 }
+
+// Unexposed query helper function that queries the swap adapter contract capabilities.
+// Returns None if the swap adapter contract does not support the capabilities query,
+// in which case it is treated as not supporting any optional execution.
+fn query_swap_adapter_capabilities(
+    deps: &DepsMut,
+    swap_adapter_contract_address: &Addr,
+) -> Option<CapabilitiesResponse> {
+    deps.querier
+        .query_wasm_smart(
+            swap_adapter_contract_address,
+            &SwapQueryMsg::Capabilities {},
+        )
+        .ok()
+}
@@ -1,5 +1,16 @@
-use crate::state::{IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP};
-use cosmwasm_std::{Addr, Deps, StdResult};
+use crate::state::{
+    ALLOWED_IBC_CHANNELS, ALLOWED_WARP_ROUTES, IBC_RECEIVER_FORMATS, IBC_TRANSFER_CONTRACT_ADDRESS,
+    ROUTE_ALLOWLIST_ENFORCED, SWAP_VENUE_MAP,
+};
+use cosmwasm_std::{Addr, Deps, HexBinary, Order, StdError, StdResult};
+use skip::{
+    address::{
+        bech32_to_bytes32, bytes32_to_bech32, bytes32_to_evm_address, evm_address_to_bytes32,
+    },
+    error::SkipError,
+    hyperlane::HplWarpRoute,
+    ibc::IbcReceiverFormat,
+};
 
 // Queries the swap venue map by name and returns the swap adapter contract address if it exists
 pub fn query_swap_venue_adapter_contract(deps: Deps, name: String) -> StdResult<Addr> {
@@ -10,3 +21,82 @@ pub fn query_swap_venue_adapter_contract(deps: Deps, name: String) -> StdResult<
 pub fn query_ibc_transfer_adapter_contract(deps: Deps) -> StdResult<Addr> {
     IBC_TRANSFER_CONTRACT_ADDRESS.load(deps.storage)
 }
+
+// Queries the receiver format of the source channel, returning any receiver if none is set
+pub fn query_ibc_receiver_format(
+    deps: Deps,
+    source_channel: String,
+) -> StdResult<IbcReceiverFormat> {
+    Ok(IBC_RECEIVER_FORMATS
+        .may_load(deps.storage, &source_channel)?
+        .unwrap_or(IbcReceiverFormat::Any {}))
+}
+
+// Queries whether transfers are restricted to the allowlisted channels and warp routes
+pub fn query_route_allowlist_enforced(deps: Deps) -> StdResult<bool> {
+    Ok(ROUTE_ALLOWLIST_ENFORCED
+        .may_load(deps.storage)?
+        .unwrap_or_default())
+}
+
+// Queries the allowlisted IBC source channels
+pub fn query_allowed_ibc_channels(deps: Deps) -> StdResult<Vec<String>> {
+    ALLOWED_IBC_CHANNELS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+// Queries the allowlisted Hyperlane warp routes, only those
+// to the destination domain if one is given
+pub fn query_allowed_warp_routes(
+    deps: Deps,
+    dest_domain: Option<u32>,
+) -> StdResult<Vec<HplWarpRoute>> {
+    match dest_domain {
+        Some(dest_domain) => ALLOWED_WARP_ROUTES
+            .prefix(dest_domain)
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|warp_address| {
+                Ok(HplWarpRoute {
+                    dest_domain,
+                    warp_address: warp_address?.to_string(),
+                })
+            })
+            .collect(),
+        None => ALLOWED_WARP_ROUTES
+            .keys(deps.storage, None, None, Order::Ascending)
+            .map(|key| {
+                let (dest_domain, warp_address) = key?;
+                Ok(HplWarpRoute {
+                    dest_domain,
+                    warp_address: warp_address.to_string(),
+                })
+            })
+            .collect(),
+    }
+}
+
+// Converts the bech32 address into its 32 byte form
+pub fn query_bech32_to_bytes32(address: String) -> StdResult<HexBinary> {
+    bech32_to_bytes32(&address).map_err(address_conversion_error)
+}
+
+// Converts the 32 byte address into a bech32 address with the given prefix
+pub fn query_bytes32_to_bech32(bytes32: HexBinary, prefix: String) -> StdResult<String> {
+    bytes32_to_bech32(&bytes32, &prefix).map_err(address_conversion_error)
+}
+
+// Converts the EVM address into its 32 byte form
+pub fn query_evm_address_to_bytes32(address: String) -> StdResult<HexBinary> {
+    evm_address_to_bytes32(&address).map_err(address_conversion_error)
+}
+
+// Converts the 32 byte address into a checksummed EVM address
+pub fn query_bytes32_to_evm_address(bytes32: HexBinary) -> StdResult<String> {
+    bytes32_to_evm_address(&bytes32).map_err(address_conversion_error)
+}
+
+// Converts an address conversion error into a query error
+fn address_conversion_error(err: SkipError) -> StdError {
+    StdError::generic_err(err.to_string())
+}
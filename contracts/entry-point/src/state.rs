@@ -1,6 +1,7 @@
 use crate::reply::RecoverTempStorage;
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, Map};
+use skip::ibc::IbcReceiverFormat;
 
 pub const BLOCKED_CONTRACT_ADDRESSES: Map<&Addr, ()> = Map::new("blocked_contract_addresses");
 pub const SWAP_VENUE_MAP: Map<&str, Addr> = Map::new("swap_venue_map");
@@ -8,6 +9,17 @@ pub const IBC_TRANSFER_CONTRACT_ADDRESS: Item<Addr> = Item::new("ibc_transfer_co
 pub const HYPERLANE_TRANSFER_CONTRACT_ADDRESS: Item<Addr> =
     Item::new("hyperlane_transfer_contract_address");
 
+// Admin maintained registry of the receiver format IBC transfers
+// over each source channel must have, keyed by source channel
+pub const IBC_RECEIVER_FORMATS: Map<&str, IbcReceiverFormat> = Map::new("ibc_receiver_formats");
+
+// Admin maintained allowlists of the IBC source channels and the Hyperlane warp routes,
+// keyed by destination domain and warp route address, that transfers are restricted
+// to while enforced. Transfers are unrestricted while not enforced or unset.
+pub const ROUTE_ALLOWLIST_ENFORCED: Item<bool> = Item::new("route_allowlist_enforced");
+pub const ALLOWED_IBC_CHANNELS: Map<&str, ()> = Map::new("allowed_ibc_channels");
+pub const ALLOWED_WARP_ROUTES: Map<(u32, &Addr), ()> = Map::new("allowed_warp_routes");
+
 // Temporary state to save variables to be used in
 // reply handling in case of recovering from an error
 pub const RECOVER_TEMP_STORAGE: Item<RecoverTempStorage> = Item::new("recover_temp_storage");
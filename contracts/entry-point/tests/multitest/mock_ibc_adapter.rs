@@ -0,0 +1,135 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult,
+};
+use cw_storage_plus::{Item, Map};
+use skip::ibc::{ExecuteMsg, InstantiateMsg, MigrateMsg};
+
+// The mock ibc transfer adapter holds the transferred coins in flight until the
+// test resolves the packet lifecycle through sudo, mimicking the ibc hooks adapter:
+// an ack burns the coins as they left the chain, while an error or timeout refunds
+// them to the recover address.
+#[cw_serde]
+pub struct InFlightTransfer {
+    pub source_channel: String,
+    pub receiver: String,
+    pub recover_address: String,
+    pub coin: Coin,
+}
+
+#[cw_serde]
+pub enum SudoMsg {
+    Ack { sequence: u64 },
+    Error { sequence: u64 },
+    Timeout { sequence: u64 },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    InFlightTransfers {},
+}
+
+const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+const NEXT_SEQUENCE: Item<u64> = Item::new("next_sequence");
+const IN_FLIGHT_TRANSFERS: Map<u64, InFlightTransfer> = Map::new("in_flight_transfers");
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    ENTRY_POINT_CONTRACT_ADDRESS.save(
+        deps.storage,
+        &deps.api.addr_validate(&msg.entry_point_contract_address)?,
+    )?;
+
+    Ok(Response::new())
+}
+
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    ENTRY_POINT_CONTRACT_ADDRESS.save(
+        deps.storage,
+        &deps.api.addr_validate(&msg.entry_point_contract_address)?,
+    )?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    let ExecuteMsg::IbcTransfer {
+        info: ibc_info,
+        coin,
+        ..
+    } = msg;
+
+    if info.sender != ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(StdError::generic_err("Unauthorized"));
+    }
+
+    if info.funds != vec![coin.clone()] {
+        return Err(StdError::generic_err("ibc transfer coin not sent"));
+    }
+
+    let sequence = NEXT_SEQUENCE.may_load(deps.storage)?.unwrap_or(1);
+    NEXT_SEQUENCE.save(deps.storage, &(sequence + 1))?;
+
+    IN_FLIGHT_TRANSFERS.save(
+        deps.storage,
+        sequence,
+        &InFlightTransfer {
+            source_channel: ibc_info.source_channel,
+            receiver: ibc_info.receiver,
+            recover_address: ibc_info.recover_address,
+            coin,
+        },
+    )?;
+
+    Ok(Response::new().add_attribute("sequence", sequence.to_string()))
+}
+
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> StdResult<Response> {
+    let (sequence, msg) = match msg {
+        SudoMsg::Ack { sequence } => {
+            let transfer = IN_FLIGHT_TRANSFERS.load(deps.storage, sequence)?;
+
+            (
+                sequence,
+                BankMsg::Burn {
+                    amount: vec![transfer.coin],
+                },
+            )
+        }
+        SudoMsg::Error { sequence } | SudoMsg::Timeout { sequence } => {
+            let transfer = IN_FLIGHT_TRANSFERS.load(deps.storage, sequence)?;
+
+            (
+                sequence,
+                BankMsg::Send {
+                    to_address: transfer.recover_address,
+                    amount: vec![transfer.coin],
+                },
+            )
+        }
+    };
+
+    IN_FLIGHT_TRANSFERS.remove(deps.storage, sequence);
+
+    Ok(Response::new().add_message(msg))
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::InFlightTransfers {} => to_json_binary(
+            &IN_FLIGHT_TRANSFERS
+                .range(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<(u64, InFlightTransfer)>>>()?,
+        ),
+    }
+}
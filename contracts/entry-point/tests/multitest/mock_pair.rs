@@ -0,0 +1,126 @@
+use astroport::{
+    asset::AssetInfo,
+    pair::{
+        ExecuteMsg as PairExecuteMsg, QueryMsg as PairQueryMsg, ReverseSimulationResponse,
+        SimulationResponse,
+    },
+};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo, Response,
+    StdError, StdResult, Uint128,
+};
+use cw_storage_plus::Item;
+
+// The mock pair is a constant price astroport pair between two native denoms,
+// swapping `price` ask denom per offer denom, and 1 / `price` the other way.
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub offer_denom: String,
+    pub ask_denom: String,
+    pub price: Decimal,
+}
+
+const CONFIG: Item<InstantiateMsg> = Item::new("config");
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    CONFIG.save(deps.storage, &msg)?;
+
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: PairExecuteMsg,
+) -> StdResult<Response> {
+    let PairExecuteMsg::Swap {
+        offer_asset, to, ..
+    } = msg
+    else {
+        return Err(StdError::generic_err("mock pair only supports swaps"));
+    };
+
+    let offer_denom = native_denom(&offer_asset.info)?;
+
+    // Ensure the offer asset was sent along with the swap
+    if info.funds != vec![Coin::new(offer_asset.amount.u128(), offer_denom.clone())] {
+        return Err(StdError::generic_err("offer asset not sent"));
+    }
+
+    let (ask_denom, return_amount) = simulate(deps.as_ref(), &offer_denom, offer_asset.amount)?;
+
+    let to_address = match to {
+        Some(to) => deps.api.addr_validate(&to)?.to_string(),
+        None => info.sender.to_string(),
+    };
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address,
+            amount: vec![Coin::new(return_amount.u128(), ask_denom)],
+        })
+        .add_attribute("return_amount", return_amount))
+}
+
+pub fn query(deps: Deps, _env: Env, msg: PairQueryMsg) -> StdResult<Binary> {
+    match msg {
+        PairQueryMsg::Simulation { offer_asset, .. } => {
+            let (_, return_amount) =
+                simulate(deps, &native_denom(&offer_asset.info)?, offer_asset.amount)?;
+
+            to_json_binary(&SimulationResponse {
+                return_amount,
+                spread_amount: Uint128::zero(),
+                commission_amount: Uint128::zero(),
+            })
+        }
+        PairQueryMsg::ReverseSimulation { ask_asset, .. } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            // Reverse simulate by swapping the ask asset at the opposite price, rounding down
+            let ask_denom = native_denom(&ask_asset.info)?;
+            let offer_amount = if ask_denom == config.ask_denom {
+                ask_asset.amount * (Decimal::one() / config.price)
+            } else {
+                ask_asset.amount * config.price
+            };
+
+            to_json_binary(&ReverseSimulationResponse {
+                offer_amount,
+                spread_amount: Uint128::zero(),
+                commission_amount: Uint128::zero(),
+            })
+        }
+        _ => Err(StdError::generic_err("mock pair only supports simulations")),
+    }
+}
+
+// Returns the ask denom and return amount of swapping the offer amount
+fn simulate(deps: Deps, offer_denom: &str, offer_amount: Uint128) -> StdResult<(String, Uint128)> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if offer_denom == config.offer_denom {
+        Ok((config.ask_denom, offer_amount * config.price))
+    } else if offer_denom == config.ask_denom {
+        Ok((
+            config.offer_denom,
+            offer_amount * (Decimal::one() / config.price),
+        ))
+    } else {
+        Err(StdError::generic_err("denom not in mock pair"))
+    }
+}
+
+fn native_denom(asset_info: &AssetInfo) -> StdResult<String> {
+    match asset_info {
+        AssetInfo::NativeToken { denom } => Ok(denom.clone()),
+        AssetInfo::Token { .. } => Err(StdError::generic_err("mock pair only supports native")),
+    }
+}
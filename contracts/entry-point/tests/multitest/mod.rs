@@ -0,0 +1,239 @@
+// Full stack cw-multi-test fixture deploying the real entry point contract and the real
+// astroport swap adapter against mock astroport pairs, plus a mock ibc transfer adapter
+// whose packet lifecycle (ack, error, timeout) is resolved by the test through sudo.
+pub mod mock_ibc_adapter;
+pub mod mock_pair;
+
+use cosmwasm_std::{Addr, Coin, Decimal};
+use cw_multi_test::{
+    addons::{MockAddressGenerator, MockApiBech32},
+    App, AppBuilder, AppResponse, BankKeeper, ContractWrapper, Executor, WasmKeeper,
+};
+use skip::{
+    entry_point::{ExecuteMsg, InstantiateMsg},
+    ibc::{InstantiateMsg as IbcInstantiateMsg, MigrateMsg as IbcMigrateMsg},
+    swap::{
+        InstantiateMsg as SwapInstantiateMsg, MigrateMsg as SwapMigrateMsg, SwapOperation,
+        SwapVenue,
+    },
+};
+
+pub const ASTROPORT_SWAP_VENUE_NAME: &str = "astroport";
+
+pub type SkipApp = App<BankKeeper, MockApiBech32>;
+
+pub struct Fixture {
+    pub app: SkipApp,
+    pub owner: Addr,
+    pub entry_point: Addr,
+    pub astroport_adapter: Addr,
+    pub ibc_adapter: Addr,
+    mock_pair_code_id: u64,
+}
+
+impl Fixture {
+    // Deploys the entry point, the astroport swap adapter and the mock ibc transfer adapter.
+    //
+    // The adapters need the entry point address and the entry point needs the adapter
+    // addresses, so the adapters are instantiated with the owner as entry point and
+    // migrated to the real entry point address once it is instantiated.
+    pub fn deploy() -> Self {
+        let mut app = AppBuilder::new()
+            .with_api(MockApiBech32::new("osmo"))
+            .with_wasm(WasmKeeper::new().with_address_generator(MockAddressGenerator))
+            .build(|_, _, _| {});
+
+        let owner = app.api().addr_make("owner");
+
+        let entry_point_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                skip_go_entry_point::contract::execute,
+                skip_go_entry_point::contract::instantiate,
+                skip_go_entry_point::contract::query,
+            )
+            .with_reply_empty(skip_go_entry_point::contract::reply),
+        ));
+
+        let astroport_adapter_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                skip_go_swap_adapter_astroport::contract::execute,
+                skip_go_swap_adapter_astroport::contract::instantiate,
+                skip_go_swap_adapter_astroport::contract::query,
+            )
+            .with_migrate_empty(skip_go_swap_adapter_astroport::contract::migrate),
+        ));
+
+        let ibc_adapter_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                mock_ibc_adapter::execute,
+                mock_ibc_adapter::instantiate,
+                mock_ibc_adapter::query,
+            )
+            .with_sudo_empty(mock_ibc_adapter::sudo)
+            .with_migrate_empty(mock_ibc_adapter::migrate),
+        ));
+
+        let mock_pair_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            mock_pair::execute,
+            mock_pair::instantiate,
+            mock_pair::query,
+        )));
+
+        let astroport_adapter = app
+            .instantiate_contract(
+                astroport_adapter_code_id,
+                owner.clone(),
+                &SwapInstantiateMsg {
+                    entry_point_contract_address: owner.to_string(),
+                },
+                &[],
+                "skip_swap_adapter:astroport",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        let ibc_adapter = app
+            .instantiate_contract(
+                ibc_adapter_code_id,
+                owner.clone(),
+                &IbcInstantiateMsg {
+                    entry_point_contract_address: owner.to_string(),
+                },
+                &[],
+                "skip_ibc_adapter:mock",
+                Some(owner.to_string()),
+            )
+            .unwrap();
+
+        let entry_point = app
+            .instantiate_contract(
+                entry_point_code_id,
+                owner.clone(),
+                &InstantiateMsg {
+                    swap_venues: vec![SwapVenue {
+                        name: ASTROPORT_SWAP_VENUE_NAME.to_string(),
+                        adapter_contract_address: astroport_adapter.to_string(),
+                    }],
+                    ibc_transfer_contract_address: ibc_adapter.to_string(),
+                    hyperlane_transfer_contract_address: None,
+                },
+                &[],
+                "skip_entry_point",
+                None,
+            )
+            .unwrap();
+
+        app.migrate_contract(
+            owner.clone(),
+            astroport_adapter.clone(),
+            &SwapMigrateMsg {
+                entry_point_contract_address: entry_point.to_string(),
+            },
+            astroport_adapter_code_id,
+        )
+        .unwrap();
+
+        app.migrate_contract(
+            owner.clone(),
+            ibc_adapter.clone(),
+            &IbcMigrateMsg {
+                entry_point_contract_address: entry_point.to_string(),
+            },
+            ibc_adapter_code_id,
+        )
+        .unwrap();
+
+        Self {
+            app,
+            owner,
+            entry_point,
+            astroport_adapter,
+            ibc_adapter,
+            mock_pair_code_id,
+        }
+    }
+
+    // Creates an address from the given label
+    pub fn addr(&self, label: &str) -> Addr {
+        self.app.api().addr_make(label)
+    }
+
+    // Deploys a mock astroport pair funded with the given liquidity, returning
+    // the swap operation swapping the offer denom to the ask denom on it
+    pub fn create_pair(
+        &mut self,
+        offer_denom: &str,
+        ask_denom: &str,
+        price: Decimal,
+        liquidity: Vec<Coin>,
+    ) -> SwapOperation {
+        let pair = self
+            .app
+            .instantiate_contract(
+                self.mock_pair_code_id,
+                self.owner.clone(),
+                &mock_pair::InstantiateMsg {
+                    offer_denom: offer_denom.to_string(),
+                    ask_denom: ask_denom.to_string(),
+                    price,
+                },
+                &[],
+                "mock_astroport_pair",
+                None,
+            )
+            .unwrap();
+
+        self.set_balance(&pair, liquidity);
+
+        SwapOperation {
+            pool: pair.to_string(),
+            denom_in: offer_denom.to_string(),
+            denom_out: ask_denom.to_string(),
+            interface: None,
+        }
+    }
+
+    // Overwrites the bank balance of the given address
+    pub fn set_balance(&mut self, addr: &Addr, coins: Vec<Coin>) {
+        self.app
+            .init_modules(|router, _, storage| router.bank.init_balance(storage, addr, coins))
+            .unwrap();
+    }
+
+    pub fn balance(&self, addr: &Addr, denom: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(addr, denom)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    // Executes an entry point message from the sender with the given funds,
+    // returning the root cause of the error if the transaction failed
+    pub fn execute_entry_point(
+        &mut self,
+        sender: &Addr,
+        msg: &ExecuteMsg,
+        funds: &[Coin],
+    ) -> Result<AppResponse, String> {
+        self.app
+            .execute_contract(sender.clone(), self.entry_point.clone(), msg, funds)
+            .map_err(|err| err.root_cause().to_string())
+    }
+
+    // Resolves an in flight ibc transfer of the mock ibc transfer adapter
+    pub fn ibc_lifecycle(&mut self, msg: mock_ibc_adapter::SudoMsg) -> AppResponse {
+        self.app.wasm_sudo(self.ibc_adapter.clone(), &msg).unwrap()
+    }
+
+    pub fn in_flight_transfers(&self) -> Vec<(u64, mock_ibc_adapter::InFlightTransfer)> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                self.ibc_adapter.clone(),
+                &mock_ibc_adapter::QueryMsg::InFlightTransfers {},
+            )
+            .unwrap()
+    }
+}
@@ -1,7 +1,7 @@
 use cosmwasm_std::{
     testing::{mock_dependencies_with_balances, mock_env, mock_info},
-    to_json_binary, Addr, BankMsg, Coin, ContractResult, OverflowError, OverflowOperation,
-    QuerierResult,
+    to_json_binary, Addr, BankMsg, Coin, ContractResult, HexBinary, OverflowError,
+    OverflowOperation, QuerierResult,
     ReplyOn::Never,
     SubMsg, SystemResult, Timestamp, Uint128, WasmMsg, WasmQuery,
 };
@@ -9,15 +9,26 @@ use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg};
 use skip::{
     asset::Asset,
     entry_point::{Action, ExecuteMsg},
-    error::SkipError::Overflow,
-    ibc::{EurekaFee, ExecuteMsg as IbcTransferExecuteMsg, IbcFee, IbcInfo},
+    error::SkipError::{Bech32PrefixMismatch, InvalidBytes32Length, InvalidEvmAddress, Overflow},
+    hyperlane::{Cw20HookMsg as HplCw20HookMsg, ExecuteMsg as HplTransferExecuteMsg},
+    ibc::{EurekaFee, ExecuteMsg as IbcTransferExecuteMsg, IbcFee, IbcInfo, IbcReceiverFormat},
+    swap::{
+        DualityExecuteMsg, DualityLimitOrderExecuteMsg, LimitOrder, LimitOrderType,
+        SwapExactAssetOut, SwapOperation,
+    },
 };
 use skip_go_entry_point::{
     error::ContractError,
-    state::{BLOCKED_CONTRACT_ADDRESSES, IBC_TRANSFER_CONTRACT_ADDRESS},
+    state::{
+        BLOCKED_CONTRACT_ADDRESSES, HYPERLANE_TRANSFER_CONTRACT_ADDRESS, IBC_RECEIVER_FORMATS,
+        IBC_TRANSFER_CONTRACT_ADDRESS, SWAP_VENUE_MAP,
+    },
 };
 use test_case::test_case;
 
+// 32 byte Hyperlane recipient address
+const HPL_RECIPIENT: &str = "0000000000000000000000000000000000000000000000000000000000000101";
+
 /*
 Test Cases:
 
@@ -26,8 +37,12 @@ Expect Response
     - Native Asset Transfer
     - Cw20 Asset Transfer
     - Ibc Transfer
+    - Ibc Transfer w/ Receiver matching the registered channel receiver format
     - Native Asset Contract Call
     - Cw20 Asset Contract Call
+    - Native Asset Place Limit Order
+    - Hyperlane Transfer w/ Hyperlane Fee of same denom as remaining asset
+    - Cw20 Asset Hyperlane Transfer
 
     // Exact Out
     - Ibc Transfer With Exact Out Set To True
@@ -40,13 +55,19 @@ Expect Error
     - Remaining Asset Less Than Min Asset - Native
     - Remaining Asset Less Than Min Asset - CW20
     - Contract Call Address Blocked
+    - Cw20 Asset Place Limit Order
     - Ibc Transfer w/ IBC Fees of different denom than min coin no fee swap
+    - Ibc Transfer w/ Receiver not matching the registered channel receiver format
+    - Hyperlane Transfer w/ Hyperlane Fee of different denom than remaining asset no fee swap
+    - Hyperlane Transfer w/ Fee Swap and no Hyperlane Fee
+    - Hyperlane Transfer w/ Recipient not 32 bytes
 
     // Eureka
     - Eureka fee timeout has passed
     - Eureka fee greater than remaining asset
     - Eureka fee denom different than remaining asset
     - Eureka fee decreases remaining asset below min asset
+    - Solidity ABI encoded Ibc Transfer w/ Receiver not an EVM address
  */
 
 // Define test parameters
@@ -159,6 +180,53 @@ struct Params {
         expected_error: None,
     };
     "Ibc Transfer")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-1".to_string(),
+                receiver: "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+                encoding: None,
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "ibc_transfer_adapter".to_string(),
+                msg: to_json_binary(&IbcTransferExecuteMsg::IbcTransfer {
+                    info: IbcInfo {
+                        source_channel: "channel-1".to_string(),
+                        receiver: "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw".to_string(),
+                        memo: "".to_string(),
+                        fee: None,
+                        recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                            .to_string(),
+                        encoding: None,
+                        eureka_fee: None,
+                    },
+                    coin: Coin::new(1_000_000, "os"),
+                    timeout_timestamp: 101,
+                })
+                .unwrap(),
+                funds: vec![Coin::new(1_000_000, "os")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Ibc Transfer w/ Receiver matching the registered channel receiver format")]
 #[test_case(
     Params {
         info_funds: vec![Coin::new(1_000_000, "os")],
@@ -279,6 +347,144 @@ struct Params {
         expected_error: None,
     };
     "Cw20 Asset Contract Call")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::PlaceLimitOrder {
+            swap_venue_name: "swap_venue_name".to_string(),
+            limit_order: LimitOrder {
+                denom_out: "un".to_string(),
+                tick_index_in_to_out: 100,
+                order_type: LimitOrderType::GoodTilCancelled,
+                max_amount_out: None,
+                receiver: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+            },
+        },
+        exact_out: false,
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_venue_adapter".to_string(),
+                msg: to_json_binary(&DualityExecuteMsg::LimitOrder(
+                    DualityLimitOrderExecuteMsg::PlaceLimitOrder {
+                        limit_order: LimitOrder {
+                            denom_out: "un".to_string(),
+                            tick_index_in_to_out: 100,
+                            order_type: LimitOrderType::GoodTilCancelled,
+                            max_amount_out: None,
+                            receiver: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                        },
+                    },
+                )).unwrap(),
+                funds: vec![Coin::new(1_000_000, "os")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Native Asset Place Limit Order")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::HplTransfer {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex(HPL_RECIPIENT).unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: Some(Coin::new(100_000, "os")),
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "hyperlane_transfer_adapter".to_string(),
+                    amount: vec![Coin::new(100_000, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "hyperlane_transfer_adapter".to_string(),
+                    msg: to_json_binary(&HplTransferExecuteMsg::HplTransfer {
+                        dest_domain: 1,
+                        recipient: HexBinary::from_hex(HPL_RECIPIENT).unwrap(),
+                        hook: None,
+                        metadata: None,
+                        warp_address: "warp_address".to_string(),
+                        recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+                        fee: Some(Coin::new(100_000, "os")),
+                    })
+                    .unwrap(),
+                    funds: vec![Coin::new(900_000, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "Hyperlane Transfer w/ Hyperlane Fee of same denom as remaining asset")]
+#[test_case(
+    Params {
+        info_funds: vec![],
+        sent_asset: Some(Asset::Cw20(Cw20Coin{
+            address: "neutron123".to_string(),
+            amount: Uint128::new(1_000_000),
+        })),
+        min_asset: None,
+        action: Action::HplTransfer {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex(HPL_RECIPIENT).unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: None,
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "neutron123".to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                    contract: "hyperlane_transfer_adapter".to_string(),
+                    amount: Uint128::new(1_000_000),
+                    msg: to_json_binary(&HplCw20HookMsg::HplTransfer {
+                        dest_domain: 1,
+                        recipient: HexBinary::from_hex(HPL_RECIPIENT).unwrap(),
+                        hook: None,
+                        metadata: None,
+                        warp_address: "warp_address".to_string(),
+                        recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+                        fee: None,
+                    })
+                    .unwrap(),
+                })
+                .unwrap(),
+                funds: vec![],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        }],
+        expected_error: None,
+    };
+    "Cw20 Asset Hyperlane Transfer")]
 #[test_case(
     Params {
         info_funds: vec![Coin::new(1_000_000, "os")],
@@ -504,6 +710,57 @@ struct Params {
         expected_error: Some(ContractError::RemainingAssetAndEurekaFeeDenomMismatch),
     };
     "Eureka fee denom different than remaining asset - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-1".to_string(),
+                receiver: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+                encoding: None,
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(Bech32PrefixMismatch {
+            address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+            prefix: "osmo".to_string(),
+        })),
+    };
+    "Ibc Transfer w/ Receiver not matching the registered channel receiver format - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5"
+                    .to_string(),
+                encoding: Some("application/x-solidity-abi".to_string()),
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(InvalidEvmAddress {
+            address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+        })),
+    };
+    "Solidity ABI encoded Ibc Transfer w/ Receiver not an EVM address - Expect Error")]
 #[test_case(
     Params {
         info_funds: vec![Coin::new(1_000_000, "os")],
@@ -579,6 +836,98 @@ struct Params {
         expected_error: Some(ContractError::ContractCallAddressBlocked),
     };
     "Contract Call Address Blocked - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![],
+        sent_asset: Some(Asset::Cw20(Cw20Coin{
+            address: "neutron123".to_string(),
+            amount: Uint128::new(1_000_000),
+        })),
+        min_asset: None,
+        action: Action::PlaceLimitOrder {
+            swap_venue_name: "swap_venue_name".to_string(),
+            limit_order: LimitOrder {
+                denom_out: "un".to_string(),
+                tick_index_in_to_out: 100,
+                order_type: LimitOrderType::GoodTilCancelled,
+                max_amount_out: None,
+                receiver: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+            },
+        },
+        exact_out: false,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::NonNativeLimitOrder),
+    };
+    "Cw20 Asset Place Limit Order - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::HplTransfer {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex(HPL_RECIPIENT).unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: Some(Coin::new(100_000, "un")),
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::HplFeeDenomDiffersFromAssetReceived),
+    };
+    "Hyperlane Transfer w/ Hyperlane Fee of different denom than remaining asset no fee swap - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::HplTransfer {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex(HPL_RECIPIENT).unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: None,
+            fee_swap: Some(SwapExactAssetOut {
+                swap_venue_name: "swap_venue_name".to_string(),
+                operations: vec![SwapOperation {
+                    pool: "pool".to_string(),
+                    denom_in: "os".to_string(),
+                    denom_out: "un".to_string(),
+                    interface: None,
+                }],
+                refund_address: None,
+            }),
+        },
+        exact_out: false,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::FeeSwapWithoutHplFee),
+    };
+    "Hyperlane Transfer w/ Fee Swap and no Hyperlane Fee - Expect Error")]
+#[test_case(
+    Params {
+        info_funds: vec![Coin::new(1_000_000, "os")],
+        sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+        min_asset: None,
+        action: Action::HplTransfer {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex("0101").unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: None,
+            fee_swap: None,
+        },
+        exact_out: false,
+        expected_messages: vec![],
+        expected_error: Some(ContractError::Skip(InvalidBytes32Length { length: 2 })),
+    };
+    "Hyperlane Transfer w/ Recipient not 32 bytes - Expect Error")]
 fn test_execute_post_swap_action(params: Params) {
     // Create mock dependencies
     let mut deps = mock_dependencies_with_balances(&[(
@@ -619,6 +968,32 @@ fn test_execute_post_swap_action(params: Params) {
         .save(deps.as_mut().storage, &ibc_transfer_adapter)
         .unwrap();
 
+    // Store the hyperlane transfer adapter contract address
+    let hyperlane_transfer_adapter = Addr::unchecked("hyperlane_transfer_adapter");
+    HYPERLANE_TRANSFER_CONTRACT_ADDRESS
+        .save(deps.as_mut().storage, &hyperlane_transfer_adapter)
+        .unwrap();
+
+    // Store the osmo bech32 receiver format for channel-1
+    IBC_RECEIVER_FORMATS
+        .save(
+            deps.as_mut().storage,
+            "channel-1",
+            &IbcReceiverFormat::Bech32 {
+                prefix: "osmo".to_string(),
+            },
+        )
+        .unwrap();
+
+    // Store the swap venue adapter contract address
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
     // Store the entry point contract address in the blocked contract addresses map
     BLOCKED_CONTRACT_ADDRESSES
         .save(deps.as_mut().storage, &Addr::unchecked("entry_point"), &())
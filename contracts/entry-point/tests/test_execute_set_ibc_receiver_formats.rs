@@ -0,0 +1,151 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_json_binary, Addr, ContractInfoResponse, ContractResult, QuerierResult, SystemResult,
+    WasmQuery,
+};
+use skip::{
+    entry_point::{ExecuteMsg, QueryMsg},
+    error::SkipError,
+    ibc::{IbcChannelReceiverFormat, IbcReceiverFormat},
+};
+use skip_go_entry_point::error::ContractError;
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    - Set Receiver Formats By Contract Admin (tests the formats are stored and queryable)
+
+Expect Error
+    - Unauthorized Caller (Only the contract admin can set receiver formats)
+    - Contract Without Admin
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    admin: Option<String>,
+    expected_error: Option<ContractError>,
+}
+
+// Test execute_set_ibc_receiver_formats
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        admin: Some("admin".to_string()),
+        expected_error: None,
+    };
+    "Set Receiver Formats By Contract Admin")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        admin: Some("admin".to_string()),
+        expected_error: Some(ContractError::Skip(SkipError::Unauthorized)),
+    };
+    "Unauthorized Caller - Expect Error")]
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        admin: None,
+        expected_error: Some(ContractError::Skip(SkipError::Unauthorized)),
+    };
+    "Contract Without Admin - Expect Error")]
+fn test_execute_set_ibc_receiver_formats(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the contract info query
+    let admin = params.admin.clone();
+    let wasm_handler = move |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { contract_addr } if contract_addr == "entry_point" => {
+                let mut contract_info = ContractInfoResponse::new(1, "creator");
+                contract_info.admin = admin.clone();
+
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&contract_info).unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Create mock info with the caller
+    let info = mock_info(&params.caller, &[]);
+
+    // Call execute_set_ibc_receiver_formats with the given test parameters
+    let res = skip_go_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        ExecuteMsg::SetIbcReceiverFormats {
+            receiver_formats: vec![
+                IbcChannelReceiverFormat {
+                    source_channel: "channel-0".to_string(),
+                    receiver_format: IbcReceiverFormat::Bech32 {
+                        prefix: "osmo".to_string(),
+                    },
+                },
+                IbcChannelReceiverFormat {
+                    source_channel: "channel-1".to_string(),
+                    receiver_format: IbcReceiverFormat::Evm {},
+                },
+            ],
+        },
+    );
+
+    match res {
+        Ok(_) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Verify the receiver formats were stored, and unset channels accept any receiver
+            for (source_channel, expected_receiver_format) in [
+                (
+                    "channel-0",
+                    IbcReceiverFormat::Bech32 {
+                        prefix: "osmo".to_string(),
+                    },
+                ),
+                ("channel-1", IbcReceiverFormat::Evm {}),
+                ("channel-2", IbcReceiverFormat::Any {}),
+            ] {
+                let receiver_format: IbcReceiverFormat = from_json(
+                    skip_go_entry_point::contract::query(
+                        deps.as_ref(),
+                        env.clone(),
+                        QueryMsg::IbcReceiverFormat {
+                            source_channel: source_channel.to_string(),
+                        },
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+
+                assert_eq!(receiver_format, expected_receiver_format);
+            }
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
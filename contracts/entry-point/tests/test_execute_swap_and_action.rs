@@ -519,6 +519,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(200_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(200_000, "osmo")], 
                 }
@@ -839,6 +840,7 @@ struct Params {
                                     interface: None,
                                 }
                             ],
+                            min_amount_out: Some(Uint128::new(200_000)),
                         }).unwrap(),
                     }).unwrap(),
                     funds: vec![],
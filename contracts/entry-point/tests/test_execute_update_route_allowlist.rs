@@ -0,0 +1,392 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{mock_dependencies, mock_dependencies_with_balances, mock_env, mock_info},
+    to_json_binary, Addr, Coin, ContractInfoResponse, ContractResult, Deps, HexBinary,
+    QuerierResult, SystemResult, Timestamp, WasmQuery,
+};
+use skip::{
+    asset::Asset,
+    entry_point::{Action, ExecuteMsg, QueryMsg},
+    error::SkipError,
+    hyperlane::HplWarpRoute,
+    ibc::IbcInfo,
+};
+use skip_go_entry_point::{
+    error::ContractError,
+    state::{
+        ALLOWED_IBC_CHANNELS, ALLOWED_WARP_ROUTES, HYPERLANE_TRANSFER_CONTRACT_ADDRESS,
+        IBC_TRANSFER_CONTRACT_ADDRESS, ROUTE_ALLOWLIST_ENFORCED,
+    },
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Response
+    // Update Route Allowlist
+    - Update Route Allowlist By Contract Admin (tests the allowlist is stored and queryable)
+
+    // Action
+    - Ibc Transfer Over Allowed Channel With Allowlist Enforced
+    - Ibc Transfer Over Unlisted Channel With Allowlist Not Enforced
+    - Hyperlane Transfer Through Allowed Warp Route With Allowlist Enforced
+
+Expect Error
+    // Update Route Allowlist
+    - Unauthorized Caller (Only the contract admin can update the route allowlist)
+
+    // Action
+    - Ibc Transfer Over Unlisted Channel With Allowlist Enforced
+    - Hyperlane Transfer Through Warp Route Allowed For Another Destination Domain
+ */
+
+// Define test parameters
+struct Params {
+    caller: String,
+    expected_error: Option<ContractError>,
+}
+
+// Define action test parameters
+struct ActionParams {
+    enforced: bool,
+    action: Action,
+    expected_error: Option<ContractError>,
+}
+
+// Returns the warp routes allowed by the query for the given destination domain
+fn query_allowed_warp_routes(deps: Deps, dest_domain: Option<u32>) -> Vec<HplWarpRoute> {
+    from_json(
+        skip_go_entry_point::contract::query(
+            deps,
+            mock_env(),
+            QueryMsg::AllowedWarpRoutes { dest_domain },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+// Test execute_set_route_allowlist_enforced and execute_update_route_allowlist
+#[test_case(
+    Params {
+        caller: "admin".to_string(),
+        expected_error: None,
+    };
+    "Update Route Allowlist By Contract Admin")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        expected_error: Some(ContractError::Skip(SkipError::Unauthorized)),
+    };
+    "Unauthorized Caller - Expect Error")]
+fn test_execute_update_route_allowlist(params: Params) {
+    // Create mock dependencies
+    let mut deps = mock_dependencies();
+
+    // Create mock wasm handler to handle the contract info query
+    let wasm_handler = |query: &WasmQuery| -> QuerierResult {
+        match query {
+            WasmQuery::ContractInfo { contract_addr } if contract_addr == "entry_point" => {
+                let mut contract_info = ContractInfoResponse::new(1, "creator");
+                contract_info.admin = Some("admin".to_string());
+
+                SystemResult::Ok(ContractResult::Ok(to_json_binary(&contract_info).unwrap()))
+            }
+            _ => panic!("Unsupported query: {:?}", query),
+        }
+    };
+
+    // Update querier with mock wasm handler
+    deps.querier.update_wasm(wasm_handler);
+
+    // Create mock env with the entry point contract address
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    // Enforce the route allowlist
+    let res = skip_go_entry_point::contract::execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(&params.caller, &[]),
+        ExecuteMsg::SetRouteAllowlistEnforced { enforced: true },
+    )
+    .and_then(|_| {
+        // Add channels and warp routes, removing one of each
+        skip_go_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(&params.caller, &[]),
+            ExecuteMsg::UpdateRouteAllowlist {
+                add_ibc_channels: vec!["channel-0".to_string(), "channel-1".to_string()],
+                remove_ibc_channels: vec!["channel-1".to_string()],
+                add_warp_routes: vec![
+                    HplWarpRoute {
+                        dest_domain: 1,
+                        warp_address: "warp_a".to_string(),
+                    },
+                    HplWarpRoute {
+                        dest_domain: 2,
+                        warp_address: "warp_b".to_string(),
+                    },
+                    HplWarpRoute {
+                        dest_domain: 2,
+                        warp_address: "warp_c".to_string(),
+                    },
+                ],
+                remove_warp_routes: vec![HplWarpRoute {
+                    dest_domain: 2,
+                    warp_address: "warp_c".to_string(),
+                }],
+            },
+        )
+    });
+
+    match res {
+        Ok(_) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Verify the route allowlist is enforced
+            let enforced: bool = from_json(
+                skip_go_entry_point::contract::query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::RouteAllowlistEnforced {},
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert!(enforced);
+
+            // Verify the allowed channels are stored
+            let allowed_ibc_channels: Vec<String> = from_json(
+                skip_go_entry_point::contract::query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::AllowedIbcChannels {},
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            assert_eq!(allowed_ibc_channels, vec!["channel-0".to_string()]);
+
+            // Verify the allowed warp routes are stored, in total and per destination domain
+            assert_eq!(
+                query_allowed_warp_routes(deps.as_ref(), None),
+                vec![
+                    HplWarpRoute {
+                        dest_domain: 1,
+                        warp_address: "warp_a".to_string(),
+                    },
+                    HplWarpRoute {
+                        dest_domain: 2,
+                        warp_address: "warp_b".to_string(),
+                    },
+                ]
+            );
+            assert_eq!(
+                query_allowed_warp_routes(deps.as_ref(), Some(2)),
+                vec![HplWarpRoute {
+                    dest_domain: 2,
+                    warp_address: "warp_b".to_string(),
+                }]
+            );
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
+// Test the route allowlist is enforced on execute_action
+#[test_case(
+    ActionParams {
+        enforced: true,
+        action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                encoding: None,
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        expected_error: None,
+    };
+    "Ibc Transfer Over Allowed Channel With Allowlist Enforced")]
+#[test_case(
+    ActionParams {
+        enforced: false,
+        action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-5".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                encoding: None,
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        expected_error: None,
+    };
+    "Ibc Transfer Over Unlisted Channel With Allowlist Not Enforced")]
+#[test_case(
+    ActionParams {
+        enforced: true,
+        action: Action::HplTransfer {
+            dest_domain: 1,
+            recipient: HexBinary::from_hex(
+                "0000000000000000000000000000000000000000000000000000000000000101",
+            )
+            .unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: None,
+            fee_swap: None,
+        },
+        expected_error: None,
+    };
+    "Hyperlane Transfer Through Allowed Warp Route With Allowlist Enforced")]
+#[test_case(
+    ActionParams {
+        enforced: true,
+        action: Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-5".to_string(),
+                receiver: "receiver".to_string(),
+                memo: "".to_string(),
+                fee: None,
+                recover_address: "cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string(),
+                encoding: None,
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        expected_error: Some(ContractError::IbcChannelNotAllowed {
+            source_channel: "channel-5".to_string(),
+        }),
+    };
+    "Ibc Transfer Over Unlisted Channel With Allowlist Enforced - Expect Error")]
+#[test_case(
+    ActionParams {
+        enforced: true,
+        action: Action::HplTransfer {
+            dest_domain: 2,
+            recipient: HexBinary::from_hex(
+                "0000000000000000000000000000000000000000000000000000000000000101",
+            )
+            .unwrap(),
+            hook: None,
+            metadata: None,
+            warp_address: "warp_address".to_string(),
+            recover_address: Some("cosmos1xv9tklw7d82sezh9haa573wufgy59vmwe6xxe5".to_string()),
+            fee: None,
+            fee_swap: None,
+        },
+        expected_error: Some(ContractError::WarpRouteNotAllowed {
+            dest_domain: 2,
+            warp_address: "warp_address".to_string(),
+        }),
+    };
+    "Hyperlane Transfer Through Warp Route Allowed For Another Destination Domain - Expect Error")]
+fn test_execute_action_with_route_allowlist(params: ActionParams) {
+    // Create mock dependencies
+    let mut deps =
+        mock_dependencies_with_balances(&[("entry_point", &[Coin::new(1_000_000, "os")])]);
+
+    // Create mock env with parameters that make testing easier
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+    env.block.time = Timestamp::from_nanos(100);
+
+    // Create mock info with the sent funds
+    let info = mock_info("actioner", &[Coin::new(1_000_000, "os")]);
+
+    // Store the ibc and hyperlane transfer adapter contract addresses
+    IBC_TRANSFER_CONTRACT_ADDRESS
+        .save(
+            deps.as_mut().storage,
+            &Addr::unchecked("ibc_transfer_adapter"),
+        )
+        .unwrap();
+    HYPERLANE_TRANSFER_CONTRACT_ADDRESS
+        .save(
+            deps.as_mut().storage,
+            &Addr::unchecked("hyperlane_transfer_adapter"),
+        )
+        .unwrap();
+
+    // Store the route allowlist, allowing channel-0 and the warp route to destination domain 1
+    ROUTE_ALLOWLIST_ENFORCED
+        .save(deps.as_mut().storage, &params.enforced)
+        .unwrap();
+    ALLOWED_IBC_CHANNELS
+        .save(deps.as_mut().storage, "channel-0", &())
+        .unwrap();
+    ALLOWED_WARP_ROUTES
+        .save(
+            deps.as_mut().storage,
+            (1, &Addr::unchecked("warp_address")),
+            &(),
+        )
+        .unwrap();
+
+    // Call execute_action with the given test parameters
+    let res = skip_go_entry_point::contract::execute(
+        deps.as_mut(),
+        env,
+        info,
+        ExecuteMsg::Action {
+            sent_asset: Some(Asset::Native(Coin::new(1_000_000, "os"))),
+            timeout_timestamp: 101,
+            action: params.action,
+            exact_out: false,
+            min_asset: None,
+        },
+    );
+
+    match res {
+        Ok(res) => {
+            // Assert the test did not expect an error
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            // Assert the transfer message was dispatched
+            assert_eq!(res.messages.len(), 1);
+        }
+        Err(err) => {
+            // Assert the test expected an error
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            // Assert the error is correct
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
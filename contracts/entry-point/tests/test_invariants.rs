@@ -0,0 +1,316 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{
+        mock_dependencies_with_balances, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    },
+    to_json_binary, Addr, BankMsg, Binary, Coin, ContractResult, CosmosMsg, Env, OwnedDeps,
+    QuerierResult, SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+use proptest::{collection::vec, prelude::*};
+use skip::{
+    asset::Asset,
+    entry_point::{Action, Affiliate, ExecuteMsg},
+    swap::{
+        ExecuteMsg as SwapExecuteMsg, Route, SmartSwapExactAssetIn, Swap, SwapExactAssetIn,
+        SwapExactAssetOut, SwapOperation,
+    },
+};
+use skip_go_entry_point::state::{PRE_SWAP_OUT_ASSET_AMOUNT, SWAP_VENUE_MAP};
+
+/*
+Invariants:
+
+    // Swap And Action
+    - Smart swap routes are rebalanced to offer exactly the sent asset, erroring
+      (instead of panicking) only if the largest route cannot absorb the difference
+
+    // User Swap
+    - Affiliate fees are exact, and never sum to more than the min asset
+    - The swap exact asset in message never sends more than the remaining asset, and
+      requires the swap venue to return at least the min asset plus the affiliate fees
+    - Swap exact asset out refunds are exact, the refund plus the swap asset in
+      add up to the remaining asset, erroring if the swap needs more than it
+
+    // Post Swap Action
+    - Only the asset received from the swap is transferred out, never the pre swap
+      contract balance, and the action errors if it is less than the min asset
+ */
+
+type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+// Creates the mock dependencies with the given entry point contract balance
+// and the mock env with the entry point contract address
+fn setup(contract_balance: &[Coin]) -> (MockDeps, Env) {
+    let mut deps = mock_dependencies_with_balances(&[("entry_point", contract_balance)]);
+
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name",
+            &Addr::unchecked("swap_venue_adapter"),
+        )
+        .unwrap();
+
+    (deps, env)
+}
+
+fn swap_operations() -> Vec<SwapOperation> {
+    vec![SwapOperation {
+        pool: "pool".to_string(),
+        denom_in: "os".to_string(),
+        denom_out: "un".to_string(),
+        interface: None,
+    }]
+}
+
+// Returns the funds and the message of a wasm execute message
+fn wasm_execute(msg: &CosmosMsg) -> (Vec<Coin>, Binary) {
+    match msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { msg, funds, .. }) => (funds.clone(), msg.clone()),
+        _ => panic!("expected a wasm execute message, got {:?}", msg),
+    }
+}
+
+// Returns the recipient and amount of a bank send message of a single coin
+fn bank_send(msg: &CosmosMsg) -> (String, u128) {
+    match msg {
+        CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => {
+            assert_eq!(amount.len(), 1);
+            (to_address.clone(), amount[0].amount.u128())
+        }
+        _ => panic!("expected a bank send message, got {:?}", msg),
+    }
+}
+
+proptest! {
+    // Smart swap routes are rebalanced to offer exactly the sent asset
+    #[test]
+    fn smart_swap_routes_offer_exactly_the_sent_asset(
+        route_amounts in vec(0u128..1_000_000_000_000, 1..5),
+        sent_amount in 1u128..5_000_000_000_000,
+    ) {
+        let (mut deps, env) = setup(&[]);
+
+        let res = skip_go_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("user", &[Coin::new(sent_amount, "os")]),
+            ExecuteMsg::SwapAndAction {
+                sent_asset: None,
+                user_swap: Swap::SmartSwapExactAssetIn(SmartSwapExactAssetIn {
+                    swap_venue_name: "swap_venue_name".to_string(),
+                    routes: route_amounts
+                        .iter()
+                        .map(|amount| Route {
+                            offer_asset: Asset::Native(Coin::new(*amount, "os")),
+                            operations: swap_operations(),
+                        })
+                        .collect(),
+                }),
+                min_asset: Asset::Native(Coin::new(1, "un")),
+                timeout_timestamp: env.block.time.plus_seconds(1).nanos(),
+                post_swap_action: Action::Transfer {
+                    to_address: "recipient".to_string(),
+                },
+                affiliates: vec![],
+            },
+        );
+
+        let total: u128 = route_amounts.iter().sum();
+        let largest = *route_amounts.iter().max().unwrap();
+
+        // The only expected error is the largest route not being able to absorb the difference
+        prop_assert_eq!(res.is_err(), total > sent_amount && total - sent_amount > largest);
+
+        if let Ok(res) = res {
+            let (funds, user_swap_msg) = wasm_execute(&res.messages[0].msg);
+            prop_assert!(funds.is_empty());
+
+            let ExecuteMsg::UserSwap {
+                swap: Swap::SmartSwapExactAssetIn(smart_swap),
+                remaining_asset,
+                ..
+            } = from_json(user_swap_msg).unwrap()
+            else {
+                panic!("expected a smart swap user swap message");
+            };
+
+            prop_assert_eq!(remaining_asset.amount().u128(), sent_amount);
+            prop_assert_eq!(smart_swap.amount().u128(), sent_amount);
+        }
+    }
+
+    // Affiliate fees are exact and never sum to more than the min asset, and the
+    // swap exact asset in message never sends more than the remaining asset
+    #[test]
+    fn affiliate_fees_never_exceed_min_asset(
+        min_amount in any::<u128>(),
+        remaining_amount in 1u128..u128::MAX,
+        basis_points_fees in vec(0u128..=2_000, 0..5),
+    ) {
+        let (mut deps, env) = setup(&[]);
+
+        let affiliates: Vec<Affiliate> = basis_points_fees
+            .iter()
+            .enumerate()
+            .map(|(i, basis_points_fee)| Affiliate {
+                basis_points_fee: Uint128::new(*basis_points_fee),
+                address: format!("affiliate_{i}"),
+            })
+            .collect();
+
+        let res = skip_go_entry_point::contract::execute(
+            deps.as_mut(),
+            env,
+            mock_info("entry_point", &[]),
+            ExecuteMsg::UserSwap {
+                swap: Swap::SwapExactAssetIn(SwapExactAssetIn {
+                    swap_venue_name: "swap_venue_name".to_string(),
+                    operations: swap_operations(),
+                }),
+                min_asset: Asset::Native(Coin::new(min_amount, "un")),
+                remaining_asset: Asset::Native(Coin::new(remaining_amount, "os")),
+                affiliates: affiliates.clone(),
+            },
+        )
+        .unwrap();
+
+        // The swap message sends exactly the remaining asset to the swap adapter
+        let (funds, swap_msg) = wasm_execute(&res.messages[0].msg);
+        prop_assert_eq!(funds, vec![Coin::new(remaining_amount, "os")]);
+
+        // Each affiliate receives exactly its fee, skipping zero fees
+        let expected_fees: Vec<(String, u128)> = affiliates
+            .iter()
+            .map(|affiliate| {
+                (
+                    affiliate.address.clone(),
+                    Uint128::new(min_amount)
+                        .multiply_ratio(affiliate.basis_points_fee, Uint128::new(10_000))
+                        .u128(),
+                )
+            })
+            .filter(|(_, fee)| *fee > 0)
+            .collect();
+
+        let fees: Vec<(String, u128)> = res.messages[1..]
+            .iter()
+            .map(|sub_msg| bank_send(&sub_msg.msg))
+            .collect();
+
+        prop_assert_eq!(&fees, &expected_fees);
+        prop_assert!(fees.iter().map(|(_, fee)| fee).sum::<u128>() <= min_amount);
+
+        // The swap venue must return at least the min asset plus the affiliate fees
+        let SwapExecuteMsg::Swap { min_amount_out, .. } = from_json(swap_msg).unwrap() else {
+            panic!("expected a swap adapter swap message");
+        };
+        prop_assert_eq!(
+            min_amount_out,
+            Some(Uint128::new(min_amount).saturating_add(Uint128::new(
+                fees.iter().map(|(_, fee)| fee).sum::<u128>()
+            )))
+        );
+    }
+
+    // Swap exact asset out refunds are exact, erroring if the swap needs more than the remaining asset
+    #[test]
+    fn swap_exact_asset_out_refunds_are_exact(
+        remaining_amount in 1u128..1_000_000_000_000_000_000,
+        swap_amount_in in 1u128..1_000_000_000_000_000_000,
+        min_amount in 1u128..1_000_000_000_000_000_000,
+    ) {
+        let (mut deps, env) = setup(&[]);
+
+        // Mock the swap adapter reverse simulation to need the generated swap amount in
+        deps.querier.update_wasm(move |query: &WasmQuery| -> QuerierResult {
+            match query {
+                WasmQuery::Smart { .. } => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&Asset::Native(Coin::new(swap_amount_in, "os"))).unwrap(),
+                )),
+                _ => panic!("Unsupported query: {:?}", query),
+            }
+        });
+
+        let res = skip_go_entry_point::contract::execute(
+            deps.as_mut(),
+            env,
+            mock_info("entry_point", &[]),
+            ExecuteMsg::UserSwap {
+                swap: Swap::SwapExactAssetOut(SwapExactAssetOut {
+                    swap_venue_name: "swap_venue_name".to_string(),
+                    operations: swap_operations(),
+                    refund_address: Some("refund_address".to_string()),
+                }),
+                min_asset: Asset::Native(Coin::new(min_amount, "un")),
+                remaining_asset: Asset::Native(Coin::new(remaining_amount, "os")),
+                affiliates: vec![],
+            },
+        );
+
+        prop_assert_eq!(res.is_err(), swap_amount_in > remaining_amount);
+
+        if let Ok(res) = res {
+            let refund_amount = remaining_amount - swap_amount_in;
+
+            // The refund message is only included if there is something to refund
+            let swap_msg = if refund_amount > 0 {
+                prop_assert_eq!(
+                    bank_send(&res.messages[0].msg),
+                    ("refund_address".to_string(), refund_amount)
+                );
+                &res.messages[1].msg
+            } else {
+                &res.messages[0].msg
+            };
+
+            let (funds, _) = wasm_execute(swap_msg);
+            prop_assert_eq!(funds, vec![Coin::new(swap_amount_in, "os")]);
+        }
+    }
+
+    // Only the asset received from the swap is transferred out, never the pre swap contract balance
+    #[test]
+    fn post_swap_action_only_transfers_the_swap_out(
+        pre_swap_amount in 0u128..1_000_000_000_000_000_000,
+        swap_amount_out in 0u128..1_000_000_000_000_000_000,
+        min_amount in 0u128..1_000_000_000_000_000_000,
+        exact_out in any::<bool>(),
+    ) {
+        let (mut deps, env) = setup(&[Coin::new(pre_swap_amount + swap_amount_out, "un")]);
+
+        PRE_SWAP_OUT_ASSET_AMOUNT
+            .save(deps.as_mut().storage, &Uint128::new(pre_swap_amount))
+            .unwrap();
+
+        let res = skip_go_entry_point::contract::execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("entry_point", &[]),
+            ExecuteMsg::PostSwapAction {
+                min_asset: Asset::Native(Coin::new(min_amount, "un")),
+                timeout_timestamp: env.block.time.plus_seconds(1).nanos(),
+                post_swap_action: Action::Transfer {
+                    to_address: "recipient".to_string(),
+                },
+                exact_out,
+            },
+        );
+
+        prop_assert_eq!(res.is_err(), swap_amount_out < min_amount);
+
+        if let Ok(res) = res {
+            let expected_amount = if exact_out { min_amount } else { swap_amount_out };
+
+            prop_assert_eq!(res.messages.len(), 1);
+            prop_assert_eq!(
+                bank_send(&res.messages[0].msg),
+                ("recipient".to_string(), expected_amount)
+            );
+            prop_assert!(expected_amount <= swap_amount_out);
+        }
+    }
+}
@@ -0,0 +1,408 @@
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use multitest::{
+    mock_ibc_adapter::{InFlightTransfer, SudoMsg},
+    Fixture, ASTROPORT_SWAP_VENUE_NAME,
+};
+use skip::{
+    asset::Asset,
+    entry_point::{Action, Affiliate, ExecuteMsg},
+    ibc::IbcInfo,
+    swap::{
+        Route, SmartSwapExactAssetIn, Swap, SwapExactAssetIn, SwapExactAssetOut, SwapOperation,
+    },
+};
+use test_case::test_case;
+
+mod multitest;
+
+/*
+Test Cases:
+
+Expect Success
+    - Swap Exact Asset In And Transfer (tests the recipient receives the swap out)
+    - Swap Exact Asset In And Transfer With Affiliate Fee (tests the affiliate fee is deducted from the swap out)
+    - Swap Exact Asset In And Transfer With Pre Existing Entry Point Balance (tests only the swap out is transferred)
+    - Swap Exact Asset Out And Transfer (tests the unused asset in is refunded and the exact min asset is transferred)
+    - Smart Swap Exact Asset In And Transfer (tests the routes' swap outs are aggregated)
+    - Swap And Action With Recover Failing (tests the sent asset is sent to the recovery address)
+    - Swap And Ibc Transfer Acked (tests the transferred coin leaves the chain)
+    - Swap And Ibc Transfer Errored (tests the transferred coin is refunded to the recover address)
+    - Swap And Ibc Transfer Timed Out (tests the transferred coin is refunded to the recover address)
+
+Expect Error
+    - Swap And Action Receiving Less Than Min Asset (tests the whole transaction is reverted)
+ */
+
+const DENOM_IN: &str = "uosmo";
+const DENOM_OUT: &str = "uatom";
+
+// Creates a fixture with a user holding 1_000 DENOM_IN and a mock astroport
+// pair swapping DENOM_IN to DENOM_OUT at the given price.
+fn setup(price: u64) -> (Fixture, Addr, SwapOperation) {
+    let mut fixture = Fixture::deploy();
+
+    let user = fixture.addr("user");
+    fixture.set_balance(&user, vec![Coin::new(1_000, DENOM_IN)]);
+
+    let operation = fixture.create_pair(
+        DENOM_IN,
+        DENOM_OUT,
+        Decimal::from_ratio(price, 1u64),
+        vec![
+            Coin::new(1_000_000, DENOM_IN),
+            Coin::new(1_000_000, DENOM_OUT),
+        ],
+    );
+
+    (fixture, user, operation)
+}
+
+fn swap_and_action_msg(
+    fixture: &Fixture,
+    user_swap: Swap,
+    min_amount_out: u128,
+    post_swap_action: Action,
+    affiliates: Vec<Affiliate>,
+) -> ExecuteMsg {
+    ExecuteMsg::SwapAndAction {
+        sent_asset: None,
+        user_swap,
+        min_asset: Asset::Native(Coin::new(min_amount_out, DENOM_OUT)),
+        timeout_timestamp: fixture.app.block_info().time.plus_seconds(100).nanos(),
+        post_swap_action,
+        affiliates,
+    }
+}
+
+fn swap_exact_asset_in(operations: Vec<SwapOperation>) -> Swap {
+    Swap::SwapExactAssetIn(SwapExactAssetIn {
+        swap_venue_name: ASTROPORT_SWAP_VENUE_NAME.to_string(),
+        operations,
+    })
+}
+
+// Asserts the entry point and swap adapter do not hold any of the swapped denoms
+fn assert_no_funds_left_behind(fixture: &Fixture, expected_entry_point_out_balance: u128) {
+    for (contract, expected_out_balance) in [
+        (&fixture.entry_point, expected_entry_point_out_balance),
+        (&fixture.astroport_adapter, 0),
+    ] {
+        assert_eq!(fixture.balance(contract, DENOM_IN), 0);
+        assert_eq!(fixture.balance(contract, DENOM_OUT), expected_out_balance);
+    }
+}
+
+// Define test parameters
+struct Params {
+    pre_swap_entry_point_out_balance: u128,
+    affiliate_basis_points_fee: Option<u128>,
+    expected_recipient_balance: u128,
+    expected_affiliate_balance: u128,
+}
+
+// Test swap exact asset in and transfer, with the balance delta accounting
+#[test_case(
+    Params {
+        pre_swap_entry_point_out_balance: 0,
+        affiliate_basis_points_fee: None,
+        expected_recipient_balance: 2_000,
+        expected_affiliate_balance: 0,
+    };
+    "Swap Exact Asset In And Transfer")]
+#[test_case(
+    Params {
+        pre_swap_entry_point_out_balance: 0,
+        affiliate_basis_points_fee: Some(100),
+        expected_recipient_balance: 1_981,
+        expected_affiliate_balance: 19,
+    };
+    "Swap Exact Asset In And Transfer With Affiliate Fee")]
+#[test_case(
+    Params {
+        pre_swap_entry_point_out_balance: 5,
+        affiliate_basis_points_fee: None,
+        expected_recipient_balance: 2_000,
+        expected_affiliate_balance: 0,
+    };
+    "Swap Exact Asset In And Transfer With Pre Existing Entry Point Balance")]
+fn test_swap_exact_asset_in_and_transfer(params: Params) {
+    let (mut fixture, user, operation) = setup(2);
+
+    let recipient = fixture.addr("recipient");
+    let affiliate = fixture.addr("affiliate");
+
+    if params.pre_swap_entry_point_out_balance > 0 {
+        let entry_point = fixture.entry_point.clone();
+        fixture.set_balance(
+            &entry_point,
+            vec![Coin::new(
+                params.pre_swap_entry_point_out_balance,
+                DENOM_OUT,
+            )],
+        );
+    }
+
+    let affiliates = params
+        .affiliate_basis_points_fee
+        .map(|basis_points_fee| Affiliate {
+            basis_points_fee: Uint128::new(basis_points_fee),
+            address: affiliate.to_string(),
+        })
+        .into_iter()
+        .collect();
+
+    let msg = swap_and_action_msg(
+        &fixture,
+        swap_exact_asset_in(vec![operation]),
+        1_900,
+        Action::Transfer {
+            to_address: recipient.to_string(),
+        },
+        affiliates,
+    );
+
+    fixture
+        .execute_entry_point(&user, &msg, &[Coin::new(1_000, DENOM_IN)])
+        .unwrap();
+
+    assert_eq!(fixture.balance(&user, DENOM_IN), 0);
+    assert_eq!(
+        fixture.balance(&recipient, DENOM_OUT),
+        params.expected_recipient_balance
+    );
+    assert_eq!(
+        fixture.balance(&affiliate, DENOM_OUT),
+        params.expected_affiliate_balance
+    );
+    assert_no_funds_left_behind(&fixture, params.pre_swap_entry_point_out_balance);
+}
+
+// Test swap exact asset out refunds the unused asset in and transfers exactly the min asset
+#[test]
+fn test_swap_exact_asset_out_and_transfer() {
+    let (mut fixture, user, operation) = setup(2);
+
+    let recipient = fixture.addr("recipient");
+
+    let msg = swap_and_action_msg(
+        &fixture,
+        Swap::SwapExactAssetOut(SwapExactAssetOut {
+            swap_venue_name: ASTROPORT_SWAP_VENUE_NAME.to_string(),
+            operations: vec![operation],
+            refund_address: Some(user.to_string()),
+        }),
+        1_000,
+        Action::Transfer {
+            to_address: recipient.to_string(),
+        },
+        vec![],
+    );
+
+    fixture
+        .execute_entry_point(&user, &msg, &[Coin::new(1_000, DENOM_IN)])
+        .unwrap();
+
+    // The astroport adapter adds 1 to the reverse simulated 500 asset in to round up,
+    // so 501 is swapped into 1_002, of which exactly the min asset is transferred
+    assert_eq!(fixture.balance(&user, DENOM_IN), 499);
+    assert_eq!(fixture.balance(&recipient, DENOM_OUT), 1_000);
+    assert_no_funds_left_behind(&fixture, 2);
+}
+
+// Test smart swap exact asset in aggregates the swap outs of its routes
+#[test]
+fn test_smart_swap_exact_asset_in_and_transfer() {
+    let (mut fixture, user, operation) = setup(2);
+
+    let recipient = fixture.addr("recipient");
+
+    let second_operation = fixture.create_pair(
+        DENOM_IN,
+        DENOM_OUT,
+        Decimal::from_ratio(3u64, 1u64),
+        vec![Coin::new(1_000_000, DENOM_OUT)],
+    );
+
+    // The routes only offer 900 of the 1_000 sent, the difference is
+    // added to the largest route by the entry point
+    let msg = swap_and_action_msg(
+        &fixture,
+        Swap::SmartSwapExactAssetIn(SmartSwapExactAssetIn {
+            swap_venue_name: ASTROPORT_SWAP_VENUE_NAME.to_string(),
+            routes: vec![
+                Route {
+                    offer_asset: Asset::Native(Coin::new(500, DENOM_IN)),
+                    operations: vec![operation],
+                },
+                Route {
+                    offer_asset: Asset::Native(Coin::new(400, DENOM_IN)),
+                    operations: vec![second_operation],
+                },
+            ],
+        }),
+        2_000,
+        Action::Transfer {
+            to_address: recipient.to_string(),
+        },
+        vec![],
+    );
+
+    fixture
+        .execute_entry_point(&user, &msg, &[Coin::new(1_000, DENOM_IN)])
+        .unwrap();
+
+    // 600 * 2 + 400 * 3
+    assert_eq!(fixture.balance(&recipient, DENOM_OUT), 2_400);
+    assert_no_funds_left_behind(&fixture, 0);
+}
+
+// Test a swap and action receiving less than the min asset reverts the whole transaction,
+// and the with recover variant sends the sent asset to the recovery address instead
+#[test]
+fn test_swap_and_action_less_than_min_asset() {
+    let (mut fixture, user, operation) = setup(2);
+
+    let recipient = fixture.addr("recipient");
+    let recovery = fixture.addr("recovery");
+
+    let ExecuteMsg::SwapAndAction {
+        sent_asset,
+        user_swap,
+        min_asset,
+        timeout_timestamp,
+        post_swap_action,
+        affiliates,
+    } = swap_and_action_msg(
+        &fixture,
+        swap_exact_asset_in(vec![operation]),
+        2_001,
+        Action::Transfer {
+            to_address: recipient.to_string(),
+        },
+        vec![],
+    )
+    else {
+        unreachable!()
+    };
+
+    let err = fixture
+        .execute_entry_point(
+            &user,
+            &ExecuteMsg::SwapAndAction {
+                sent_asset: sent_asset.clone(),
+                user_swap: user_swap.clone(),
+                min_asset: min_asset.clone(),
+                timeout_timestamp,
+                post_swap_action: post_swap_action.clone(),
+                affiliates: affiliates.clone(),
+            },
+            &[Coin::new(1_000, DENOM_IN)],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        "Received Less Asset From Swaps Than Minimum Asset Required"
+    );
+    assert_eq!(fixture.balance(&user, DENOM_IN), 1_000);
+
+    fixture
+        .execute_entry_point(
+            &user,
+            &ExecuteMsg::SwapAndActionWithRecover {
+                sent_asset,
+                user_swap,
+                min_asset,
+                timeout_timestamp,
+                post_swap_action,
+                affiliates,
+                recovery_addr: recovery.clone(),
+            },
+            &[Coin::new(1_000, DENOM_IN)],
+        )
+        .unwrap();
+
+    assert_eq!(fixture.balance(&user, DENOM_IN), 0);
+    assert_eq!(fixture.balance(&recovery, DENOM_IN), 1_000);
+    assert_eq!(fixture.balance(&recipient, DENOM_OUT), 0);
+    assert_no_funds_left_behind(&fixture, 0);
+}
+
+// Define test parameters
+struct IbcParams {
+    lifecycle: fn(u64) -> SudoMsg,
+    expected_recover_balance: u128,
+}
+
+// Test swap and ibc transfer, resolving the packet lifecycle on the mock ibc transfer adapter
+#[test_case(
+    IbcParams {
+        lifecycle: |sequence| SudoMsg::Ack { sequence },
+        expected_recover_balance: 0,
+    };
+    "Swap And Ibc Transfer Acked")]
+#[test_case(
+    IbcParams {
+        lifecycle: |sequence| SudoMsg::Error { sequence },
+        expected_recover_balance: 2_000,
+    };
+    "Swap And Ibc Transfer Errored")]
+#[test_case(
+    IbcParams {
+        lifecycle: |sequence| SudoMsg::Timeout { sequence },
+        expected_recover_balance: 2_000,
+    };
+    "Swap And Ibc Transfer Timed Out")]
+fn test_swap_and_ibc_transfer(params: IbcParams) {
+    let (mut fixture, user, operation) = setup(2);
+
+    let recover = fixture.addr("recover");
+
+    let msg = swap_and_action_msg(
+        &fixture,
+        swap_exact_asset_in(vec![operation]),
+        1_900,
+        Action::IbcTransfer {
+            ibc_info: IbcInfo {
+                source_channel: "channel-0".to_string(),
+                receiver: "cosmos1receiver".to_string(),
+                fee: None,
+                memo: "".to_string(),
+                recover_address: recover.to_string(),
+                encoding: None,
+                eureka_fee: None,
+            },
+            fee_swap: None,
+        },
+        vec![],
+    );
+
+    fixture
+        .execute_entry_point(&user, &msg, &[Coin::new(1_000, DENOM_IN)])
+        .unwrap();
+
+    // The swapped coin is in flight on the ibc transfer adapter
+    assert_eq!(
+        fixture.in_flight_transfers(),
+        vec![(
+            1,
+            InFlightTransfer {
+                source_channel: "channel-0".to_string(),
+                receiver: "cosmos1receiver".to_string(),
+                recover_address: recover.to_string(),
+                coin: Coin::new(2_000, DENOM_OUT),
+            }
+        )]
+    );
+    assert_eq!(fixture.balance(&fixture.ibc_adapter, DENOM_OUT), 2_000);
+    assert_no_funds_left_behind(&fixture, 0);
+
+    fixture.ibc_lifecycle((params.lifecycle)(1));
+
+    assert!(fixture.in_flight_transfers().is_empty());
+    assert_eq!(fixture.balance(&fixture.ibc_adapter, DENOM_OUT), 0);
+    assert_eq!(
+        fixture.balance(&recover, DENOM_OUT),
+        params.expected_recover_balance
+    );
+}
@@ -1,4 +1,5 @@
 use cosmwasm_std::{
+    from_json,
     testing::{mock_dependencies_with_balances, mock_env, mock_info},
     to_json_binary, Addr, BankMsg, Coin, ContractResult, OverflowError, OverflowOperation,
     QuerierResult,
@@ -14,7 +15,8 @@ use skip::{
         SwapOperationsEmpty,
     },
     swap::{
-        ExecuteMsg as SwapExecuteMsg, Route, SmartSwapExactAssetIn, Swap, SwapExactAssetIn,
+        CapabilitiesResponse, ExecuteMsg as SwapExecuteMsg, PoolIdentifier,
+        QueryMsg as SwapQueryMsg, Route, SmartSwapExactAssetIn, Swap, SwapExactAssetIn,
         SwapExactAssetOut, SwapOperation,
     },
 };
@@ -38,6 +40,8 @@ Expect Response
     - User Swap Exact Coin Out With Multiple Affiliates
     - User Swap Exact Coin Out With Zero Fee Affiliate
     - User Swap Exact Coin Out With Refund Amount Zero (Ensure No Refund Message Included)
+    - User Swap Exact Coin Out With Swap Exact Asset Out Execution (Swap Adapter Refunds The Unused Remaining Coin)
+    - User Swap Exact Coin Out With Swap Exact Asset Out Execution And No Refund Address (Ensure Swap Exact Coin In Is Used)
     - User Swap Exact Cw20 Asset Out With Single Affiliate
 
 Expect Error
@@ -105,6 +109,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(1_000_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "un")], 
                 }
@@ -152,6 +157,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(1_100_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "un")], 
                 }
@@ -215,6 +221,7 @@ struct Params {
                                     interface: None,
                                 }
                             ],
+                            min_amount_out: Some(Uint128::new(1_100_000)),
                         }).unwrap(),
                     }).unwrap(),
                     funds: vec![],
@@ -279,6 +286,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(1_200_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "un")], 
                 }
@@ -346,6 +354,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(1_000_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(1_000_000, "un")], 
                 }
@@ -401,6 +410,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(500_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(500_000, "un")], 
                 }
@@ -461,6 +471,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(550_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(500_000, "un")], 
                 }
@@ -542,6 +553,7 @@ struct Params {
                                     interface: None,
                                 }
                             ],
+                            min_amount_out: Some(Uint128::new(1_100_000)),
                         }).unwrap(),
                     }).unwrap(),
                     funds: vec![],
@@ -621,6 +633,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(600_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(500_000, "un")], 
                 }
@@ -701,6 +714,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(1_000_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(500_000, "un")], 
                 }
@@ -746,6 +760,7 @@ struct Params {
                                 interface: None,
                             }
                         ],
+                        min_amount_out: Some(Uint128::new(500_000)),
                     }).unwrap(),
                     funds: vec![Coin::new(500_000, "un")], 
                 }
@@ -757,6 +772,114 @@ struct Params {
         expected_error: None,
     };
     "User Swap Exact Coin Out With Refund Amount Zero")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactAssetOut (
+            SwapExactAssetOut{
+                swap_venue_name: "swap_venue_name_3".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "un".to_string(),
+                        denom_out: "os".to_string(),
+                        interface: None,
+                    }
+                ],
+                refund_address: Some("refund_address".to_string()),
+            }
+        ),
+        remaining_asset: Asset::Native(Coin::new(1_000_000, "un")),
+        min_asset: Asset::Native(Coin::new(500_000, "os")),
+        affiliates: vec![
+            Affiliate {
+                address: "affiliate".to_string(),
+                basis_points_fee: Uint128::new(1000),
+            },
+        ],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter_3".to_string(),
+                    msg: to_json_binary(&SwapExecuteMsg::SwapExactAssetOut {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "un".to_string(),
+                                denom_out: "os".to_string(),
+                                interface: None,
+                            }
+                        ],
+                        amount_out: Uint128::new(550_000),
+                        refund_address: "refund_address".to_string(),
+                    }).unwrap(),
+                    funds: vec![Coin::new(1_000_000, "un")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+            SubMsg {
+                id: 0,
+                msg: BankMsg::Send {
+                    to_address: "affiliate".to_string(),
+                    amount: vec![Coin::new(50_000, "os")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin Out With Swap Exact Asset Out Execution")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        user_swap: Swap::SwapExactAssetOut (
+            SwapExactAssetOut{
+                swap_venue_name: "swap_venue_name_3".to_string(),
+                operations: vec![
+                    SwapOperation {
+                        pool: "pool".to_string(),
+                        denom_in: "un".to_string(),
+                        denom_out: "os".to_string(),
+                        interface: None,
+                    }
+                ],
+                refund_address: None,
+            }
+        ),
+        remaining_asset: Asset::Native(Coin::new(500_000, "un")),
+        min_asset: Asset::Native(Coin::new(500_000, "os")),
+        affiliates: vec![],
+        expected_messages: vec![
+            SubMsg {
+                id: 0,
+                msg: WasmMsg::Execute {
+                    contract_addr: "swap_venue_adapter_3".to_string(),
+                    msg: to_json_binary(&SwapExecuteMsg::Swap {
+                        operations: vec![
+                            SwapOperation {
+                                pool: "pool".to_string(),
+                                denom_in: "un".to_string(),
+                                denom_out: "os".to_string(),
+                                interface: None,
+                            }
+                        ],
+                        min_amount_out: Some(Uint128::new(500_000)),
+                    }).unwrap(),
+                    funds: vec![Coin::new(500_000, "un")],
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: Never,
+            },
+        ],
+        expected_error: None,
+    };
+    "User Swap Exact Coin Out With Swap Exact Asset Out Execution And No Refund Address")]
 #[test_case(
     Params {
         caller: "entry_point".to_string(),
@@ -1055,6 +1178,7 @@ struct Params {
                         denom_out: "os".to_string(),
                         interface: None,
                     }],
+                    min_amount_out: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(250_000, "un")],
@@ -1082,6 +1206,7 @@ struct Params {
                             interface: None,
                         },
                     ],
+                    min_amount_out: None,
                 })
                 .unwrap(),
                 funds: vec![Coin::new(750_000, "un")],
@@ -1093,6 +1218,85 @@ struct Params {
     ],
     expected_error: None,
 }; "SmartSwapExactAssetIn")]
+#[test_case(Params {
+    caller: "entry_point".to_string(),
+    user_swap: Swap::SmartSwapExactAssetIn(SmartSwapExactAssetIn {
+        swap_venue_name: "swap_venue_name_3".to_string(),
+        routes: vec![
+            Route {
+                offer_asset: Asset::Native(Coin::new(250_000, "un")),
+                operations: vec![SwapOperation {
+                    pool: "1".to_string(),
+                    denom_in: "un".to_string(),
+                    denom_out: "os".to_string(),
+                    interface: None,
+                }],
+            },
+            Route {
+                offer_asset: Asset::Native(Coin::new(750_000, "un")),
+                operations: vec![SwapOperation {
+                    pool: "2".to_string(),
+                    denom_in: "un".to_string(),
+                    denom_out: "os".to_string(),
+                    interface: None,
+                }],
+            },
+        ],
+    }),
+    remaining_asset: Asset::Native(Coin::new(1_000_000, "un")),
+    min_asset: Asset::Native(Coin::new(1_000_000, "os")),
+    affiliates: vec![Affiliate {
+        address: "affiliate".to_string(),
+        basis_points_fee: Uint128::new(1000),
+    }],
+    expected_messages: vec![
+        SubMsg {
+            id: 0,
+            msg: WasmMsg::Execute {
+                contract_addr: "swap_venue_adapter_3".to_string(),
+                msg: to_json_binary(&SwapExecuteMsg::SmartSwap {
+                    routes: vec![
+                        Route {
+                            offer_asset: Asset::Native(Coin::new(250_000, "un")),
+                            operations: vec![SwapOperation {
+                                pool: "1".to_string(),
+                                denom_in: "un".to_string(),
+                                denom_out: "os".to_string(),
+                                interface: None,
+                            }],
+                        },
+                        Route {
+                            offer_asset: Asset::Native(Coin::new(750_000, "un")),
+                            operations: vec![SwapOperation {
+                                pool: "2".to_string(),
+                                denom_in: "un".to_string(),
+                                denom_out: "os".to_string(),
+                                interface: None,
+                            }],
+                        },
+                    ],
+                    min_amount_out: Some(Uint128::new(1_100_000)),
+                })
+                .unwrap(),
+                funds: vec![Coin::new(1_000_000, "un")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+        SubMsg {
+            id: 0,
+            msg: BankMsg::Send {
+                to_address: "affiliate".to_string(),
+                amount: vec![Coin::new(100_000, "os")],
+            }
+            .into(),
+            gas_limit: None,
+            reply_on: Never,
+        },
+    ],
+    expected_error: None,
+}; "SmartSwapExactAssetIn With Smart Swap Execution")]
 #[test_case(Params {
     caller: "entry_point".to_string(),
     user_swap: Swap::SmartSwapExactAssetIn(SmartSwapExactAssetIn {
@@ -1181,8 +1385,28 @@ fn test_execute_user_swap(params: Params) {
     // Create mock wasm handler to handle the swap adapter contract query
     let wasm_handler = |query: &WasmQuery| -> QuerierResult {
         match query {
-            WasmQuery::Smart { contract_addr, .. } => {
-                if contract_addr == "swap_venue_adapter" {
+            WasmQuery::Smart { contract_addr, msg } => {
+                if contract_addr == "swap_venue_adapter_3" {
+                    // The third swap adapter can execute swaps of an exact asset out and smart swaps
+                    match from_json(msg).unwrap() {
+                        SwapQueryMsg::Capabilities {} => SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary(&CapabilitiesResponse {
+                                swap_exact_asset_in: true,
+                                swap_exact_asset_out: true,
+                                swap_exact_asset_out_execution: true,
+                                smart_swap: true,
+                                smart_swap_execution: true,
+                                cw20_input: false,
+                                spot_price: true,
+                                pool_identifiers: vec![PoolIdentifier::PoolId],
+                            })
+                            .unwrap(),
+                        )),
+                        _ => SystemResult::Ok(ContractResult::Ok(
+                            to_json_binary(&Asset::Native(Coin::new(500_000, "un"))).unwrap(),
+                        )),
+                    }
+                } else if contract_addr == "swap_venue_adapter" {
                     SystemResult::Ok(ContractResult::Ok(
                         to_json_binary(&Asset::Native(Coin::new(500_000, "un"))).unwrap(),
                     ))
@@ -1214,6 +1438,7 @@ fn test_execute_user_swap(params: Params) {
     // Store the swap venue adapter contract address in the swap venue map
     let swap_venue_adapter = Addr::unchecked("swap_venue_adapter");
     let swap_venue_adapter_2 = Addr::unchecked("swap_venue_adapter_2");
+    let swap_venue_adapter_3 = Addr::unchecked("swap_venue_adapter_3");
     SWAP_VENUE_MAP
         .save(
             deps.as_mut().storage,
@@ -1228,6 +1453,13 @@ fn test_execute_user_swap(params: Params) {
             &swap_venue_adapter_2,
         )
         .unwrap();
+    SWAP_VENUE_MAP
+        .save(
+            deps.as_mut().storage,
+            "swap_venue_name_3",
+            &swap_venue_adapter_3,
+        )
+        .unwrap();
 
     // Call execute_swap_and_action with the given test case params
     let res = skip_go_entry_point::contract::execute(
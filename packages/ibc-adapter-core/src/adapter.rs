@@ -0,0 +1,281 @@
+use crate::{
+    error::ContractError,
+    state::{ACK_ID_TO_RECOVER_ADDRESS, ENTRY_POINT_CONTRACT_ADDRESS, IN_PROGRESS_RECOVER_ADDRESS},
+};
+use cosmwasm_std::{
+    BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError,
+    StdResult, SubMsg, SubMsgResult,
+};
+use cw2::set_contract_version;
+use skip::{
+    ibc::{AckID, IbcInfo, InstantiateMsg, MigrateMsg},
+    sudo::SudoType,
+};
+
+// Reply id used for the ibc transfer sub message dispatched by every IBC transfer adapter
+pub const REPLY_ID: u64 = 1;
+
+// The IbcTransport trait is implemented by each IBC transfer adapter contract to plug
+// its transport specific message construction into the shared IBC adapter flow.
+//
+// The shared flow authorizes the entry point contract, saves the in progress recover
+// address, dispatches the transfer message as a sub message, maps the AckID of the sent
+// packet to the recover address in the reply, and refunds the recover address when the
+// transport's lifecycle hook (sudo, callbacks, ...) reports an error or timeout.
+//
+// The ibc-callbacks adapter does not use this flow: it is built against cosmwasm-std 2
+// and the skip2 package, its lifecycle is driven by the ibc callbacks entry points
+// rather than sudo, and it refunds the recorded transfer coin instead of the whole
+// contract balance. This crate is built against cosmwasm-std 1 and the skip package,
+// so it cannot expose the types that adapter is built on.
+pub trait IbcTransport {
+    // The transport's error type, which wraps the errors of the shared flow
+    // so each transport can add its own errors without editing this crate.
+    type Error: From<ContractError> + From<StdError>;
+
+    // Validates the given ibc info and converts it and the coin into the
+    // transport's transfer message, which is dispatched as a sub message.
+    fn transfer_msg(
+        deps: DepsMut,
+        env: &Env,
+        ibc_info: IbcInfo,
+        coin: Coin,
+        timeout_timestamp: u64,
+    ) -> Result<CosmosMsg, Self::Error>;
+
+    // Decodes the transfer sub message response data into the
+    // channel id and sequence id of the sent packet.
+    fn ack_id(deps: DepsMut, data: &[u8]) -> Result<(String, u64), Self::Error>;
+}
+
+///////////////
+/// MIGRATE ///
+///////////////
+
+// Sets the contract version and stores the entry point contract address
+pub fn migrate<T: IbcTransport>(
+    deps: DepsMut,
+    contract_name: &str,
+    contract_version: &str,
+    msg: MigrateMsg,
+) -> Result<Response, T::Error> {
+    let checked_entry_point_contract_address = save_entry_point_contract_address::<T>(
+        deps,
+        contract_name,
+        contract_version,
+        &msg.entry_point_contract_address,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute(
+            "entry_point_contract_address",
+            checked_entry_point_contract_address,
+        ))
+}
+
+/////////////////
+// INSTANTIATE //
+/////////////////
+
+// Sets the contract version and stores the entry point contract address
+pub fn instantiate<T: IbcTransport>(
+    deps: DepsMut,
+    contract_name: &str,
+    contract_version: &str,
+    msg: InstantiateMsg,
+) -> Result<Response, T::Error> {
+    let checked_entry_point_contract_address = save_entry_point_contract_address::<T>(
+        deps,
+        contract_name,
+        contract_version,
+        &msg.entry_point_contract_address,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute(
+            "entry_point_contract_address",
+            checked_entry_point_contract_address,
+        ))
+}
+
+///////////////
+/// EXECUTE ///
+///////////////
+
+// Converts the given info and coin into the transport's transfer message,
+// saves necessary info in case the ibc transfer fails to send funds back to
+// a recovery address, and then emits the transfer message as a sub message
+pub fn execute_ibc_transfer<T: IbcTransport>(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    ibc_info: IbcInfo,
+    coin: Coin,
+    timeout_timestamp: u64,
+) -> Result<Response, T::Error> {
+    // Get entry point contract address from storage
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    // Enforce the caller is the entry point contract
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized.into());
+    }
+
+    // Save in progress recover address to storage, to be used in the lifecycle hook
+    IN_PROGRESS_RECOVER_ADDRESS.save(
+        deps.storage,
+        &ibc_info.recover_address, // This address is verified in entry point
+    )?;
+
+    // Create the transport's transfer message
+    let msg = T::transfer_msg(deps.branch(), &env, ibc_info, coin, timeout_timestamp)?;
+
+    // Create sub message from the transfer message to receive a reply
+    let sub_msg = SubMsg::reply_on_success(msg, REPLY_ID);
+
+    Ok(Response::new()
+        .add_submessage(sub_msg)
+        .add_attribute("action", "execute_ibc_transfer"))
+}
+
+/////////////
+/// REPLY ///
+/////////////
+
+// Handles the reply from the transfer sub message
+// Upon success, maps the sub msg AckID (channel_id, sequence_id)
+// to the in progress recover address, and saves it to storage.
+// Now that the map entry is stored, it also removes the in progress
+// recover address from storage.
+pub fn reply<T: IbcTransport>(mut deps: DepsMut, reply: Reply) -> Result<Response, T::Error> {
+    // Error if the reply id is not the same as the one used in the sub message dispatched
+    // This should never happen since we are using a constant reply id, but added in case
+    // the wasm module doesn't behave as expected.
+    if reply.id != REPLY_ID {
+        unreachable!()
+    }
+
+    // Get the sub message response from the reply and error if it does not exist
+    // This should never happen since sub msg was set to reply on success only,
+    // but added in case the wasm module doesn't behave as expected.
+    let SubMsgResult::Ok(sub_msg_response) = reply.result else {
+        unreachable!()
+    };
+
+    // Get the channel id and sequence id of the sent packet from the response data
+    let (channel_id, sequence_id) = T::ack_id(
+        deps.branch(),
+        sub_msg_response
+            .data
+            .ok_or(ContractError::MissingResponseData)?
+            .as_slice(),
+    )?;
+
+    // Set ack_id to be the channel id and sequence id as a tuple
+    let ack_id: AckID = (&channel_id, sequence_id);
+
+    // Get and delete the in progress recover address from storage
+    let in_progress_recover_address = IN_PROGRESS_RECOVER_ADDRESS.load(deps.storage)?;
+    IN_PROGRESS_RECOVER_ADDRESS.remove(deps.storage);
+
+    // Error if unique ack_id (channel id, sequence id) already exists in storage
+    if ACK_ID_TO_RECOVER_ADDRESS.has(deps.storage, ack_id) {
+        return Err(ContractError::AckIDAlreadyExists {
+            channel_id: ack_id.0.into(),
+            sequence_id: ack_id.1,
+        }
+        .into());
+    }
+
+    // Set the in progress recover address to storage, keyed by channel id and sequence id
+    ACK_ID_TO_RECOVER_ADDRESS.save(deps.storage, ack_id, &in_progress_recover_address)?;
+
+    Ok(Response::new().add_attribute("action", "sub_msg_reply_success"))
+}
+
+/////////////////
+/// LIFECYCLE ///
+/////////////////
+
+// Removes the AckID <> in progress recover address from storage once the
+// transport's lifecycle hook reports the ibc transfer was successful.
+pub fn complete_ibc_transfer<T: IbcTransport>(
+    deps: DepsMut,
+    channel_id: &str,
+    sequence_id: u64,
+) -> Result<Response, T::Error> {
+    ACK_ID_TO_RECOVER_ADDRESS.remove(deps.storage, (channel_id, sequence_id));
+
+    Ok(Response::new().add_attribute("action", SudoType::Response))
+}
+
+// Sends the contract's balance to the recover address stored for the given
+// AckID, and removes the AckID <> in progress recover address from storage.
+pub fn refund_ibc_transfer<T: IbcTransport>(
+    deps: DepsMut,
+    env: Env,
+    channel_id: &str,
+    sequence_id: u64,
+    sudo_type: SudoType,
+) -> Result<Response, T::Error> {
+    // Get and remove the AckID <> in progress recover address from storage
+    let ack_id: AckID = (channel_id, sequence_id);
+    let to_address = ACK_ID_TO_RECOVER_ADDRESS.load(deps.storage, ack_id)?;
+    ACK_ID_TO_RECOVER_ADDRESS.remove(deps.storage, ack_id);
+
+    // Get all coins from contract's balance, which will be the failed ibc
+    // transfer coin if the ibc transfer errored or timed out, any refunded
+    // fees, and any leftover dust on the contract
+    let amount = deps.querier.query_all_balances(env.contract.address)?;
+
+    // If amount is empty, return a no funds to refund error
+    if amount.is_empty() {
+        return Err(ContractError::NoFundsToRefund.into());
+    }
+
+    // Create bank send message to send funds back to user's recover address
+    let bank_send_msg = BankMsg::Send { to_address, amount };
+
+    Ok(Response::new()
+        .add_message(bank_send_msg)
+        .add_attribute("action", sudo_type))
+}
+
+/////////////
+/// QUERY ///
+/////////////
+
+// Returns the recover address stored for the given AckID
+pub fn query_in_progress_recover_address(
+    deps: Deps,
+    channel_id: String,
+    sequence_id: u64,
+) -> StdResult<String> {
+    ACK_ID_TO_RECOVER_ADDRESS.load(deps.storage, (&channel_id, sequence_id))
+}
+
+//////////////////////
+// HELPER FUNCTIONS //
+//////////////////////
+
+// Sets the contract version, validates the entry point contract address and stores it
+fn save_entry_point_contract_address<T: IbcTransport>(
+    deps: DepsMut,
+    contract_name: &str,
+    contract_version: &str,
+    entry_point_contract_address: &str,
+) -> Result<String, T::Error> {
+    // Set contract version
+    set_contract_version(deps.storage, contract_name, contract_version)?;
+
+    // Validate entry point contract address
+    let checked_entry_point_contract_address =
+        deps.api.addr_validate(entry_point_contract_address)?;
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
+
+    Ok(checked_entry_point_contract_address.to_string())
+}
@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+// The errors of the shared IBC adapter flow. Transport specific errors live in each
+// adapter's own error type, which wraps these (see IbcTransport::Error).
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("SubMsgResponse does not contain data")]
+    MissingResponseData,
+
+    #[error("Failed to receive ibc funds to refund the user")]
+    NoFundsToRefund,
+
+    #[error("ACK ID already exists for channel ID {channel_id} and sequence ID {sequence_id}")]
+    AckIDAlreadyExists {
+        channel_id: String,
+        sequence_id: u64,
+    },
+}
@@ -0,0 +1,3 @@
+pub mod adapter;
+pub mod error;
+pub mod state;
@@ -0,0 +1,360 @@
+use crate::error::SkipError;
+
+use bech32::{FromBase32, ToBase32, Variant};
+use cosmwasm_std::HexBinary;
+use sha3::{Digest, Keccak256};
+
+// The length in bytes of the recipient addresses used by Hyperlane
+// and the length in bytes of EVM addresses left padded into them
+pub const BYTES32_LENGTH: usize = 32;
+pub const EVM_ADDRESS_LENGTH: usize = 20;
+
+////////////////
+// VALIDATION //
+////////////////
+
+// Returns an error if the given bytes are not exactly 32 bytes long
+pub fn validate_bytes32(bytes32: &HexBinary) -> Result<(), SkipError> {
+    if bytes32.len() != BYTES32_LENGTH {
+        return Err(SkipError::InvalidBytes32Length {
+            length: bytes32.len(),
+        });
+    }
+
+    Ok(())
+}
+
+// Returns an error if the given address is not a bech32 address with the given prefix
+pub fn validate_bech32_address(address: &str, prefix: &str) -> Result<(), SkipError> {
+    let (hrp, _, variant) =
+        bech32::decode(address).map_err(|_| SkipError::InvalidBech32Address {
+            address: address.to_string(),
+        })?;
+    if variant != Variant::Bech32 {
+        return Err(SkipError::InvalidBech32Address {
+            address: address.to_string(),
+        });
+    }
+
+    if hrp != prefix {
+        return Err(SkipError::Bech32PrefixMismatch {
+            address: address.to_string(),
+            prefix: prefix.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+// Returns an error if the given address is not a 0x prefixed 20 byte hex address,
+// or if it is mixed case and does not match its EIP-55 checksum. All lowercase
+// and all uppercase addresses carry no checksum and are accepted as is.
+// Copied into the skip2 package's address module with to_checksum_evm_address
+// and decode_evm_address, keep the copies the same in signature and behaviour.
+pub fn validate_evm_address(address: &str) -> Result<(), SkipError> {
+    decode_evm_address(address).map(|_| ())
+}
+
+////////////////
+// CONVERSION //
+////////////////
+
+// Converts a bech32 address into its 32 byte form, left padding
+// 20 byte account addresses with zeros
+pub fn bech32_to_bytes32(address: &str) -> Result<HexBinary, SkipError> {
+    let invalid_address = || SkipError::InvalidBech32Address {
+        address: address.to_string(),
+    };
+
+    let (_, data, variant) = bech32::decode(address).map_err(|_| invalid_address())?;
+    if variant != Variant::Bech32 {
+        return Err(invalid_address());
+    }
+
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|_| invalid_address())?;
+    if bytes.is_empty() || bytes.len() > BYTES32_LENGTH {
+        return Err(invalid_address());
+    }
+
+    Ok(left_pad_to_bytes32(&bytes))
+}
+
+// Converts a 32 byte address into a bech32 address with the given prefix,
+// stripping the padding of left padded 20 byte account addresses
+pub fn bytes32_to_bech32(bytes32: &HexBinary, prefix: &str) -> Result<String, SkipError> {
+    validate_bytes32(bytes32)?;
+
+    let bytes = if is_left_padded_evm_length(bytes32) {
+        &bytes32[BYTES32_LENGTH - EVM_ADDRESS_LENGTH..]
+    } else {
+        bytes32.as_slice()
+    };
+
+    bech32::encode(prefix, bytes.to_base32(), Variant::Bech32).map_err(|_| {
+        SkipError::InvalidBech32Prefix {
+            prefix: prefix.to_string(),
+        }
+    })
+}
+
+// Converts an EVM hex address into its 32 byte form, left padded with zeros
+pub fn evm_address_to_bytes32(address: &str) -> Result<HexBinary, SkipError> {
+    let bytes = decode_evm_address(address)?;
+
+    Ok(left_pad_to_bytes32(&bytes))
+}
+
+// Converts a 32 byte address holding a left padded 20 byte
+// EVM address into its EIP-55 checksummed hex form
+pub fn bytes32_to_evm_address(bytes32: &HexBinary) -> Result<String, SkipError> {
+    validate_bytes32(bytes32)?;
+
+    if !is_left_padded_evm_length(bytes32) {
+        return Err(SkipError::Bytes32NotEvmAddress);
+    }
+
+    Ok(to_checksum_evm_address(
+        &bytes32[BYTES32_LENGTH - EVM_ADDRESS_LENGTH..],
+    ))
+}
+
+// Formats a 20 byte EVM address as 0x prefixed hex, uppercasing each letter whose
+// corresponding nibble in the keccak256 hash of the lowercase hex address is 8 or more
+pub fn to_checksum_evm_address(bytes: &[u8]) -> String {
+    let lowercase_hex = HexBinary::from(bytes).to_hex();
+    let hash = Keccak256::digest(lowercase_hex.as_bytes());
+
+    let checksummed_hex: String = lowercase_hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    format!("0x{checksummed_hex}")
+}
+
+//////////////////////
+// HELPER FUNCTIONS //
+//////////////////////
+
+// Decodes a 0x prefixed 20 byte hex address, validating its EIP-55 checksum if mixed case
+fn decode_evm_address(address: &str) -> Result<Vec<u8>, SkipError> {
+    let invalid_address = || SkipError::InvalidEvmAddress {
+        address: address.to_string(),
+    };
+
+    let hex = address.strip_prefix("0x").ok_or_else(invalid_address)?;
+    if hex.len() != EVM_ADDRESS_LENGTH * 2 {
+        return Err(invalid_address());
+    }
+
+    let bytes = HexBinary::from_hex(hex).map_err(|_| invalid_address())?;
+
+    let is_mixed_case =
+        hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case && to_checksum_evm_address(&bytes) != address {
+        return Err(SkipError::InvalidEvmAddressChecksum {
+            address: address.to_string(),
+        });
+    }
+
+    Ok(bytes.to_vec())
+}
+
+// Left pads the given bytes with zeros to 32 bytes
+fn left_pad_to_bytes32(bytes: &[u8]) -> HexBinary {
+    let mut bytes32 = vec![0u8; BYTES32_LENGTH - bytes.len()];
+    bytes32.extend_from_slice(bytes);
+
+    bytes32.into()
+}
+
+// Returns whether the leading bytes of the 32 byte address beyond
+// the length of a 20 byte address are all zeros
+fn is_left_padded_evm_length(bytes32: &HexBinary) -> bool {
+    bytes32[..BYTES32_LENGTH - EVM_ADDRESS_LENGTH]
+        .iter()
+        .all(|byte| *byte == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 20 byte account address 0x0102...14, and 32 byte contract address 0x0102...20
+    const ACCOUNT_BECH32: &str = "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw";
+    const CONTRACT_BECH32: &str =
+        "neutron1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqpsa9eu";
+
+    fn account_bytes32() -> HexBinary {
+        left_pad_to_bytes32(&(1..=20).collect::<Vec<u8>>())
+    }
+
+    fn contract_bytes32() -> HexBinary {
+        (1..=32).collect::<Vec<u8>>().into()
+    }
+
+    #[test]
+    fn test_validate_bytes32() {
+        // TEST CASE 1: 32 Bytes
+        assert_eq!(validate_bytes32(&contract_bytes32()), Ok(()));
+
+        // TEST CASE 2: 20 Bytes Without Padding
+        assert_eq!(
+            validate_bytes32(&HexBinary::from(vec![1u8; 20])),
+            Err(SkipError::InvalidBytes32Length { length: 20 })
+        );
+    }
+
+    #[test]
+    fn test_validate_bech32_address() {
+        // TEST CASE 1: Expected Prefix
+        assert_eq!(validate_bech32_address(ACCOUNT_BECH32, "osmo"), Ok(()));
+
+        // TEST CASE 2: Different Prefix
+        assert_eq!(
+            validate_bech32_address(ACCOUNT_BECH32, "neutron"),
+            Err(SkipError::Bech32PrefixMismatch {
+                address: ACCOUNT_BECH32.to_string(),
+                prefix: "neutron".to_string(),
+            })
+        );
+
+        // TEST CASE 3: Not A Bech32 Address
+        assert_eq!(
+            validate_bech32_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "osmo"),
+            Err(SkipError::InvalidBech32Address {
+                address: "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_evm_address() {
+        // TEST CASE 1: Checksummed Addresses
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            assert_eq!(validate_evm_address(address), Ok(()));
+        }
+
+        // TEST CASE 2: All Lowercase And All Uppercase Addresses
+        assert_eq!(
+            validate_evm_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_evm_address("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"),
+            Ok(())
+        );
+
+        // TEST CASE 3: Mixed Case Address With Invalid Checksum
+        assert_eq!(
+            validate_evm_address("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(SkipError::InvalidEvmAddressChecksum {
+                address: "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            })
+        );
+
+        // TEST CASE 4: Malformed Addresses
+        for address in [
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beagg",
+            ACCOUNT_BECH32,
+        ] {
+            assert_eq!(
+                validate_evm_address(address),
+                Err(SkipError::InvalidEvmAddress {
+                    address: address.to_string(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_bech32_to_bytes32() {
+        // TEST CASE 1: 20 Byte Account Address Is Left Padded
+        assert_eq!(bech32_to_bytes32(ACCOUNT_BECH32), Ok(account_bytes32()));
+
+        // TEST CASE 2: 32 Byte Contract Address
+        assert_eq!(bech32_to_bytes32(CONTRACT_BECH32), Ok(contract_bytes32()));
+
+        // TEST CASE 3: Invalid Checksum
+        assert_eq!(
+            bech32_to_bytes32("osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsq"),
+            Err(SkipError::InvalidBech32Address {
+                address: "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsq".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_bytes32_to_bech32() {
+        // TEST CASE 1: Left Padded 20 Byte Account Address
+        assert_eq!(
+            bytes32_to_bech32(&account_bytes32(), "osmo"),
+            Ok(ACCOUNT_BECH32.to_string())
+        );
+
+        // TEST CASE 2: 32 Byte Contract Address
+        assert_eq!(
+            bytes32_to_bech32(&contract_bytes32(), "neutron"),
+            Ok(CONTRACT_BECH32.to_string())
+        );
+
+        // TEST CASE 3: Invalid Prefix
+        assert_eq!(
+            bytes32_to_bech32(&account_bytes32(), "Osmo"),
+            Err(SkipError::InvalidBech32Prefix {
+                prefix: "Osmo".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_evm_address_conversion() {
+        let bytes32 =
+            HexBinary::from_hex("0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed")
+                .unwrap();
+
+        // TEST CASE 1: EVM Address To Bytes32
+        assert_eq!(
+            evm_address_to_bytes32("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Ok(bytes32.clone())
+        );
+
+        // TEST CASE 2: Bytes32 To Checksummed EVM Address
+        assert_eq!(
+            bytes32_to_evm_address(&bytes32),
+            Ok("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string())
+        );
+
+        // TEST CASE 3: Bytes32 Not Holding An EVM Address
+        assert_eq!(
+            bytes32_to_evm_address(&contract_bytes32()),
+            Err(SkipError::Bytes32NotEvmAddress)
+        );
+
+        // TEST CASE 4: Bech32 And EVM Forms Of The Same Account
+        assert_eq!(
+            bech32_to_bytes32("osmo1t2htvpfl862vnwdqnuekd9p4ulh3h6hdlvdhh0"),
+            Ok(bytes32)
+        );
+    }
+}
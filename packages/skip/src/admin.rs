@@ -0,0 +1,77 @@
+use crate::error::SkipError;
+
+use cosmwasm_std::{Addr, Deps, Env};
+
+////////////////
+// VALIDATION //
+////////////////
+
+// Returns an error if the given sender is not the admin of the contract
+pub fn assert_contract_admin(deps: Deps, env: &Env, sender: &Addr) -> Result<(), SkipError> {
+    let contract_info = deps
+        .querier
+        .query_wasm_contract_info(&env.contract.address)?;
+
+    if contract_info.admin.as_deref() != Some(sender.as_str()) {
+        return Err(SkipError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        to_json_binary, ContractInfoResponse, ContractResult as SystemContractResult,
+        QuerierResult, SystemResult, WasmQuery,
+    };
+
+    #[test]
+    fn test_assert_contract_admin() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // Mock the contract info query, with an admin only for the contract itself
+        let contract_address = env.contract.address.to_string();
+        deps.querier
+            .update_wasm(move |query: &WasmQuery| -> QuerierResult {
+                match query {
+                    WasmQuery::ContractInfo { contract_addr } => {
+                        let mut contract_info = ContractInfoResponse::new(1, "creator");
+                        if *contract_addr == contract_address {
+                            contract_info.admin = Some("admin".to_string());
+                        }
+
+                        SystemResult::Ok(SystemContractResult::Ok(
+                            to_json_binary(&contract_info).unwrap(),
+                        ))
+                    }
+                    _ => panic!("Unsupported query: {:?}", query),
+                }
+            });
+
+        // TEST CASE 1: Sender Is The Contract Admin
+        assert_eq!(
+            assert_contract_admin(deps.as_ref(), &env, &Addr::unchecked("admin")),
+            Ok(())
+        );
+
+        // TEST CASE 2: Sender Is Not The Contract Admin
+        assert_eq!(
+            assert_contract_admin(deps.as_ref(), &env, &Addr::unchecked("creator")),
+            Err(SkipError::Unauthorized)
+        );
+
+        // TEST CASE 3: Contract Without An Admin
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("contract_without_admin");
+
+        assert_eq!(
+            assert_contract_admin(deps.as_ref(), &env, &Addr::unchecked("admin")),
+            Err(SkipError::Unauthorized)
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
     asset::Asset,
-    ibc::IbcInfo,
-    swap::{Swap, SwapExactAssetOut, SwapVenue},
+    hyperlane::HplWarpRoute,
+    ibc::{IbcChannelReceiverFormat, IbcInfo},
+    swap::{LimitOrder, Swap, SwapExactAssetOut, SwapVenue},
 };
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary, HexBinary, Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, HexBinary, Uint128};
 use cw20::Cw20ReceiveMsg;
 
 ///////////////
@@ -82,6 +83,25 @@ pub enum ExecuteMsg {
         min_asset: Option<Asset>,
         recovery_addr: Addr,
     },
+    // Only callable by the contract admin, sets the receiver format IBC
+    // transfers over each of the given source channels must have.
+    SetIbcReceiverFormats {
+        receiver_formats: Vec<IbcChannelReceiverFormat>,
+    },
+    // Only callable by the contract admin, sets whether IBC transfers and Hyperlane
+    // transfers are restricted to the allowlisted channels and warp routes.
+    // Transfers are unrestricted until enforcement is enabled.
+    SetRouteAllowlistEnforced {
+        enforced: bool,
+    },
+    // Only callable by the contract admin, adds and removes IBC source
+    // channels and Hyperlane warp routes from the route allowlist.
+    UpdateRouteAllowlist {
+        add_ibc_channels: Vec<String>,
+        remove_ibc_channels: Vec<String>,
+        add_warp_routes: Vec<HplWarpRoute>,
+        remove_warp_routes: Vec<HplWarpRoute>,
+    },
 }
 
 /// This structure describes a CW20 hook message.
@@ -134,6 +154,45 @@ pub enum QueryMsg {
     // transfer adapter contract.
     #[returns(cosmwasm_std::Addr)]
     IbcTransferAdapterContract {},
+
+    // IbcReceiverFormat returns the receiver format of IBC transfers over
+    // the given source channel, any receiver if none has been set.
+    #[returns(crate::ibc::IbcReceiverFormat)]
+    IbcReceiverFormat { source_channel: String },
+
+    // RouteAllowlistEnforced returns whether transfers are restricted
+    // to the allowlisted IBC channels and Hyperlane warp routes.
+    #[returns(bool)]
+    RouteAllowlistEnforced {},
+
+    // AllowedIbcChannels returns the allowlisted IBC source channels.
+    #[returns(Vec<String>)]
+    AllowedIbcChannels {},
+
+    // AllowedWarpRoutes returns the allowlisted Hyperlane warp routes,
+    // only those to the given destination domain if provided.
+    #[returns(Vec<HplWarpRoute>)]
+    AllowedWarpRoutes { dest_domain: Option<u32> },
+
+    // Bech32ToBytes32 returns the 32 byte form of the given bech32
+    // address, left padding 20 byte account addresses with zeros.
+    #[returns(HexBinary)]
+    Bech32ToBytes32 { address: String },
+
+    // Bytes32ToBech32 returns the bech32 address with the given prefix
+    // of the given 32 byte address, stripping 20 byte account padding.
+    #[returns(String)]
+    Bytes32ToBech32 { bytes32: HexBinary, prefix: String },
+
+    // EvmAddressToBytes32 returns the left padded 32 byte form of the
+    // given EVM address, validating its EIP-55 checksum if mixed case.
+    #[returns(HexBinary)]
+    EvmAddressToBytes32 { address: String },
+
+    // Bytes32ToEvmAddress returns the EIP-55 checksummed EVM address
+    // held by the given left padded 32 byte address.
+    #[returns(String)]
+    Bytes32ToEvmAddress { bytes32: HexBinary },
 }
 
 ////////////////////
@@ -161,6 +220,17 @@ pub enum Action {
         hook: Option<String>,
         metadata: Option<HexBinary>,
         warp_address: String,
+        recover_address: Option<String>,
+        // The interchain gas payment for the warp route transfer, deducted from the
+        // action asset unless a fee swap is provided to swap into it.
+        fee: Option<Coin>,
+        fee_swap: Option<SwapExactAssetOut>,
+    },
+    // Places a limit order with the action asset through the swap venue's adapter
+    // contract. Only supported by the duality swap venue.
+    PlaceLimitOrder {
+        swap_venue_name: String,
+        limit_order: LimitOrder,
     },
 }
 
@@ -1,4 +1,4 @@
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{CheckedFromRatioError, OverflowError, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -18,6 +18,9 @@ pub enum SkipError {
     #[error(transparent)]
     Overflow(#[from] OverflowError),
 
+    #[error(transparent)]
+    CheckedFromRatio(#[from] CheckedFromRatioError),
+
     //////////
     // SWAP //
     //////////
@@ -33,6 +36,27 @@ pub enum SkipError {
     #[error("Routes Empty")]
     RoutesEmpty,
 
+    #[error("Execute Msg Not Supported By Swap Adapter")]
+    UnsupportedExecuteMsg,
+
+    #[error("Query Msg Not Supported By Swap Adapter")]
+    UnsupportedQueryMsg,
+
+    #[error("Migration Not Supported By Swap Adapter")]
+    MigrationUnsupported,
+
+    #[error("Smart Swap Over Multiple Routes Not Supported By Swap Adapter")]
+    SmartSwapUnsupported,
+
+    #[error("Cw20 Assets Not Supported By Swap Adapter")]
+    Cw20Unsupported,
+
+    #[error("Swap Operation Max Spread Must Not Be Greater Than One")]
+    InvalidMaxSpread,
+
+    #[error("Swap Operation Belief Price Must Be Greater Than Zero")]
+    InvalidBeliefPrice,
+
     /////////
     // IBC //
     /////////
@@ -47,4 +71,28 @@ pub enum SkipError {
 
     #[error("Cw20 Coin Sent To Contract Does Not Match Asset")]
     InvalidCw20Coin,
+
+    /////////////
+    // ADDRESS //
+    /////////////
+    #[error("Invalid Bech32 Address: {address}")]
+    InvalidBech32Address { address: String },
+
+    #[error("Invalid Bech32 Prefix: {prefix}")]
+    InvalidBech32Prefix { prefix: String },
+
+    #[error("Bech32 Address {address} Does Not Have Expected Prefix {prefix}")]
+    Bech32PrefixMismatch { address: String, prefix: String },
+
+    #[error("Bytes32 Address Must Be 32 Bytes Long, Got {length} Bytes")]
+    InvalidBytes32Length { length: usize },
+
+    #[error("Bytes32 Address Is Not A Left Padded 20 Byte EVM Address")]
+    Bytes32NotEvmAddress,
+
+    #[error("Invalid EVM Address: {address}")]
+    InvalidEvmAddress { address: String },
+
+    #[error("EVM Address Does Not Match Its EIP-55 Checksum: {address}")]
+    InvalidEvmAddressChecksum { address: String },
 }
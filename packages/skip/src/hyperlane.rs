@@ -1,5 +1,16 @@
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::HexBinary;
+use crate::{
+    asset::Asset,
+    entry_point::{Action, Affiliate},
+    swap::Swap,
+};
+
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Coin, HexBinary};
+use cw20::Cw20ReceiveMsg;
+use hpl_interface::{
+    core::HandleMsg,
+    ism::{InterchainSecurityModuleResponse, IsmSpecifierQueryMsg},
+};
 
 ///////////////
 /// MIGRATE ///
@@ -9,6 +20,11 @@ use cosmwasm_std::HexBinary;
 #[cw_serde]
 pub struct MigrateMsg {
     pub entry_point_contract_address: String,
+    pub mailbox_contract_address: Option<String>,
+    #[serde(default)]
+    pub allowed_inbound_senders: Vec<HplInboundSender>,
+    #[serde(default)]
+    pub allowed_inbound_warp_routes: Vec<String>,
 }
 /////////////////
 // INSTANTIATE //
@@ -18,6 +34,14 @@ pub struct MigrateMsg {
 #[cw_serde]
 pub struct InstantiateMsg {
     pub entry_point_contract_address: String,
+    // The mailbox allowed to deliver inbound messages, inbound
+    // messages are rejected if no mailbox is set.
+    pub mailbox_contract_address: Option<String>,
+    #[serde(default)]
+    pub allowed_inbound_senders: Vec<HplInboundSender>,
+    // The local warp routes allowed to deliver the assets of inbound messages.
+    #[serde(default)]
+    pub allowed_inbound_warp_routes: Vec<String>,
 }
 
 ///////////////
@@ -27,11 +51,101 @@ pub struct InstantiateMsg {
 // The ExecuteMsg enum defines the execution message that the IBC Transfer Adapter contracts can handle.
 #[cw_serde]
 pub enum ExecuteMsg {
+    // Cw20 assets are transferred through cw20 collateral warp routes with the Receive execute message
+    Receive(Cw20ReceiveMsg),
     HplTransfer {
         dest_domain: u32,
         recipient: HexBinary,
         hook: Option<String>,
         metadata: Option<HexBinary>,
         warp_address: String,
+        // The address the transferred coin and interchain gas payment
+        // are refunded to if the warp route transfer fails. If not set,
+        // a failed warp route transfer reverts the whole transaction.
+        recover_address: Option<String>,
+        // The interchain gas payment sent to the adapter ahead of the transfer,
+        // attached to the warp route transfer alongside the transferred coin.
+        fee: Option<Coin>,
+    },
+    // Handle is called by the mailbox to deliver inbound messages, the
+    // message body is a JSON encoded HplSwapAndAction.
+    Handle(HandleMsg),
+    // Replaces the mailbox and the senders and warp routes allowed for
+    // inbound messages, only callable by the contract admin.
+    UpdateInboundConfig {
+        mailbox_contract_address: Option<String>,
+        #[serde(default)]
+        allowed_inbound_senders: Vec<HplInboundSender>,
+        #[serde(default)]
+        allowed_inbound_warp_routes: Vec<String>,
     },
 }
+
+// The Cw20HookMsg enum defines the message the Hyperlane Transfer Adapter contract
+// handles when receiving cw20 tokens, mirroring the HplTransfer execute message.
+#[cw_serde]
+pub enum Cw20HookMsg {
+    HplTransfer {
+        dest_domain: u32,
+        recipient: HexBinary,
+        hook: Option<String>,
+        metadata: Option<HexBinary>,
+        warp_address: String,
+        recover_address: Option<String>,
+        fee: Option<Coin>,
+    },
+}
+
+/////////////
+/// QUERY ///
+/////////////
+
+// The QueryMsg enum defines the queries the Hyperlane Transfer Adapter Contract provides.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // IsmSpecifier is queried by the mailbox to get the interchain security
+    // module verifying inbound messages, no module is returned so the
+    // mailbox's default interchain security module is used.
+    #[returns(InterchainSecurityModuleResponse)]
+    IsmSpecifier(IsmSpecifierQueryMsg),
+}
+
+////////////////////
+/// COMMON TYPES ///
+////////////////////
+
+// The HplWarpRoute struct defines a warp route contract transferring to a destination domain.
+#[cw_serde]
+pub struct HplWarpRoute {
+    pub dest_domain: u32,
+    pub warp_address: String,
+}
+
+// The HplInboundSender struct defines a remote sender allowed to send inbound
+// messages, identified by its origin domain and 32 byte sender address.
+#[cw_serde]
+pub struct HplInboundSender {
+    pub origin_domain: u32,
+    pub sender: HexBinary,
+}
+
+// The HplSwapAndAction struct defines the body of inbound Hyperlane messages,
+// the sent asset is received from the warp route ahead of the message and is
+// swapped and actioned through the entry point, recovering to the recovery
+// address on failure.
+#[cw_serde]
+pub struct HplSwapAndAction {
+    pub sent_asset: Asset,
+    pub user_swap: Swap,
+    pub min_asset: Asset,
+    pub timeout_timestamp: u64,
+    pub post_swap_action: Action,
+    pub affiliates: Vec<Affiliate>,
+    pub recovery_addr: String,
+    // The local warp route that delivered the sent asset, and the Hyperlane
+    // message it was delivered with. Each warp route message can only fund
+    // a single inbound message.
+    pub warp_address: String,
+    pub warp_message: HexBinary,
+}
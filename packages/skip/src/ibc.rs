@@ -1,4 +1,8 @@
-use crate::{error::SkipError, proto_coin::ProtoCoin};
+use crate::{
+    address::{validate_bech32_address, validate_evm_address},
+    error::SkipError,
+    proto_coin::ProtoCoin,
+};
 
 use std::convert::From;
 
@@ -133,6 +137,9 @@ pub struct EurekaFee {
     pub timeout_timestamp: u64,
 }
 
+// The encoding of IBC transfers to EVM chains through Eureka, whose receiver must be an EVM address
+pub const IBC_SOLIDITY_ABI_ENCODING: &str = "application/x-solidity-abi";
+
 // The IbcInfo struct defines the information for an IBC transfer standardized across all IBC Transfer Adapter contracts.
 #[cw_serde]
 pub struct IbcInfo {
@@ -145,6 +152,33 @@ pub struct IbcInfo {
     pub eureka_fee: Option<EurekaFee>,
 }
 
+// The IbcReceiverFormat enum defines the format the receiver of IBC transfers
+// over a source channel must have on the chain at the other end of the channel.
+#[cw_serde]
+pub enum IbcReceiverFormat {
+    Bech32 { prefix: String },
+    Evm {},
+    Any {},
+}
+
+impl IbcReceiverFormat {
+    // Returns an error if the given receiver does not have the format
+    pub fn validate(&self, receiver: &str) -> Result<(), SkipError> {
+        match self {
+            IbcReceiverFormat::Bech32 { prefix } => validate_bech32_address(receiver, prefix),
+            IbcReceiverFormat::Evm {} => validate_evm_address(receiver),
+            IbcReceiverFormat::Any {} => Ok(()),
+        }
+    }
+}
+
+// The IbcChannelReceiverFormat struct defines the receiver format of a source channel.
+#[cw_serde]
+pub struct IbcChannelReceiverFormat {
+    pub source_channel: String,
+    pub receiver_format: IbcReceiverFormat,
+}
+
 // The IbcTransfer struct defines the parameters for an IBC transfer standardized across all IBC Transfer Adapter contracts.
 #[cw_serde]
 pub struct IbcTransfer {
@@ -287,4 +321,41 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), SkipError::IbcFeesNotOneCoin);
     }
+
+    #[test]
+    fn test_ibc_receiver_format_validate() {
+        let bech32_receiver = "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw";
+        let evm_receiver = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+
+        // TEST CASE 1: Bech32 Format
+        let receiver_format = IbcReceiverFormat::Bech32 {
+            prefix: "osmo".to_string(),
+        };
+
+        assert_eq!(receiver_format.validate(bech32_receiver), Ok(()));
+        assert_eq!(
+            receiver_format.validate(evm_receiver),
+            Err(SkipError::InvalidBech32Address {
+                address: evm_receiver.to_string(),
+            })
+        );
+
+        // TEST CASE 2: EVM Format
+        let receiver_format = IbcReceiverFormat::Evm {};
+
+        assert_eq!(receiver_format.validate(evm_receiver), Ok(()));
+        assert_eq!(
+            receiver_format.validate(bech32_receiver),
+            Err(SkipError::InvalidEvmAddress {
+                address: bech32_receiver.to_string(),
+            })
+        );
+
+        // TEST CASE 3: Any Format
+        let receiver_format = IbcReceiverFormat::Any {};
+
+        assert_eq!(receiver_format.validate(bech32_receiver), Ok(()));
+        assert_eq!(receiver_format.validate(evm_receiver), Ok(()));
+        assert_eq!(receiver_format.validate("receiver"), Ok(()));
+    }
 }
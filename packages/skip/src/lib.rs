@@ -1,3 +1,5 @@
+pub mod address;
+pub mod admin;
 pub mod asset;
 pub mod entry_point;
 pub mod error;
@@ -6,3 +8,4 @@ pub mod ibc;
 pub mod proto_coin;
 pub mod sudo;
 pub mod swap;
+pub mod swap_adapter;
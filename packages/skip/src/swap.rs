@@ -1,11 +1,15 @@
-use crate::{asset::Asset, error::SkipError};
+use crate::{
+    asset::{get_current_asset_available, Asset},
+    error::SkipError,
+};
 
 use std::{convert::TryFrom, num::ParseIntError};
 
 use astroport::{asset::AssetInfo, router::SwapOperation as AstroportSwapOperation};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{
-    Addr, Api, BankMsg, Binary, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, Uint128,
+    from_json, Addr, Api, BankMsg, Binary, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response,
+    Uint128,
 };
 use cw20::Cw20Contract;
 use cw20::Cw20ReceiveMsg;
@@ -15,6 +19,7 @@ use elys_std::types::elys::amm::{
 use osmosis_std::types::osmosis::poolmanager::v1beta1::{
     SwapAmountInRoute as OsmosisSwapAmountInRoute, SwapAmountOutRoute as OsmosisSwapAmountOutRoute,
 };
+use serde::Deserialize;
 
 ///////////////
 /// MIGRATE ///
@@ -26,17 +31,32 @@ pub struct MigrateMsg {
     pub entry_point_contract_address: String,
 }
 
+#[cw_serde]
+pub struct AstroportMigrateMsg {
+    pub entry_point_contract_address: String,
+    pub router_contract_address: Option<String>,
+}
+
 /////////////////
 // INSTANTIATE //
 /////////////////
 
 // The InstantiateMsg struct defines the initialization parameters for the
-// Osmosis Poolmanager, Astroport and elys Amm swap adapter contracts.
+// Osmosis Poolmanager and elys Amm swap adapter contracts.
 #[cw_serde]
 pub struct InstantiateMsg {
     pub entry_point_contract_address: String,
 }
 
+// The AstroportInstantiateMsg struct defines the initialization parameters for the
+// Astroport swap adapter contract. If the router contract address is set, swaps are
+// executed through the router's swap operations instead of a pool swap per operation.
+#[cw_serde]
+pub struct AstroportInstantiateMsg {
+    pub entry_point_contract_address: String,
+    pub router_contract_address: Option<String>,
+}
+
 #[cw_serde]
 pub struct MantraDexInstantiateMsg {
     pub entry_point_contract_address: String,
@@ -56,10 +76,15 @@ pub struct DexterAdapterInstantiateMsg {
     pub dexter_router_contract_address: String,
 }
 
+// The DropBondInstantiateMsg struct defines the initialization parameters for the
+// Drop swap adapter contract. If the instant unbond pool contract address is set, the
+// bonded denom is unbonded by swapping it for the remote denom through that Astroport
+// pair, since Drop core unbonding only pays out once the unbonding period has passed.
 #[cw_serde]
 pub struct DropBondInstantiateMsg {
     pub entry_point_contract_address: String,
     pub drop_factory_contract_address: String,
+    pub instant_unbond_pool_contract_address: Option<String>,
 }
 
 #[cw_serde]
@@ -80,24 +105,151 @@ pub struct HallswapInstantiateMsg {
     pub hallswap_contract_address: String,
 }
 
+// The WrapperInstantiateMsg struct defines the initialization parameters for the
+// wrapper swap adapter contract, which converts between the denoms of each pair.
+#[cw_serde]
+pub struct WrapperInstantiateMsg {
+    pub entry_point_contract_address: String,
+    pub pairs: Vec<WrapperPair>,
+}
+
+// The WrapperPair struct defines a pair of denoms converted into each other by a wrapper
+// contract, like a cw20 <> tokenfactory wrapper or a canonical bridged asset converter.
+// Cw20 denoms are the cw20 contract addresses.
+#[cw_serde]
+pub struct WrapperPair {
+    pub unwrapped_denom: String,
+    pub wrapped_denom: String,
+    pub wrap: WrapperAction,
+    pub unwrap: WrapperAction,
+    pub rate: WrapperRate,
+}
+
+// The WrapperPairDenoms struct identifies a stored wrapper pair by its denoms
+#[cw_serde]
+pub struct WrapperPairDenoms {
+    pub unwrapped_denom: String,
+    pub wrapped_denom: String,
+}
+
+// The WrapperAction struct defines the message converting one denom of a wrapper pair
+// into the other. The asset in is sent as funds of the execute message, or as a cw20
+// send to the contract with the message as the hook message if the asset in is a cw20 token.
+#[cw_serde]
+pub struct WrapperAction {
+    pub contract_address: String,
+    pub msg: Binary,
+}
+
+// The WrapperRate enum defines the amount of the wrapped denom minted per unwrapped denom
+#[cw_serde]
+pub enum WrapperRate {
+    Fixed(Decimal),
+    // The rate is queried with the smart query message, which must return a Decimal
+    Query {
+        contract_address: String,
+        msg: Binary,
+    },
+}
+
 /////////////////////////
 ///      EXECUTE      ///
 /////////////////////////
 
 // The ExecuteMsg enum defines the execution message that the swap adapter contracts can handle.
 // Only the Swap message is callable by external users.
+//
+// The optional min amount out is the minimum amount of the last swap operation's denom out
+// the swap must return, which the swap adapters pass to the swap venue's slippage protection
+// so a swap that moved too far fails at the venue instead of only in the entry point.
 #[cw_serde]
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
-    Swap { operations: Vec<SwapOperation> },
-    TransferFundsBack { swapper: Addr, return_denom: String },
-    AstroportPoolSwap { operation: SwapOperation }, // Only used for the astroport swap adapter contract
-    WhiteWhalePoolSwap { operation: SwapOperation }, // Only used for the white whale swap adapter contract
+    Swap {
+        operations: Vec<SwapOperation>,
+        min_amount_out: Option<Uint128>,
+    },
+    TransferFundsBack {
+        swapper: Addr,
+        return_denom: String,
+    },
+    // Swaps the coin sent over all the routes at once, with the min amount out being the
+    // minimum amount of the routes' denom out all the routes must return in total.
+    // Only supported by the swap adapters with the smart swap execution capability.
+    SmartSwap {
+        routes: Vec<Route>,
+        min_amount_out: Option<Uint128>,
+    },
+    // Swaps the coin sent for exactly the amount out of the last swap operation's denom out,
+    // refunding the part of the coin sent the swap did not use to the refund address.
+    // Only supported by the swap adapters with the swap exact asset out execution capability.
+    SwapExactAssetOut {
+        operations: Vec<SwapOperation>,
+        amount_out: Uint128,
+        refund_address: String,
+    },
+    // Only used by the swap adapter contracts executing the SwapExactAssetOut message
+    RefundAssetIn {
+        refund_address: Addr,
+        denom_in: String,
+    },
+    // Only used for the astroport swap adapter contract
+    AstroportPoolSwap {
+        operation: SwapOperation,
+        min_amount_out: Option<Uint128>,
+    },
+    // Only used for the white whale swap adapter contract
+    WhiteWhalePoolSwap {
+        operation: SwapOperation,
+        min_amount_out: Option<Uint128>,
+    },
+}
+
+// The DualityExecuteMsg enum defines the execution messages the duality swap adapter
+// contract handles, the swap adapter messages and placing limit orders.
+#[cw_serde]
+#[serde(untagged)]
+pub enum DualityExecuteMsg {
+    SwapAdapter(ExecuteMsg),
+    LimitOrder(DualityLimitOrderExecuteMsg),
+}
+
+// The DualityLimitOrderExecuteMsg enum defines the limit order messages
+// of the duality swap adapter contract.
+#[cw_serde]
+pub enum DualityLimitOrderExecuteMsg {
+    // Places a limit order selling the coin sent, with the limit order's receiver
+    // owning the order.
+    PlaceLimitOrder { limit_order: LimitOrder },
+}
+
+// The WrapperExecuteMsg enum defines the execution messages the wrapper swap adapter
+// contract handles, the swap adapter messages and updating the wrapper pairs.
+#[cw_serde]
+#[serde(untagged)]
+pub enum WrapperExecuteMsg {
+    SwapAdapter(ExecuteMsg),
+    WrapperPairs(WrapperPairsExecuteMsg),
+}
+
+// The WrapperPairsExecuteMsg enum defines the wrapper pair messages
+// of the wrapper swap adapter contract.
+#[cw_serde]
+pub enum WrapperPairsExecuteMsg {
+    // Only callable by the contract admin, removes and then adds wrapper pairs, so a
+    // pair's rate or actions are changed by removing and adding it in the same message.
+    UpdateWrapperPairs {
+        add_pairs: Vec<WrapperPair>,
+        remove_pairs: Vec<WrapperPairDenoms>,
+    },
 }
 
 #[cw_serde]
 pub enum Cw20HookMsg {
-    Swap { operations: Vec<SwapOperation> },
+    Swap {
+        operations: Vec<SwapOperation>,
+        min_amount_out: Option<Uint128>,
+    },
 }
 
 /////////////////////////
@@ -144,6 +296,33 @@ pub enum QueryMsg {
         routes: Vec<Route>,
         include_spot_price: bool,
     },
+    // Capabilities returns the features supported by the swap adapter contract
+    #[returns(CapabilitiesResponse)]
+    Capabilities {},
+}
+
+// The DualityQueryMsg enum defines the queries the duality swap adapter contract
+// provides, the swap adapter queries and the queries of the limit orders it places.
+#[cw_serde]
+#[derive(QueryResponses)]
+#[query_responses(nested)]
+#[serde(untagged)]
+pub enum DualityQueryMsg {
+    SwapAdapter(QueryMsg),
+    LimitOrder(DualityLimitOrderQueryMsg),
+}
+
+// The DualityLimitOrderQueryMsg enum defines the queries of the limit orders
+// placed through the duality swap adapter contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum DualityLimitOrderQueryMsg {
+    // LimitOrderTrancheStatus returns the status of the address' share of a limit order tranche
+    #[returns(LimitOrderTrancheStatusResponse)]
+    LimitOrderTrancheStatus {
+        address: String,
+        tranche_key: String,
+    },
 }
 
 // The SimulateSwapExactAssetInResponse struct defines the response for the
@@ -170,6 +349,56 @@ pub struct SimulateSmartSwapExactAssetInResponse {
     pub spot_price: Option<Decimal>,
 }
 
+// The CapabilitiesResponse struct defines the response for the
+// Capabilities query, used to discover what a swap adapter supports
+// before routing through it.
+#[cw_serde]
+pub struct CapabilitiesResponse {
+    // Swaps of an exact asset in can be executed and simulated
+    pub swap_exact_asset_in: bool,
+    // Swaps of an exact asset out can be simulated, to be executed as an exact asset in swap
+    pub swap_exact_asset_out: bool,
+    // Swaps of an exact asset out can be executed with the SwapExactAssetOut message
+    pub swap_exact_asset_out_execution: bool,
+    // Smart swaps over multiple routes can be simulated
+    pub smart_swap: bool,
+    // Smart swaps over multiple routes can be executed at once with the SmartSwap message
+    pub smart_swap_execution: bool,
+    // Cw20 assets can be swapped in through the Receive execute message
+    pub cw20_input: bool,
+    // Spot prices are returned by the simulation queries with metadata
+    pub spot_price: bool,
+    // The formats of the swap operation pool identifiers the adapter accepts
+    pub pool_identifiers: Vec<PoolIdentifier>,
+}
+
+// The LimitOrderTrancheStatusResponse struct defines the response for the
+// LimitOrderTrancheStatus query. The tranche reserves are None once the
+// tranche is no longer active, i.e. it has been fully filled or has expired.
+#[cw_serde]
+pub struct LimitOrderTrancheStatusResponse {
+    pub shares_owned: Uint128,
+    pub shares_withdrawn: Uint128,
+    pub shares_cancelled: Uint128,
+    pub withdrawable_shares: Uint128,
+    pub reserves_maker_denom: Option<Uint128>,
+    pub reserves_taker_denom: Option<Uint128>,
+}
+
+// The PoolIdentifier enum defines the formats of the pool field of a
+// swap operation that a swap adapter contract can accept.
+#[cw_serde]
+pub enum PoolIdentifier {
+    // The address of the swap venue's pool or pair contract
+    ContractAddress,
+    // The numeric id of the swap venue's pool
+    PoolId,
+    // A swap venue specific pool identifier string
+    VenuePoolIdentifier,
+    // The pool is ignored since the adapter swaps through a single venue
+    Unused,
+}
+
 ////////////////////
 /// COMMON TYPES ///
 ////////////////////
@@ -203,6 +432,28 @@ pub fn get_ask_denom_for_routes(routes: &[Route]) -> Result<String, SkipError> {
     }
 }
 
+// The LimitOrder struct defines a limit order selling the coin sent for the denom out
+// at the tick index, with the receiver owning the order and receiving its filled amount.
+#[cw_serde]
+pub struct LimitOrder {
+    pub denom_out: String,
+    pub tick_index_in_to_out: i64,
+    pub order_type: LimitOrderType,
+    pub max_amount_out: Option<Uint128>,
+    pub receiver: String,
+}
+
+// The LimitOrderType enum defines how long a limit order stays on the order book.
+// The expiration time of a good til time order is a unix timestamp in seconds.
+#[cw_serde]
+pub enum LimitOrderType {
+    GoodTilCancelled,
+    FillOrKill,
+    ImmediateOrCancel,
+    JustInTime,
+    GoodTilTime { expiration_time: u64 },
+}
+
 // Standard swap operation type that contains the pool, denom in, and denom out
 // for the swap operation. The type is converted into the respective swap venues
 // expected format in each adapter contract.
@@ -214,6 +465,64 @@ pub struct SwapOperation {
     pub interface: Option<Binary>,
 }
 
+// Slippage settings of a swap operation on an astroport, white whale or mantra dex
+// pool, json encoded in the swap operation's interface. The max spread defaults to
+// the adapter's max allowed slippage, and no belief price is used by default.
+#[cw_serde]
+#[derive(Default)]
+pub struct SlippageInterface {
+    pub max_spread: Option<Decimal>,
+    pub belief_price: Option<Decimal>,
+}
+
+// The slippage keys read from a swap operation's interface. Other keys are
+// ignored so interfaces carrying other settings keep parsing.
+#[derive(Deserialize)]
+struct SlippageInterfaceKeys {
+    max_spread: Option<Decimal>,
+    belief_price: Option<Decimal>,
+}
+
+impl SwapOperation {
+    // Returns the slippage settings in the swap operation's interface, or the default
+    // settings if the swap operation has no interface or it is not a json object
+    pub fn slippage_interface(&self) -> Result<SlippageInterface, SkipError> {
+        let Some(interface) = &self.interface else {
+            return Ok(SlippageInterface::default());
+        };
+
+        if interface.iter().find(|byte| !byte.is_ascii_whitespace()) != Some(&b'{') {
+            return Ok(SlippageInterface::default());
+        }
+
+        let SlippageInterfaceKeys {
+            max_spread,
+            belief_price,
+        } = from_json(interface)?;
+
+        let slippage_interface = SlippageInterface {
+            max_spread,
+            belief_price,
+        };
+
+        if slippage_interface
+            .max_spread
+            .is_some_and(|max_spread| max_spread > Decimal::one())
+        {
+            return Err(SkipError::InvalidMaxSpread);
+        }
+
+        if slippage_interface
+            .belief_price
+            .is_some_and(|belief_price| belief_price.is_zero())
+        {
+            return Err(SkipError::InvalidBeliefPrice);
+        }
+
+        Ok(slippage_interface)
+    }
+}
+
 // ASTROPORT CONVERSION
 
 // Converts a swap operation to an astroport swap operation
@@ -367,6 +676,7 @@ impl From<SwapExactAssetOut> for ExecuteMsg {
     fn from(swap: SwapExactAssetOut) -> Self {
         ExecuteMsg::Swap {
             operations: swap.operations,
+            min_amount_out: None,
         }
     }
 }
@@ -377,6 +687,7 @@ impl From<SwapExactAssetIn> for ExecuteMsg {
     fn from(swap: SwapExactAssetIn) -> Self {
         ExecuteMsg::Swap {
             operations: swap.operations,
+            min_amount_out: None,
         }
     }
 }
@@ -423,6 +734,37 @@ pub fn execute_transfer_funds_back(
         .add_attribute("action", "dispatch_transfer_funds_back_bank_send"))
 }
 
+// Refunds the contract's balance of the denom in to the refund address
+// after a swap of an exact asset out, if any of it was left unused
+pub fn execute_refund_asset_in(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    refund_address: Addr,
+    denom_in: String,
+) -> Result<Response, SkipError> {
+    // Ensure the caller is the contract itself
+    if info.sender != env.contract.address {
+        return Err(SkipError::Unauthorized);
+    }
+
+    // Get the unused asset in left in the contract
+    let unused_asset_in = get_current_asset_available(&deps, &env, &denom_in)?;
+
+    let mut response = Response::new().add_attribute("action", "execute_refund_asset_in");
+
+    // Only refund the unused asset in if there is any, since a bank send of zero fails
+    if !unused_asset_in.amount().is_zero() {
+        response = response
+            .add_message(unused_asset_in.transfer(refund_address.as_str()))
+            .add_attribute("action", "dispatch_refund_asset_in")
+            .add_attribute("address", refund_address)
+            .add_attribute("amount", unused_asset_in.amount());
+    }
+
+    Ok(response)
+}
+
 // Validates the swap operations
 pub fn validate_swap_operations(
     swap_operations: &[SwapOperation],
@@ -447,11 +789,34 @@ pub fn validate_swap_operations(
     Ok(())
 }
 
+// Calculates the spot price of a smart swap as the average of the routes' spot prices,
+// weighted by the share of the asset in offered to each route. Takes the offer amount
+// and spot price of each route, and errors if the asset in amount is zero.
+pub fn calculate_weighted_spot_price(
+    asset_in: &Asset,
+    route_spot_prices: impl IntoIterator<Item = (Uint128, Decimal)>,
+) -> Result<Decimal, SkipError> {
+    route_spot_prices.into_iter().try_fold(
+        Decimal::zero(),
+        |curr_spot_price, (offer_amount, route_spot_price)| {
+            let weight = Decimal::checked_from_ratio(offer_amount, asset_in.amount())?;
+
+            Ok(curr_spot_price.checked_add(route_spot_price.checked_mul(weight)?)?)
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{
+        testing::{
+            mock_dependencies, mock_dependencies_with_balances, mock_env, mock_info,
+            MOCK_CONTRACT_ADDR,
+        },
+        to_json_binary, Coin, SubMsg,
+    };
 
     #[test]
     fn test_from_swap_operation_to_astropot_swap_operation() {
@@ -671,6 +1036,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_swap_operation_slippage_interface() {
+        let swap_operation = |interface: Option<Binary>| SwapOperation {
+            pool: "pool".to_string(),
+            denom_in: "ua".to_string(),
+            denom_out: "uo".to_string(),
+            interface,
+        };
+
+        // TEST CASE 1: No Interface
+        assert_eq!(
+            swap_operation(None).slippage_interface().unwrap(),
+            SlippageInterface::default()
+        );
+
+        // TEST CASE 2: Max Spread And Belief Price
+        let slippage_interface = swap_operation(Some(Binary::from(
+            br#"{"max_spread":"0.01","belief_price":"1.5"}"#.as_slice(),
+        )))
+        .slippage_interface()
+        .unwrap();
+
+        assert_eq!(
+            slippage_interface,
+            SlippageInterface {
+                max_spread: Some(Decimal::percent(1)),
+                belief_price: Some(Decimal::percent(150)),
+            }
+        );
+
+        // TEST CASE 3: Only Max Spread
+        let slippage_interface =
+            swap_operation(Some(Binary::from(br#"{"max_spread":"0.01"}"#.as_slice())))
+                .slippage_interface()
+                .unwrap();
+
+        assert_eq!(
+            slippage_interface,
+            SlippageInterface {
+                max_spread: Some(Decimal::percent(1)),
+                belief_price: None,
+            }
+        );
+
+        // TEST CASE 4: Max Spread Greater Than One
+        let result = swap_operation(Some(Binary::from(br#"{"max_spread":"1.1"}"#.as_slice())))
+            .slippage_interface();
+
+        assert_eq!(result.unwrap_err(), SkipError::InvalidMaxSpread);
+
+        // TEST CASE 5: Zero Belief Price
+        let result = swap_operation(Some(Binary::from(br#"{"belief_price":"0"}"#.as_slice())))
+            .slippage_interface();
+
+        assert_eq!(result.unwrap_err(), SkipError::InvalidBeliefPrice);
+
+        // TEST CASE 6: Interface Without Slippage Keys
+        let slippage_interface = swap_operation(Some(Binary::from(br#"{"route":"1"}"#.as_slice())))
+            .slippage_interface()
+            .unwrap();
+
+        assert_eq!(slippage_interface, SlippageInterface::default());
+
+        // TEST CASE 7: Slippage Keys Alongside Other Keys
+        let slippage_interface = swap_operation(Some(Binary::from(
+            br#"{"route":"1","max_spread":"0.01"}"#.as_slice(),
+        )))
+        .slippage_interface()
+        .unwrap();
+
+        assert_eq!(
+            slippage_interface,
+            SlippageInterface {
+                max_spread: Some(Decimal::percent(1)),
+                belief_price: None,
+            }
+        );
+
+        // TEST CASE 8: Interface Not A Json Object
+        let slippage_interface = swap_operation(Some(Binary::from(b"\x01\x02".as_slice())))
+            .slippage_interface()
+            .unwrap();
+
+        assert_eq!(slippage_interface, SlippageInterface::default());
+
+        // TEST CASE 9: Invalid Slippage Key Value
+        let result = swap_operation(Some(Binary::from(br#"{"max_spread":"abc"}"#.as_slice())))
+            .slippage_interface();
+
+        assert!(matches!(result.unwrap_err(), SkipError::Std(_)));
+    }
+
     #[test]
     fn test_validate_swap_operations() {
         // TEST CASE 1: Valid Swap Operations
@@ -761,4 +1218,132 @@ mod tests {
             SkipError::SwapOperationsAssetOutDenomMismatch
         );
     }
+
+    #[test]
+    fn test_execute_refund_asset_in() {
+        // TEST CASE 1: Unused Asset In Is Refunded
+        let mut deps = mock_dependencies_with_balances(&[(
+            MOCK_CONTRACT_ADDR,
+            &[Coin::new(100, "uosmo"), Coin::new(50, "untrn")],
+        )]);
+
+        let res = execute_refund_asset_in(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            Addr::unchecked("refund_address"),
+            "uosmo".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "refund_address".to_string(),
+                amount: vec![Coin::new(100, "uosmo")],
+            }))]
+        );
+
+        // TEST CASE 2: No Unused Asset In
+        let mut deps =
+            mock_dependencies_with_balances(&[(MOCK_CONTRACT_ADDR, &[Coin::new(50, "untrn")])]);
+
+        let res = execute_refund_asset_in(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MOCK_CONTRACT_ADDR, &[]),
+            Addr::unchecked("refund_address"),
+            "uosmo".to_string(),
+        )
+        .unwrap();
+
+        assert!(res.messages.is_empty());
+
+        // TEST CASE 3: Unauthorized Caller
+        let res = execute_refund_asset_in(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            Addr::unchecked("refund_address"),
+            "uosmo".to_string(),
+        );
+
+        assert_eq!(res.unwrap_err(), SkipError::Unauthorized);
+    }
+
+    #[test]
+    fn test_calculate_weighted_spot_price() {
+        // TEST CASE 1: Routes Weighted By Their Share Of The Asset In
+        let asset_in = Asset::Native(Coin::new(100, "uosmo"));
+
+        let res = calculate_weighted_spot_price(
+            &asset_in,
+            vec![
+                (Uint128::new(25), Decimal::percent(200)),
+                (Uint128::new(75), Decimal::percent(100)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(res, Decimal::percent(125));
+
+        // TEST CASE 2: Zero Asset In
+        let asset_in = Asset::Native(Coin::new(0, "uosmo"));
+
+        let res = calculate_weighted_spot_price(
+            &asset_in,
+            vec![(Uint128::new(25), Decimal::percent(200))],
+        );
+
+        assert!(matches!(res, Err(SkipError::CheckedFromRatio(_))));
+    }
+
+    #[test]
+    fn test_adapter_execute_msgs() {
+        // TEST CASE 1: Swap Adapter Message
+        let msg: WrapperExecuteMsg =
+            from_json(r#"{"transfer_funds_back":{"swapper":"swapper","return_denom":"uosmo"}}"#)
+                .unwrap();
+
+        assert_eq!(
+            msg,
+            WrapperExecuteMsg::SwapAdapter(ExecuteMsg::TransferFundsBack {
+                swapper: Addr::unchecked("swapper"),
+                return_denom: "uosmo".to_string(),
+            })
+        );
+
+        // TEST CASE 2: Wrapper Pairs Message
+        let msg: WrapperExecuteMsg =
+            from_json(r#"{"update_wrapper_pairs":{"add_pairs":[],"remove_pairs":[]}}"#).unwrap();
+
+        assert_eq!(
+            msg,
+            WrapperExecuteMsg::WrapperPairs(WrapperPairsExecuteMsg::UpdateWrapperPairs {
+                add_pairs: vec![],
+                remove_pairs: vec![],
+            })
+        );
+
+        // TEST CASE 3: Serialized The Same As The Wrapped Message
+        assert_eq!(
+            to_json_binary(&DualityExecuteMsg::SwapAdapter(ExecuteMsg::RefundAssetIn {
+                refund_address: Addr::unchecked("refund"),
+                denom_in: "uosmo".to_string(),
+            }))
+            .unwrap(),
+            to_json_binary(&ExecuteMsg::RefundAssetIn {
+                refund_address: Addr::unchecked("refund"),
+                denom_in: "uosmo".to_string(),
+            })
+            .unwrap()
+        );
+
+        // TEST CASE 4: Message Of Another Swap Adapter
+        let res = from_json::<DualityExecuteMsg>(
+            r#"{"update_wrapper_pairs":{"add_pairs":[],"remove_pairs":[]}}"#,
+        );
+
+        assert!(res.is_err());
+    }
 }
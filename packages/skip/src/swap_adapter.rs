@@ -0,0 +1,728 @@
+use crate::{
+    asset::Asset,
+    error::SkipError,
+    swap::{
+        calculate_weighted_spot_price, execute_transfer_funds_back, CapabilitiesResponse,
+        Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, PoolIdentifier, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation,
+    },
+};
+
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo,
+    OverflowError, Response, StdError, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::{Cw20Coin, Cw20ReceiveMsg};
+use cw_storage_plus::Item;
+use cw_utils::{one_coin, PaymentError};
+
+// Entry point contract address stored by swap adapters built on the SwapAdapter trait.
+// Uses the same storage key as the existing swap adapter contracts so they can migrate.
+pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+
+// The SwapAdapter trait is implemented by a swap venue to get the swap adapter
+// entry points (instantiate, migrate, execute, query) for free.
+//
+// A venue implements single hop simulation, reverse simulation, spot price and
+// swap message construction. The framework provides the entry point authorization,
+// CW20 receive handling, the TransferFundsBack self call, multi-hop and smart swap
+// simulation, and the spot price metadata of the simulation queries.
+pub trait SwapAdapter {
+    type Error: From<SkipError> + From<StdError> + From<OverflowError> + From<PaymentError>;
+
+    // The formats of the swap operation pool identifiers the venue accepts
+    const POOL_IDENTIFIERS: &'static [PoolIdentifier];
+
+    // Simulates a single swap operation, returning the asset out received for the given asset in
+    fn simulate_hop_exact_asset_in(
+        deps: Deps,
+        asset_in: Asset,
+        operation: &SwapOperation,
+    ) -> Result<Asset, Self::Error>;
+
+    // Reverse simulates a single swap operation, returning the asset in needed to receive the given asset out
+    fn simulate_hop_exact_asset_out(
+        deps: Deps,
+        asset_out: Asset,
+        operation: &SwapOperation,
+    ) -> Result<Asset, Self::Error>;
+
+    // Returns the spot price of a single swap operation, in denom out per denom in
+    fn hop_spot_price(deps: Deps, operation: &SwapOperation) -> Result<Decimal, Self::Error>;
+
+    // Creates the venue messages that swap the given asset in over the swap operations,
+    // leaving the asset out on the adapter contract to be transferred back to the swapper.
+    // The min amount out, if given, must be enforced by the venue's slippage protection.
+    fn swap_msgs(
+        deps: Deps,
+        env: &Env,
+        asset_in: Asset,
+        operations: Vec<SwapOperation>,
+        min_amount_out: Option<Uint128>,
+    ) -> Result<Vec<CosmosMsg>, Self::Error>;
+
+    // Returns the denom the swap of the asset in over the swap operations returns,
+    // which is the last swap operation's denom out. Venues that derive the denom out
+    // from the asset in itself can override this.
+    fn return_denom(
+        _deps: Deps,
+        _asset_in: &Asset,
+        operations: &[SwapOperation],
+    ) -> Result<String, Self::Error> {
+        match operations.last() {
+            Some(last_op) => Ok(last_op.denom_out.clone()),
+            None => Err(SkipError::SwapOperationsEmpty.into()),
+        }
+    }
+
+    // Simulates a multi-hop swap exact asset in by folding the single hop simulations.
+    // Venues that can simulate a whole route in a single query, or that do not use
+    // the swap operations at all, can override this.
+    fn simulate_swap_exact_asset_in(
+        deps: Deps,
+        asset_in: Asset,
+        operations: &[SwapOperation],
+    ) -> Result<Asset, Self::Error> {
+        // Error if swap operations is empty
+        let Some(first_op) = operations.first() else {
+            return Err(SkipError::SwapOperationsEmpty.into());
+        };
+
+        // Ensure asset_in's denom is the same as the first swap operation's denom in
+        if asset_in.denom() != first_op.denom_in {
+            return Err(SkipError::SwapOperationsAssetInDenomMismatch.into());
+        }
+
+        operations
+            .iter()
+            .try_fold(asset_in, |asset_out, operation| {
+                Self::simulate_hop_exact_asset_in(deps, asset_out, operation)
+            })
+    }
+
+    // Simulates a multi-hop swap exact asset out by folding the single hop reverse simulations.
+    // Venues that can reverse simulate a whole route in a single query, or that do not use
+    // the swap operations at all, can override this.
+    fn simulate_swap_exact_asset_out(
+        deps: Deps,
+        asset_out: Asset,
+        operations: &[SwapOperation],
+    ) -> Result<Asset, Self::Error> {
+        // Error if swap operations is empty
+        let Some(last_op) = operations.last() else {
+            return Err(SkipError::SwapOperationsEmpty.into());
+        };
+
+        // Ensure asset_out's denom is the same as the last swap operation's denom out
+        if asset_out.denom() != last_op.denom_out {
+            return Err(SkipError::SwapOperationsAssetOutDenomMismatch.into());
+        }
+
+        operations
+            .iter()
+            .rev()
+            .try_fold(asset_out, |asset_in_needed, operation| {
+                Self::simulate_hop_exact_asset_out(deps, asset_in_needed, operation)
+            })
+    }
+
+    // Calculates the spot price of a multi-hop swap as the product of the single hop spot prices
+    fn spot_price(deps: Deps, operations: &[SwapOperation]) -> Result<Decimal, Self::Error> {
+        operations
+            .iter()
+            .try_fold(Decimal::one(), |curr_spot_price, operation| {
+                Ok(curr_spot_price.checked_mul(Self::hop_spot_price(deps, operation)?)?)
+            })
+    }
+
+    // Returns the capabilities of the swap adapter, the framework supports every
+    // feature so only the pool identifiers are venue specific. Venues that cannot
+    // swap every asset, such as cw20 tokens, can override this.
+    fn capabilities() -> CapabilitiesResponse {
+        CapabilitiesResponse {
+            swap_exact_asset_in: true,
+            swap_exact_asset_out: true,
+            swap_exact_asset_out_execution: false,
+            smart_swap: true,
+            smart_swap_execution: false,
+            cw20_input: true,
+            spot_price: true,
+            pool_identifiers: Self::POOL_IDENTIFIERS.to_vec(),
+        }
+    }
+}
+
+///////////////
+/// MIGRATE ///
+///////////////
+
+// Sets the contract version and stores the entry point contract address
+pub fn migrate<A: SwapAdapter>(
+    deps: DepsMut,
+    contract_name: &str,
+    contract_version: &str,
+    msg: MigrateMsg,
+) -> Result<Response, A::Error> {
+    let checked_entry_point_contract_address = save_entry_point_contract_address::<A>(
+        deps,
+        contract_name,
+        contract_version,
+        &msg.entry_point_contract_address,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute(
+            "entry_point_contract_address",
+            checked_entry_point_contract_address,
+        ))
+}
+
+/////////////////
+// INSTANTIATE //
+/////////////////
+
+// Sets the contract version and stores the entry point contract address
+pub fn instantiate<A: SwapAdapter>(
+    deps: DepsMut,
+    contract_name: &str,
+    contract_version: &str,
+    msg: InstantiateMsg,
+) -> Result<Response, A::Error> {
+    let checked_entry_point_contract_address = save_entry_point_contract_address::<A>(
+        deps,
+        contract_name,
+        contract_version,
+        &msg.entry_point_contract_address,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute(
+            "entry_point_contract_address",
+            checked_entry_point_contract_address,
+        ))
+}
+
+///////////////
+/// EXECUTE ///
+///////////////
+
+// Handles the swap adapter execute messages. Venue specific execute
+// messages are not supported by the framework and must be handled
+// by the adapter contract before calling this function.
+pub fn execute<A: SwapAdapter>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, A::Error> {
+    match msg {
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20::<A>(deps, env, info, cw20_msg),
+        ExecuteMsg::Swap {
+            operations,
+            min_amount_out,
+        } => {
+            // Enforce the caller is the entry point contract before checking the coin sent
+            let entry_point_contract_address =
+                assert_entry_point_contract::<A>(deps.as_ref(), &info.sender)?;
+
+            let asset_in = one_coin(&info)?.into();
+
+            execute_swap::<A>(
+                deps,
+                env,
+                entry_point_contract_address,
+                asset_in,
+                operations,
+                min_amount_out,
+            )
+        }
+        ExecuteMsg::TransferFundsBack {
+            swapper,
+            return_denom,
+        } => Ok(execute_transfer_funds_back(
+            deps,
+            env,
+            info,
+            swapper,
+            return_denom,
+        )?),
+        _ => Err(SkipError::UnsupportedExecuteMsg.into()),
+    }
+}
+
+// Receives cw20 tokens and executes the swap with them
+fn receive_cw20<A: SwapAdapter>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, A::Error> {
+    let sent_asset = Asset::Cw20(Cw20Coin {
+        address: info.sender.to_string(),
+        amount: cw20_msg.amount,
+    });
+    sent_asset.validate(&deps, &env, &info)?;
+
+    // Enforce the originating address that triggered the cw20 send call is the entry point contract
+    let sender = deps.api.addr_validate(&cw20_msg.sender)?;
+    let entry_point_contract_address = assert_entry_point_contract::<A>(deps.as_ref(), &sender)?;
+
+    match from_json(&cw20_msg.msg)? {
+        Cw20HookMsg::Swap {
+            operations,
+            min_amount_out,
+        } => execute_swap::<A>(
+            deps,
+            env,
+            entry_point_contract_address,
+            sent_asset,
+            operations,
+            min_amount_out,
+        ),
+    }
+}
+
+// Executes a swap with the given swap operations and then transfers the funds back to the entry point contract
+fn execute_swap<A: SwapAdapter>(
+    deps: DepsMut,
+    env: Env,
+    entry_point_contract_address: Addr,
+    asset_in: Asset,
+    operations: Vec<SwapOperation>,
+    min_amount_out: Option<Uint128>,
+) -> Result<Response, A::Error> {
+    // Get the denom the swap returns
+    let return_denom = A::return_denom(deps.as_ref(), &asset_in, &operations)?;
+
+    // Create the venue swap messages
+    let swap_msgs = A::swap_msgs(deps.as_ref(), &env, asset_in, operations, min_amount_out)?;
+
+    // Create the transfer funds back message
+    let transfer_funds_back_msg = WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: entry_point_contract_address,
+            return_denom,
+        })?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_messages(swap_msgs)
+        .add_message(transfer_funds_back_msg)
+        .add_attribute("action", "dispatch_swap_and_transfer_back"))
+}
+
+/////////////
+/// QUERY ///
+/////////////
+
+// Handles the swap adapter simulation and capabilities queries
+pub fn query<A: SwapAdapter>(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, A::Error> {
+    match msg {
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in,
+            swap_operations,
+        } => to_json_binary(&A::simulate_swap_exact_asset_in(
+            deps,
+            asset_in,
+            &swap_operations,
+        )?),
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out,
+            swap_operations,
+        } => to_json_binary(&A::simulate_swap_exact_asset_out(
+            deps,
+            asset_out,
+            &swap_operations,
+        )?),
+        QueryMsg::SimulateSwapExactAssetInWithMetadata {
+            asset_in,
+            swap_operations,
+            include_spot_price,
+        } => to_json_binary(&SimulateSwapExactAssetInResponse {
+            asset_out: A::simulate_swap_exact_asset_in(deps, asset_in, &swap_operations)?,
+            spot_price: include_spot_price
+                .then(|| A::spot_price(deps, &swap_operations))
+                .transpose()?,
+        }),
+        QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+            asset_out,
+            swap_operations,
+            include_spot_price,
+        } => to_json_binary(&SimulateSwapExactAssetOutResponse {
+            asset_in: A::simulate_swap_exact_asset_out(deps, asset_out, &swap_operations)?,
+            spot_price: include_spot_price
+                .then(|| A::spot_price(deps, &swap_operations))
+                .transpose()?,
+        }),
+        QueryMsg::SimulateSmartSwapExactAssetIn { routes, .. } => {
+            to_json_binary(&simulate_smart_swap_exact_asset_in::<A>(deps, &routes)?)
+        }
+        QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
+            asset_in,
+            routes,
+            include_spot_price,
+        } => to_json_binary(&SimulateSmartSwapExactAssetInResponse {
+            asset_out: simulate_smart_swap_exact_asset_in::<A>(deps, &routes)?,
+            spot_price: include_spot_price
+                .then(|| -> Result<Decimal, A::Error> {
+                    let route_spot_prices = routes
+                        .iter()
+                        .map(|route| {
+                            Ok((
+                                route.offer_asset.amount(),
+                                A::spot_price(deps, &route.operations)?,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, A::Error>>()?;
+
+                    Ok(calculate_weighted_spot_price(&asset_in, route_spot_prices)?)
+                })
+                .transpose()?,
+        }),
+        QueryMsg::Capabilities {} => to_json_binary(&A::capabilities()),
+    }
+    .map_err(From::from)
+}
+
+// Simulates each route of a smart swap, returning the sum of the routes' asset out
+fn simulate_smart_swap_exact_asset_in<A: SwapAdapter>(
+    deps: Deps,
+    routes: &[Route],
+) -> Result<Asset, A::Error> {
+    // Error if routes is empty
+    let Some((first_route, other_routes)) = routes.split_first() else {
+        return Err(SkipError::RoutesEmpty.into());
+    };
+
+    // The asset out is taken from the first route's simulation so venues that
+    // do not use the swap operations do not need them to get the ask denom
+    let mut asset_out = A::simulate_swap_exact_asset_in(
+        deps,
+        first_route.offer_asset.clone(),
+        &first_route.operations,
+    )?;
+
+    for route in other_routes {
+        let route_asset_out =
+            A::simulate_swap_exact_asset_in(deps, route.offer_asset.clone(), &route.operations)?;
+
+        asset_out.add(route_asset_out.amount())?;
+    }
+
+    Ok(asset_out)
+}
+
+//////////////////////
+// COMMON FUNCTIONS //
+//////////////////////
+
+// Returns the stored entry point contract address, erroring if the caller is not the entry point contract
+fn assert_entry_point_contract<A: SwapAdapter>(
+    deps: Deps,
+    caller: &Addr,
+) -> Result<Addr, A::Error> {
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    if *caller != entry_point_contract_address {
+        return Err(SkipError::Unauthorized.into());
+    }
+
+    Ok(entry_point_contract_address)
+}
+
+// Sets the contract version, validates the entry point contract address and stores it
+fn save_entry_point_contract_address<A: SwapAdapter>(
+    deps: DepsMut,
+    contract_name: &str,
+    contract_version: &str,
+    entry_point_contract_address: &str,
+) -> Result<String, A::Error> {
+    // Set contract version
+    set_contract_version(deps.storage, contract_name, contract_version)?;
+
+    // Validate entry point contract address
+    let checked_entry_point_contract_address =
+        deps.api.addr_validate(entry_point_contract_address)?;
+
+    // Store the entry point contract address
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &checked_entry_point_contract_address)?;
+
+    Ok(checked_entry_point_contract_address.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env, mock_info},
+        Coin,
+    };
+
+    // MockVenue swaps at a fixed rate of 2 denom out per denom in
+    struct MockVenue;
+
+    impl SwapAdapter for MockVenue {
+        type Error = SkipError;
+
+        const POOL_IDENTIFIERS: &'static [PoolIdentifier] = &[PoolIdentifier::ContractAddress];
+
+        fn simulate_hop_exact_asset_in(
+            deps: Deps,
+            asset_in: Asset,
+            operation: &SwapOperation,
+        ) -> Result<Asset, SkipError> {
+            Ok(Asset::new(
+                deps.api,
+                &operation.denom_out,
+                asset_in.amount().checked_mul(Uint128::new(2))?,
+            ))
+        }
+
+        fn simulate_hop_exact_asset_out(
+            deps: Deps,
+            asset_out: Asset,
+            operation: &SwapOperation,
+        ) -> Result<Asset, SkipError> {
+            Ok(Asset::new(
+                deps.api,
+                &operation.denom_in,
+                Uint128::new((asset_out.amount().u128() + 1) / 2),
+            ))
+        }
+
+        fn hop_spot_price(_deps: Deps, _operation: &SwapOperation) -> Result<Decimal, SkipError> {
+            Ok(Decimal::from_ratio(2u128, 1u128))
+        }
+
+        fn swap_msgs(
+            _deps: Deps,
+            _env: &Env,
+            asset_in: Asset,
+            operations: Vec<SwapOperation>,
+            min_amount_out: Option<Uint128>,
+        ) -> Result<Vec<CosmosMsg>, SkipError> {
+            Ok(vec![asset_in
+                .into_wasm_msg(
+                    operations[0].pool.clone(),
+                    to_json_binary(&ExecuteMsg::Swap {
+                        operations,
+                        min_amount_out,
+                    })?,
+                )?
+                .into()])
+        }
+    }
+
+    fn swap_operation(pool: &str, denom_in: &str, denom_out: &str) -> SwapOperation {
+        SwapOperation {
+            pool: pool.to_string(),
+            denom_in: denom_in.to_string(),
+            denom_out: denom_out.to_string(),
+            interface: None,
+        }
+    }
+
+    #[test]
+    fn test_execute_swap() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked("swap_contract_address");
+
+        ENTRY_POINT_CONTRACT_ADDRESS
+            .save(deps.as_mut().storage, &Addr::unchecked("entry_point"))
+            .unwrap();
+
+        let operations = vec![swap_operation("pool_1", "os", "ua")];
+
+        // TEST CASE 1: Swap Dispatches Venue Swap And Transfer Funds Back
+        let res = execute::<MockVenue>(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("entry_point", &[Coin::new(100, "os")]),
+            ExecuteMsg::Swap {
+                operations: operations.clone(),
+                min_amount_out: Some(Uint128::new(150)),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "pool_1".to_string(),
+                msg: to_json_binary(&ExecuteMsg::Swap {
+                    operations: operations.clone(),
+                    min_amount_out: Some(Uint128::new(150)),
+                })
+                .unwrap(),
+                funds: vec![Coin::new(100, "os")],
+            })
+        );
+        assert_eq!(
+            res.messages[1].msg,
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: "swap_contract_address".to_string(),
+                msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+                    swapper: Addr::unchecked("entry_point"),
+                    return_denom: "ua".to_string(),
+                })
+                .unwrap(),
+                funds: vec![],
+            })
+        );
+
+        // TEST CASE 2: Unauthorized Caller
+        let res = execute::<MockVenue>(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("random", &[Coin::new(100, "os")]),
+            ExecuteMsg::Swap {
+                operations: operations.clone(),
+                min_amount_out: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), SkipError::Unauthorized);
+
+        // TEST CASE 3: Empty Swap Operations
+        let res = execute::<MockVenue>(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("entry_point", &[Coin::new(100, "os")]),
+            ExecuteMsg::Swap {
+                operations: vec![],
+                min_amount_out: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), SkipError::SwapOperationsEmpty);
+
+        // TEST CASE 4: Venue Specific Execute Msg
+        let res = execute::<MockVenue>(
+            deps.as_mut(),
+            env,
+            mock_info("entry_point", &[]),
+            ExecuteMsg::AstroportPoolSwap {
+                operation: operations[0].clone(),
+                min_amount_out: None,
+            },
+        );
+
+        assert_eq!(res.unwrap_err(), SkipError::UnsupportedExecuteMsg);
+    }
+
+    #[test]
+    fn test_query_simulations() {
+        let deps = mock_dependencies();
+        let env = mock_env();
+
+        let operations = vec![
+            swap_operation("pool_1", "os", "ua"),
+            swap_operation("pool_2", "ua", "un"),
+        ];
+
+        // TEST CASE 1: Simulate Swap Exact Asset In With Spot Price
+        let res: SimulateSwapExactAssetInResponse = from_json(
+            query::<MockVenue>(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::SimulateSwapExactAssetInWithMetadata {
+                    asset_in: Asset::Native(Coin::new(100, "os")),
+                    swap_operations: operations.clone(),
+                    include_spot_price: true,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res.asset_out, Asset::Native(Coin::new(400, "un")));
+        assert_eq!(res.spot_price, Some(Decimal::from_ratio(4u128, 1u128)));
+
+        // TEST CASE 2: Simulate Swap Exact Asset Out
+        let res: Asset = from_json(
+            query::<MockVenue>(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::SimulateSwapExactAssetOut {
+                    asset_out: Asset::Native(Coin::new(400, "un")),
+                    swap_operations: operations.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res, Asset::Native(Coin::new(100, "os")));
+
+        // TEST CASE 3: Simulate Smart Swap With Weighted Spot Price
+        let res: SimulateSmartSwapExactAssetInResponse = from_json(
+            query::<MockVenue>(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
+                    asset_in: Asset::Native(Coin::new(100, "os")),
+                    routes: vec![
+                        Route {
+                            offer_asset: Asset::Native(Coin::new(75, "os")),
+                            operations: operations.clone(),
+                        },
+                        Route {
+                            offer_asset: Asset::Native(Coin::new(25, "os")),
+                            operations: vec![swap_operation("pool_3", "os", "un")],
+                        },
+                    ],
+                    include_spot_price: true,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res.asset_out, Asset::Native(Coin::new(350, "un")));
+        assert_eq!(res.spot_price, Some(Decimal::from_ratio(7u128, 2u128)));
+
+        // TEST CASE 4: Capabilities
+        let res: CapabilitiesResponse = from_json(
+            query::<MockVenue>(deps.as_ref(), env.clone(), QueryMsg::Capabilities {}).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            res,
+            CapabilitiesResponse {
+                swap_exact_asset_in: true,
+                swap_exact_asset_out: true,
+                swap_exact_asset_out_execution: false,
+                smart_swap: true,
+                smart_swap_execution: false,
+                cw20_input: true,
+                spot_price: true,
+                pool_identifiers: vec![PoolIdentifier::ContractAddress],
+            }
+        );
+
+        // TEST CASE 5: Asset In Denom Mismatch
+        let res = query::<MockVenue>(
+            deps.as_ref(),
+            env,
+            QueryMsg::SimulateSwapExactAssetIn {
+                asset_in: Asset::Native(Coin::new(100, "ua")),
+                swap_operations: operations,
+            },
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            SkipError::SwapOperationsAssetInDenomMismatch
+        );
+    }
+}
@@ -0,0 +1,133 @@
+// The EVM address validation and conversion below are copied from the skip package's
+// address module, since skip builds against cosmwasm-std 1.5 and can't be depended on.
+// Keep the two the same in signature and behaviour.
+
+use crate::error::SkipError;
+
+use cosmwasm_std::HexBinary;
+use sha3::{Digest, Keccak256};
+
+// The length in bytes of EVM addresses
+pub const EVM_ADDRESS_LENGTH: usize = 20;
+
+////////////////
+// VALIDATION //
+////////////////
+
+// Returns an error if the given address is not a 0x prefixed 20 byte hex address,
+// or if it is mixed case and does not match its EIP-55 checksum. All lowercase
+// and all uppercase addresses carry no checksum and are accepted as is.
+pub fn validate_evm_address(address: &str) -> Result<(), SkipError> {
+    decode_evm_address(address).map(|_| ())
+}
+
+////////////////
+// CONVERSION //
+////////////////
+
+// Formats a 20 byte EVM address as 0x prefixed hex, uppercasing each letter whose
+// corresponding nibble in the keccak256 hash of the lowercase hex address is 8 or more
+pub fn to_checksum_evm_address(bytes: &[u8]) -> String {
+    let lowercase_hex = HexBinary::from(bytes).to_hex();
+    let hash = Keccak256::digest(lowercase_hex.as_bytes());
+
+    let checksummed_hex: String = lowercase_hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    format!("0x{checksummed_hex}")
+}
+
+//////////////////////
+// HELPER FUNCTIONS //
+//////////////////////
+
+// Decodes a 0x prefixed 20 byte hex address, validating its EIP-55 checksum if mixed case
+fn decode_evm_address(address: &str) -> Result<Vec<u8>, SkipError> {
+    let invalid_address = || SkipError::InvalidEvmAddress {
+        address: address.to_string(),
+    };
+
+    let hex = address.strip_prefix("0x").ok_or_else(invalid_address)?;
+    if hex.len() != EVM_ADDRESS_LENGTH * 2 {
+        return Err(invalid_address());
+    }
+
+    let bytes = HexBinary::from_hex(hex).map_err(|_| invalid_address())?;
+
+    let is_mixed_case =
+        hex.chars().any(|c| c.is_ascii_lowercase()) && hex.chars().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case && to_checksum_evm_address(&bytes) != address {
+        return Err(SkipError::InvalidEvmAddressChecksum {
+            address: address.to_string(),
+        });
+    }
+
+    Ok(bytes.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_evm_address() {
+        // TEST CASE 1: Checksummed Addresses
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            assert_eq!(validate_evm_address(address), Ok(()));
+        }
+
+        // TEST CASE 2: All Lowercase And All Uppercase Addresses
+        assert_eq!(
+            validate_evm_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_evm_address("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"),
+            Ok(())
+        );
+
+        // TEST CASE 3: Mixed Case Address With Invalid Checksum
+        assert_eq!(
+            validate_evm_address("0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(SkipError::InvalidEvmAddressChecksum {
+                address: "0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            })
+        );
+
+        // TEST CASE 4: Malformed Addresses
+        for address in [
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1bea",
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beagg",
+            "0x123INVALIDADDRESS",
+            "osmo1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5helwsw",
+        ] {
+            assert_eq!(
+                validate_evm_address(address),
+                Err(SkipError::InvalidEvmAddress {
+                    address: address.to_string(),
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use thiserror::Error;
+
+// Copied from the variants of the SkipError enum of the skip package used by the
+// code copied into this package, since skip builds against cosmwasm-std 1.5 and
+// can't be depended on. Keep the variants and messages the same as in skip.
+#[derive(Error, Debug, PartialEq)]
+pub enum SkipError {
+    /////////////
+    // ADDRESS //
+    /////////////
+    #[error("Invalid EVM Address: {address}")]
+    InvalidEvmAddress { address: String },
+
+    #[error("EVM Address Does Not Match Its EIP-55 Checksum: {address}")]
+    InvalidEvmAddressChecksum { address: String },
+}
@@ -47,6 +47,17 @@ pub struct RecoverInfo {
     pub coin: Coin,
 }
 
+// The FailedDelivery struct records a destination callback contract call that
+// failed, and the recover address the received funds were refunded to.
+#[cw_serde]
+pub struct FailedDelivery {
+    pub id: u64,
+    pub contract_address: String,
+    pub recover_address: String,
+    pub coin: Coin,
+    pub error: String,
+}
+
 // The QueryMsg enum defines the queries the IBC Transfer Adapter Contract provides.
 #[cw_serde]
 #[derive(QueryResponses)]
@@ -56,6 +67,13 @@ pub enum QueryMsg {
         channel_id: String,
         sequence_id: u64,
     },
+    // FailedDestinationDeliveries returns the destination callback contract calls
+    // that failed and were refunded to the recover address given in the memo.
+    #[returns(Vec<FailedDelivery>)]
+    FailedDestinationDeliveries {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 ////////////////////
@@ -96,6 +114,9 @@ pub struct EurekaFee {
     pub timeout_timestamp: u64,
 }
 
+// The encoding of IBC transfers to EVM chains through Eureka, whose receiver must be an EVM address
+pub const IBC_SOLIDITY_ABI_ENCODING: &str = "application/x-solidity-abi";
+
 // The IbcInfo struct defines the information for an IBC transfer standardized across all IBC Transfer Adapter contracts.
 #[cw_serde]
 pub struct IbcInfo {
@@ -136,6 +157,8 @@ pub type AckID<'a> = (&'a str, u64);
 #[cw_serde]
 pub struct Memo {
     pub wasm: WasmData,
+    /// Address refunded if the destination contract call fails
+    pub recover_address: Option<String>,
 }
 
 /// Nested "wasm" object
@@ -1,3 +1,5 @@
+pub mod address;
 pub mod callbacks;
+pub mod error;
 pub mod ibc;
 pub mod proto_coin;
@@ -0,0 +1,568 @@
+use cosmwasm_std::{
+    from_json,
+    testing::{
+        mock_dependencies_with_balances, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    },
+    to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env,
+    MessageInfo, OwnedDeps, Querier, QuerierResult, QueryRequest, Response, SubMsg, Uint128,
+    WasmMsg,
+};
+use cw20::Cw20ReceiveMsg;
+use skip::{
+    asset::Asset,
+    swap::{
+        CapabilitiesResponse, Cw20HookMsg, ExecuteMsg, QueryMsg, Route,
+        SimulateSmartSwapExactAssetInResponse, SimulateSwapExactAssetInResponse,
+        SimulateSwapExactAssetOutResponse, SwapOperation,
+    },
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+// Addresses used by the conformance suite
+pub const ENTRY_POINT_CONTRACT_ADDRESS: &str = "entry_point";
+pub const SWAP_CONTRACT_ADDRESS: &str = "swap_contract_address";
+pub const SWAPPER_ADDRESS: &str = "swapper";
+pub const RANDOM_ADDRESS: &str = "random";
+
+// Error messages the suite accepts for each expected error, the first being
+// the swap adapter contract error and the second being the skip package error.
+const UNAUTHORIZED_ERRORS: &[&str] = &["Unauthorized"];
+const SWAP_OPERATIONS_EMPTY_ERRORS: &[&str] =
+    &["swap_operations cannot be empty", "Swap Operations Empty"];
+const COIN_IN_DENOM_MISMATCH_ERRORS: &[&str] = &[
+    "coin_in denom must match the first swap operation's denom in",
+    "First Swap Operations' Denom In Differs From Swap Asset In Denom",
+];
+const COIN_OUT_DENOM_MISMATCH_ERRORS: &[&str] = &[
+    "coin_out denom must match the last swap operation's denom out",
+    "Last Swap Operations' Denom Out Differs From Swap Asset Out Denom",
+];
+
+pub type MockDeps = OwnedDeps<MockStorage, MockApi, VenueQuerier>;
+
+// The VenueQuerier struct answers stargate queries with the mocked venue's stargate
+// querier, and every other query with the cosmwasm mock querier.
+pub struct VenueQuerier {
+    pub querier: MockQuerier,
+    pub stargate_querier: Option<fn(&str, &Binary) -> QuerierResult>,
+}
+
+impl Querier for VenueQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        if let (Some(stargate_querier), Ok(QueryRequest::Stargate { path, data })) = (
+            self.stargate_querier,
+            from_json::<QueryRequest<Empty>>(bin_request),
+        ) {
+            return stargate_querier(&path, &data);
+        }
+
+        self.querier.raw_query(bin_request)
+    }
+}
+
+// The MockVenue struct describes the mocked swap venue the suite runs the swap adapter against.
+pub struct MockVenue {
+    // Installs the venue's query responses (pool simulations, spot prices, ...) on the mock querier
+    pub mock_querier: fn(&mut MockQuerier),
+    // Answers the venue's stargate queries given the query path and the protobuf encoded
+    // request. None if the venue is only queried through the mock querier.
+    pub stargate_querier: Option<fn(&str, &Binary) -> QuerierResult>,
+    // Swap operations of a route the mocked venue can simulate, starting from a native denom.
+    // Native denoms must not be valid mock api addresses (i.e. shorter than 3 characters, like
+    // "os" or "ua") so they are not mistaken for cw20 contract addresses.
+    pub swap_operations: Vec<SwapOperation>,
+    // Swap operations of a route the mocked venue can simulate, starting from a cw20 token.
+    // None if the swap adapter does not support cw20 input.
+    pub cw20_swap_operations: Option<Vec<SwapOperation>>,
+    // Whether the swap adapter supports simulating a swap exact asset out
+    pub supports_exact_asset_out: bool,
+}
+
+// The SwapAdapterSuite struct holds the swap adapter entry points
+// and the mocked venue the standard swap adapter test suite runs with.
+pub struct SwapAdapterSuite<E> {
+    // Instantiates the swap adapter with the given entry point contract address
+    pub instantiate: fn(DepsMut, Env, MessageInfo, String) -> Result<Response, E>,
+    pub execute: fn(DepsMut, Env, MessageInfo, ExecuteMsg) -> Result<Response, E>,
+    pub query: fn(Deps, Env, QueryMsg) -> Result<Binary, E>,
+    pub venue: MockVenue,
+}
+
+impl<E: Debug + ToString> SwapAdapterSuite<E> {
+    // Runs the whole conformance suite
+    pub fn run(&self) {
+        self.test_swap_dispatches_transfer_funds_back();
+        self.test_swap_unauthorized();
+        self.test_swap_empty_operations();
+        self.test_receive_cw20();
+        self.test_transfer_funds_back();
+        self.test_simulation_denom_mismatch();
+        self.test_simulation_empty_operations();
+        self.test_simulation_metadata();
+        self.test_smart_swap_aggregation();
+        self.test_capabilities();
+    }
+
+    // Tests a swap from the entry point ends with transferring
+    // the last swap operation's denom out back to the entry point
+    pub fn test_swap_dispatches_transfer_funds_back(&self) {
+        let (mut deps, env) = self.setup(&[]);
+
+        let res = (self.execute)(
+            deps.as_mut(),
+            env,
+            mock_info(ENTRY_POINT_CONTRACT_ADDRESS, &[self.native_coin_in()]),
+            ExecuteMsg::Swap {
+                operations: self.venue.swap_operations.clone(),
+                min_amount_out: None,
+            },
+        )
+        .expect("swap from the entry point should succeed");
+
+        assert_transfer_funds_back_last(&res, &self.venue.swap_operations);
+    }
+
+    // Tests only the entry point contract can swap
+    pub fn test_swap_unauthorized(&self) {
+        let (mut deps, env) = self.setup(&[]);
+
+        let res = (self.execute)(
+            deps.as_mut(),
+            env,
+            mock_info(RANDOM_ADDRESS, &[self.native_coin_in()]),
+            ExecuteMsg::Swap {
+                operations: self.venue.swap_operations.clone(),
+                min_amount_out: None,
+            },
+        );
+
+        assert_error(res, UNAUTHORIZED_ERRORS);
+    }
+
+    // Tests a swap without swap operations errors
+    pub fn test_swap_empty_operations(&self) {
+        let (mut deps, env) = self.setup(&[]);
+
+        let res = (self.execute)(
+            deps.as_mut(),
+            env,
+            mock_info(ENTRY_POINT_CONTRACT_ADDRESS, &[self.native_coin_in()]),
+            ExecuteMsg::Swap {
+                operations: vec![],
+                min_amount_out: None,
+            },
+        );
+
+        assert_error(res, SWAP_OPERATIONS_EMPTY_ERRORS);
+    }
+
+    // Tests cw20 input is swapped when sent by the entry point, rejected when sent
+    // by anyone else, and rejected when the swap adapter does not support cw20 input
+    pub fn test_receive_cw20(&self) {
+        let Some(cw20_swap_operations) = &self.venue.cw20_swap_operations else {
+            // Send a cw20 token to the swap adapter and assert it is not swapped
+            let (mut deps, env) = self.setup(&[]);
+
+            let res = (self.execute)(
+                deps.as_mut(),
+                env,
+                mock_info("cw20_contract", &[]),
+                cw20_receive_msg(ENTRY_POINT_CONTRACT_ADDRESS, &self.venue.swap_operations),
+            );
+
+            assert!(
+                res.is_err(),
+                "expected cw20 input to be rejected, but it succeeded"
+            );
+
+            return;
+        };
+
+        let cw20_contract_address = cw20_swap_operations[0].denom_in.clone();
+
+        // Cw20 sent by the entry point is swapped
+        let (mut deps, env) = self.setup(&[]);
+
+        let res = (self.execute)(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(&cw20_contract_address, &[]),
+            cw20_receive_msg(ENTRY_POINT_CONTRACT_ADDRESS, cw20_swap_operations),
+        )
+        .expect("cw20 swap from the entry point should succeed");
+
+        assert_transfer_funds_back_last(&res, cw20_swap_operations);
+
+        // Cw20 sent by anyone else is rejected
+        let res = (self.execute)(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(&cw20_contract_address, &[]),
+            cw20_receive_msg(RANDOM_ADDRESS, cw20_swap_operations),
+        );
+
+        assert_error(res, UNAUTHORIZED_ERRORS);
+
+        // Native coins sent along with the cw20 are rejected
+        let res = (self.execute)(
+            deps.as_mut(),
+            env,
+            mock_info(&cw20_contract_address, &[self.native_coin_in()]),
+            cw20_receive_msg(ENTRY_POINT_CONTRACT_ADDRESS, cw20_swap_operations),
+        );
+
+        assert!(
+            res.is_err(),
+            "expected native coins sent with cw20 to be rejected, but it succeeded"
+        );
+    }
+
+    // Tests transfer funds back sends the whole contract balance to the
+    // swapper, and can only be called by the swap adapter contract itself
+    pub fn test_transfer_funds_back(&self) {
+        let return_denom = self.native_denom_out();
+        let contract_balance = vec![Coin::new(100, return_denom.clone()), Coin::new(50, "dust")];
+
+        let (mut deps, env) = self.setup(&contract_balance);
+
+        let msg = ExecuteMsg::TransferFundsBack {
+            swapper: Addr::unchecked(SWAPPER_ADDRESS),
+            return_denom,
+        };
+
+        let res = (self.execute)(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(SWAP_CONTRACT_ADDRESS, &[]),
+            msg.clone(),
+        )
+        .expect("transfer funds back from the contract itself should succeed");
+
+        assert_eq!(
+            res.messages,
+            vec![SubMsg::new(BankMsg::Send {
+                to_address: SWAPPER_ADDRESS.to_string(),
+                amount: contract_balance,
+            })]
+        );
+
+        let res = (self.execute)(deps.as_mut(), env, mock_info(RANDOM_ADDRESS, &[]), msg);
+
+        assert_error(res, UNAUTHORIZED_ERRORS);
+    }
+
+    // Tests the simulations error if the asset denom does not match the swap operations
+    pub fn test_simulation_denom_mismatch(&self) {
+        let (deps, env) = self.setup(&[]);
+
+        let res = (self.query)(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::SimulateSwapExactAssetIn {
+                asset_in: Asset::Native(Coin::new(100, "mismatch")),
+                swap_operations: self.venue.swap_operations.clone(),
+            },
+        );
+
+        assert_error(res, COIN_IN_DENOM_MISMATCH_ERRORS);
+
+        if self.venue.supports_exact_asset_out {
+            let res = (self.query)(
+                deps.as_ref(),
+                env,
+                QueryMsg::SimulateSwapExactAssetOut {
+                    asset_out: Asset::Native(Coin::new(100, "mismatch")),
+                    swap_operations: self.venue.swap_operations.clone(),
+                },
+            );
+
+            assert_error(res, COIN_OUT_DENOM_MISMATCH_ERRORS);
+        }
+    }
+
+    // Tests the simulations error if the swap operations are empty
+    pub fn test_simulation_empty_operations(&self) {
+        let (deps, env) = self.setup(&[]);
+
+        let res = (self.query)(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::SimulateSwapExactAssetIn {
+                asset_in: Asset::Native(self.native_coin_in()),
+                swap_operations: vec![],
+            },
+        );
+
+        assert_error(res, SWAP_OPERATIONS_EMPTY_ERRORS);
+
+        if self.venue.supports_exact_asset_out {
+            let res = (self.query)(
+                deps.as_ref(),
+                env,
+                QueryMsg::SimulateSwapExactAssetOut {
+                    asset_out: Asset::Native(Coin::new(100, self.native_denom_out())),
+                    swap_operations: vec![],
+                },
+            );
+
+            assert_error(res, SWAP_OPERATIONS_EMPTY_ERRORS);
+        }
+    }
+
+    // Tests the metadata simulations return the same asset as the plain
+    // simulations, and only include the spot price when requested
+    pub fn test_simulation_metadata(&self) {
+        let (deps, env) = self.setup(&[]);
+
+        let asset_in = Asset::Native(self.native_coin_in());
+        let swap_operations = self.venue.swap_operations.clone();
+
+        let asset_out: Asset = self.query_ok(
+            &deps,
+            &env,
+            QueryMsg::SimulateSwapExactAssetIn {
+                asset_in: asset_in.clone(),
+                swap_operations: swap_operations.clone(),
+            },
+        );
+
+        assert_eq!(asset_out.denom(), self.native_denom_out());
+
+        for include_spot_price in [true, false] {
+            let res: SimulateSwapExactAssetInResponse = self.query_ok(
+                &deps,
+                &env,
+                QueryMsg::SimulateSwapExactAssetInWithMetadata {
+                    asset_in: asset_in.clone(),
+                    swap_operations: swap_operations.clone(),
+                    include_spot_price,
+                },
+            );
+
+            assert_eq!(res.asset_out, asset_out);
+            assert_spot_price(res.spot_price, include_spot_price);
+        }
+
+        if !self.venue.supports_exact_asset_out {
+            return;
+        }
+
+        let asset_in_needed: Asset = self.query_ok(
+            &deps,
+            &env,
+            QueryMsg::SimulateSwapExactAssetOut {
+                asset_out: asset_out.clone(),
+                swap_operations: swap_operations.clone(),
+            },
+        );
+
+        assert_eq!(asset_in_needed.denom(), asset_in.denom());
+
+        for include_spot_price in [true, false] {
+            let res: SimulateSwapExactAssetOutResponse = self.query_ok(
+                &deps,
+                &env,
+                QueryMsg::SimulateSwapExactAssetOutWithMetadata {
+                    asset_out: asset_out.clone(),
+                    swap_operations: swap_operations.clone(),
+                    include_spot_price,
+                },
+            );
+
+            assert_eq!(res.asset_in, asset_in_needed);
+            assert_spot_price(res.spot_price, include_spot_price);
+        }
+    }
+
+    // Tests a smart swap simulation returns the sum of its routes' simulations
+    pub fn test_smart_swap_aggregation(&self) {
+        let (deps, env) = self.setup(&[]);
+
+        let asset_in = Asset::Native(self.native_coin_in());
+        let denom_in = asset_in.denom().to_string();
+        let routes = vec![
+            Route {
+                offer_asset: Asset::Native(Coin::new(60, denom_in.clone())),
+                operations: self.venue.swap_operations.clone(),
+            },
+            Route {
+                offer_asset: Asset::Native(Coin::new(40, denom_in)),
+                operations: self.venue.swap_operations.clone(),
+            },
+        ];
+
+        // Simulate each route on its own and sum the asset out amounts
+        let expected_amount_out = routes
+            .iter()
+            .map(|route| {
+                self.query_ok::<Asset>(
+                    &deps,
+                    &env,
+                    QueryMsg::SimulateSwapExactAssetIn {
+                        asset_in: route.offer_asset.clone(),
+                        swap_operations: route.operations.clone(),
+                    },
+                )
+                .amount()
+            })
+            .sum::<Uint128>();
+
+        let asset_out: Asset = self.query_ok(
+            &deps,
+            &env,
+            QueryMsg::SimulateSmartSwapExactAssetIn {
+                asset_in: asset_in.clone(),
+                routes: routes.clone(),
+            },
+        );
+
+        assert_eq!(asset_out.denom(), self.native_denom_out());
+        assert_eq!(asset_out.amount(), expected_amount_out);
+
+        for include_spot_price in [true, false] {
+            let res: SimulateSmartSwapExactAssetInResponse = self.query_ok(
+                &deps,
+                &env,
+                QueryMsg::SimulateSmartSwapExactAssetInWithMetadata {
+                    asset_in: asset_in.clone(),
+                    routes: routes.clone(),
+                    include_spot_price,
+                },
+            );
+
+            assert_eq!(res.asset_out, asset_out);
+            assert_spot_price(res.spot_price, include_spot_price);
+        }
+    }
+
+    // Tests the capabilities query reports the features the rest of the suite exercised
+    pub fn test_capabilities(&self) {
+        let (deps, env) = self.setup(&[]);
+
+        let res: CapabilitiesResponse = self.query_ok(&deps, &env, QueryMsg::Capabilities {});
+
+        assert!(res.swap_exact_asset_in);
+        assert_eq!(
+            res.swap_exact_asset_out,
+            self.venue.supports_exact_asset_out
+        );
+        assert!(res.smart_swap);
+        assert_eq!(res.cw20_input, self.venue.cw20_swap_operations.is_some());
+        assert!(res.spot_price);
+        assert!(!res.pool_identifiers.is_empty());
+    }
+
+    //////////////////////
+    // HELPER FUNCTIONS //
+    //////////////////////
+
+    // Creates the mock dependencies with the given swap adapter contract balance,
+    // installs the mocked venue and instantiates the swap adapter
+    fn setup(&self, contract_balance: &[Coin]) -> (MockDeps, Env) {
+        let deps = mock_dependencies_with_balances(&[(SWAP_CONTRACT_ADDRESS, contract_balance)]);
+        let mut deps = MockDeps {
+            storage: deps.storage,
+            api: deps.api,
+            querier: VenueQuerier {
+                querier: deps.querier,
+                stargate_querier: self.venue.stargate_querier,
+            },
+            custom_query_type: PhantomData,
+        };
+        (self.venue.mock_querier)(&mut deps.querier.querier);
+
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked(SWAP_CONTRACT_ADDRESS);
+
+        (self.instantiate)(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("creator", &[]),
+            ENTRY_POINT_CONTRACT_ADDRESS.to_string(),
+        )
+        .expect("swap adapter instantiation should succeed");
+
+        (deps, env)
+    }
+
+    fn query_ok<T: serde::de::DeserializeOwned>(
+        &self,
+        deps: &MockDeps,
+        env: &Env,
+        msg: QueryMsg,
+    ) -> T {
+        let res = (self.query)(deps.as_ref(), env.clone(), msg.clone())
+            .unwrap_or_else(|err| panic!("query {msg:?} should succeed, errored with {err:?}"));
+
+        from_json(res).expect("query response should deserialize")
+    }
+
+    fn native_coin_in(&self) -> Coin {
+        Coin::new(100, self.venue.swap_operations[0].denom_in.clone())
+    }
+
+    fn native_denom_out(&self) -> String {
+        self.venue
+            .swap_operations
+            .last()
+            .expect("mock venue swap operations should not be empty")
+            .denom_out
+            .clone()
+    }
+}
+
+// Creates a cw20 receive message sent by the given sender to swap over the swap operations
+fn cw20_receive_msg(sender: &str, operations: &[SwapOperation]) -> ExecuteMsg {
+    ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: sender.to_string(),
+        amount: Uint128::new(100),
+        msg: to_json_binary(&Cw20HookMsg::Swap {
+            operations: operations.to_vec(),
+            min_amount_out: None,
+        })
+        .unwrap(),
+    })
+}
+
+// Asserts the last message of the response transfers the
+// swap operations' denom out back to the entry point
+fn assert_transfer_funds_back_last(res: &Response, operations: &[SwapOperation]) {
+    let expected_msg: CosmosMsg = WasmMsg::Execute {
+        contract_addr: SWAP_CONTRACT_ADDRESS.to_string(),
+        msg: to_json_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: Addr::unchecked(ENTRY_POINT_CONTRACT_ADDRESS),
+            return_denom: operations.last().unwrap().denom_out.clone(),
+        })
+        .unwrap(),
+        funds: vec![],
+    }
+    .into();
+
+    assert_eq!(
+        res.messages.last().map(|sub_msg| &sub_msg.msg),
+        Some(&expected_msg),
+        "expected the swap to end with transferring the funds back to the entry point"
+    );
+}
+
+// Asserts the result errored with one of the accepted error messages
+fn assert_error<T: Debug, E: Debug + ToString>(res: Result<T, E>, accepted_errors: &[&str]) {
+    match res {
+        Ok(res) => panic!("expected one of {accepted_errors:?}, but it succeeded with {res:?}"),
+        Err(err) => assert!(
+            accepted_errors.contains(&err.to_string().as_str()),
+            "expected one of {accepted_errors:?}, but it errored with {err:?}"
+        ),
+    }
+}
+
+// Asserts the spot price is included, and positive, only when requested
+fn assert_spot_price(spot_price: Option<Decimal>, include_spot_price: bool) {
+    match spot_price {
+        Some(spot_price) => {
+            assert!(include_spot_price, "spot price included but not requested");
+            assert!(!spot_price.is_zero(), "spot price should not be zero");
+        }
+        None => assert!(!include_spot_price, "spot price requested but not included"),
+    }
+}
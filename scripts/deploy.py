@@ -202,6 +202,8 @@ def main():
                 swap_adapter_instantiate_args["dexter_router_contract_address"] = venue["dexter_router_contract_address"]
             if "drop_factory_contract_address" in venue:
                 swap_adapter_instantiate_args["drop_factory_contract_address"] = venue["drop_factory_contract_address"]
+            if "instant_unbond_pool_contract_address" in venue:
+                swap_adapter_instantiate_args["instant_unbond_pool_contract_address"] = venue["instant_unbond_pool_contract_address"]
             if "dex_module_address" in venue:
                 swap_adapter_instantiate_args["dex_module_address"] = venue["dex_module_address"]
             if "astrovault_router_contract_address" in venue: